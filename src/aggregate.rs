@@ -0,0 +1,412 @@
+//! Functions for aggregating prices, such as averaging the prices of multiple listings.
+//! 
+//! Prices are aggregated by their total value using the given key price (represented as weapons)
+//! and accumulated as [`i128`] so that they cannot overflow. Results are converted back to 
+//! [`Currencies`] using the given rounding method, saturating at integer bounds.
+//! 
+//! # Panics
+//! Functions which return [`Currencies`] panic if the key price is `0`.
+//! 
+//! # Examples
+//! ```
+//! use tf2_price::{Currencies, Rounding, aggregate, refined};
+//! 
+//! let key_price = refined!(50);
+//! let prices = [
+//!     Currencies { keys: 1, metal: 0 },
+//!     Currencies { keys: 0, metal: refined!(45) },
+//!     Currencies { keys: 0, metal: refined!(40) },
+//! ];
+//! 
+//! assert_eq!(
+//!     aggregate::mean(&prices, key_price, &Rounding::Refined),
+//!     Some(Currencies { keys: 0, metal: refined!(45) }),
+//! );
+//! assert_eq!(
+//!     aggregate::median(&prices, key_price, &Rounding::Refined),
+//!     Some(Currencies { keys: 0, metal: refined!(45) }),
+//! );
+//! ```
+
+use crate::helpers;
+use crate::types::Currency;
+use crate::{Currencies, Rounding, RoundMetal};
+#[cfg(not(feature = "std"))]
+use crate::float::Float;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+fn value(currencies: &Currencies, key_price: Currency) -> i128 {
+    helpers::to_metal_wide(currencies.metal, currencies.keys, key_price)
+}
+
+/// Divides a total value into its whole part and its fraction in `0.0..1.0`.
+fn divide(total: i128, count: i128) -> (i128, f64) {
+    (total.div_euclid(count), total.rem_euclid(count) as f64 / count as f64)
+}
+
+/// Converts the total value `whole + fraction` back to currencies.
+fn to_currencies<R: RoundMetal + ?Sized>(
+    (whole, fraction): (i128, f64),
+    key_price: Currency,
+    rounding: &R,
+) -> Currencies {
+    Currencies::from_metal(helpers::round_metal_parts(whole, fraction, rounding), key_price)
+}
+
+/// The mean of the prices. `None` if `prices` is empty.
+pub fn mean<R: RoundMetal + ?Sized>(
+    prices: &[Currencies],
+    key_price: Currency,
+    rounding: &R,
+) -> Option<Currencies> {
+    if prices.is_empty() {
+        return None;
+    }
+    
+    let total = prices.iter()
+        .map(|currencies| value(currencies, key_price))
+        .sum::<i128>();
+    
+    Some(to_currencies(divide(total, prices.len() as i128), key_price, rounding))
+}
+
+/// The mean of the prices weighted by quantity, e.g. the number of listings at each price. 
+/// Pairs with a quantity of `0` or less are ignored. `None` if there are no pairs with a positive 
+/// quantity.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, Rounding, aggregate, refined};
+/// 
+/// let key_price = refined!(50);
+/// let prices = [
+///     (Currencies { keys: 0, metal: refined!(40) }, 3),
+///     (Currencies { keys: 0, metal: refined!(48) }, 1),
+/// ];
+/// 
+/// assert_eq!(
+///     aggregate::weighted_mean(&prices, key_price, &Rounding::Refined),
+///     Some(Currencies { keys: 0, metal: refined!(42) }),
+/// );
+/// ```
+pub fn weighted_mean<R: RoundMetal + ?Sized>(
+    prices: &[(Currencies, Currency)],
+    key_price: Currency,
+    rounding: &R,
+) -> Option<Currencies> {
+    let (total, quantity) = prices.iter()
+        .filter(|(_currencies, quantity)| *quantity > 0)
+        .fold((0_i128, 0_i128), |(total, count), (currencies, quantity)| {
+            let quantity = *quantity as i128;
+            
+            (
+                total.saturating_add(value(currencies, key_price).saturating_mul(quantity)),
+                count + quantity,
+            )
+        });
+    
+    if quantity == 0 {
+        return None;
+    }
+    
+    Some(to_currencies(divide(total, quantity), key_price, rounding))
+}
+
+/// The median of the prices. When there is an even number of prices the two middle values are 
+/// averaged. `None` if `prices` is empty.
+pub fn median<R: RoundMetal + ?Sized>(
+    prices: &[Currencies],
+    key_price: Currency,
+    rounding: &R,
+) -> Option<Currencies> {
+    if prices.is_empty() {
+        return None;
+    }
+    
+    let mut values = prices.iter()
+        .map(|currencies| value(currencies, key_price))
+        .collect::<Vec<_>>();
+    
+    values.sort_unstable();
+    
+    let middle = values.len() / 2;
+    let total = if values.len() % 2 == 0 {
+        // Halving the difference from the lower value rather than the sum cannot overflow.
+        let (low, high) = (values[middle - 1], values[middle]);
+        let (half, fraction) = divide(high - low, 2);
+        
+        (low + half, fraction)
+    } else {
+        (values[middle], 0.0)
+    };
+    
+    Some(to_currencies(total, key_price, rounding))
+}
+
+/// The most common price by value. When multiple values are equally common the lowest is used. 
+/// The result is neatened, e.g. `50 ref` at a key price of `50 ref` is returned as `1 key`. 
+/// `None` if `prices` is empty.
+pub fn mode(
+    prices: &[Currencies],
+    key_price: Currency,
+) -> Option<Currencies> {
+    let mut counts: BTreeMap<i128, usize> = BTreeMap::new();
+    
+    for currencies in prices {
+        *counts.entry(value(currencies, key_price)).or_default() += 1;
+    }
+    
+    let (total, _count) = counts.into_iter()
+        .max_by(|(a_value, a_count), (b_value, b_count)| {
+            a_count.cmp(b_count).then(b_value.cmp(a_value))
+        })?;
+    
+    Some(to_currencies((total, 0.0), key_price, &Rounding::None))
+}
+
+/// Removes outliers from the prices using the interquartile range. Prices more than `1.5` times 
+/// the interquartile range below the first quartile or above the third quartile are removed. The 
+/// order of the remaining prices is preserved.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, aggregate, refined, scrap};
+/// 
+/// let key_price = refined!(50);
+/// let prices = [
+///     Currencies { keys: 0, metal: scrap!(1) },
+///     Currencies { keys: 0, metal: refined!(44) },
+///     Currencies { keys: 0, metal: refined!(45) },
+///     Currencies { keys: 0, metal: refined!(46) },
+///     Currencies { keys: 0, metal: refined!(46) },
+///     Currencies { keys: 1000, metal: 0 },
+/// ];
+/// let filtered = aggregate::filter_outliers_iqr(&prices, key_price);
+/// 
+/// assert_eq!(filtered, &prices[1..5]);
+/// ```
+pub fn filter_outliers_iqr(
+    prices: &[Currencies],
+    key_price: Currency,
+) -> Vec<Currencies> {
+    let mut values = prices.iter()
+        .map(|currencies| value(currencies, key_price))
+        .collect::<Vec<_>>();
+    
+    values.sort_unstable();
+    
+    let (Some(q1), Some(q3)) = (quantile(&values, 0.25), quantile(&values, 0.75)) else {
+        return Vec::new();
+    };
+    let fence = (q3 - q1) * 1.5;
+    let (low, high) = (q1 - fence, q3 + fence);
+    
+    prices.iter()
+        .filter(|currencies| {
+            let value = value(currencies, key_price) as f64;
+            
+            low <= value && value <= high
+        })
+        .copied()
+        .collect()
+}
+
+/// Removes outliers from the prices using their z-score, the number of standard deviations a 
+/// price is from the mean. Prices with a z-score greater than `threshold` are removed, a 
+/// `threshold` of `2.0` or `3.0` is typical. The order of the remaining prices is preserved.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, aggregate, refined};
+/// 
+/// let key_price = refined!(50);
+/// let mut prices = vec![Currencies { keys: 0, metal: refined!(45) }; 10];
+/// 
+/// prices.push(Currencies { keys: 1000, metal: 0 });
+/// 
+/// assert_eq!(aggregate::filter_outliers_z_score(&prices, key_price, 3.0), &prices[..10]);
+/// ```
+pub fn filter_outliers_z_score(
+    prices: &[Currencies],
+    key_price: Currency,
+    threshold: f64,
+) -> Vec<Currencies> {
+    if prices.is_empty() {
+        return Vec::new();
+    }
+    
+    let values = prices.iter()
+        .map(|currencies| value(currencies, key_price) as f64)
+        .collect::<Vec<_>>();
+    let count = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / count;
+    let variance = values.iter().map(|value| (value - mean) * (value - mean)).sum::<f64>() / count;
+    let std_dev = variance.sqrt();
+    
+    if std_dev == 0.0 {
+        // All prices are the same.
+        return prices.to_vec();
+    }
+    
+    prices.iter()
+        .zip(values)
+        .filter(|(_currencies, value)| ((value - mean) / std_dev).abs() <= threshold)
+        .map(|(currencies, _value)| *currencies)
+        .collect()
+}
+
+/// Calculates a quantile of sorted values using linear interpolation between the closest ranks.
+fn quantile(sorted: &[i128], p: f64) -> Option<f64> {
+    let last = sorted.len().checked_sub(1)?;
+    let rank = last as f64 * p;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    let lower_value = sorted[lower] as f64;
+    
+    Some(lower_value + (sorted[upper] as f64 - lower_value) * (rank - lower as f64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{refined, scrap};
+    
+    #[test]
+    fn aggregates_empty_slice() {
+        let key_price = refined!(50);
+        
+        assert_eq!(mean(&[], key_price, &Rounding::None), None);
+        assert_eq!(median(&[], key_price, &Rounding::None), None);
+        assert_eq!(mode(&[], key_price), None);
+    }
+    
+    #[test]
+    fn mean_rounds_result() {
+        let prices = [
+            Currencies { keys: 0, metal: scrap!(1) },
+            Currencies { keys: 0, metal: scrap!(2) },
+        ];
+        
+        assert_eq!(mean(&prices, refined!(50), &Rounding::UpScrap), Some(Currencies {
+            keys: 0,
+            metal: scrap!(2),
+        }));
+    }
+    
+    #[test]
+    fn mean_does_not_overflow() {
+        let prices = [
+            Currencies { keys: Currency::MAX, metal: 0 },
+            Currencies { keys: Currency::MAX, metal: 0 },
+        ];
+        
+        assert_eq!(mean(&prices, refined!(50), &Rounding::None).unwrap().keys, Currency::MAX / refined!(50));
+    }
+    
+    #[test]
+    fn weighted_mean_ignores_non_positive_quantities() {
+        let prices = [
+            (Currencies { keys: 1, metal: 0 }, 0),
+            (Currencies { keys: 0, metal: refined!(10) }, 2),
+            (Currencies { keys: 5, metal: 0 }, -1),
+        ];
+        
+        assert_eq!(weighted_mean(&prices, refined!(50), &Rounding::None), Some(Currencies {
+            keys: 0,
+            metal: refined!(10),
+        }));
+        assert_eq!(weighted_mean(&prices[..1], refined!(50), &Rounding::None), None);
+    }
+    
+    #[test]
+    fn weighted_mean_does_not_overflow() {
+        let prices = [
+            (Currencies { keys: Currency::MAX / refined!(50), metal: 0 }, Currency::MAX),
+            (Currencies { keys: 0, metal: 0 }, Currency::MAX),
+        ];
+        
+        assert!(weighted_mean(&prices, refined!(50), &Rounding::None).is_some());
+    }
+    
+    #[test]
+    fn filters_outliers_iqr_empty() {
+        assert!(filter_outliers_iqr(&[], refined!(50)).is_empty());
+    }
+    
+    #[test]
+    fn filters_outliers_iqr_uses_total_value() {
+        let key_price = refined!(50);
+        let prices = [
+            Currencies { keys: 1, metal: 0 },
+            Currencies { keys: 0, metal: refined!(50) },
+            Currencies { keys: 0, metal: refined!(49) },
+            Currencies { keys: 1, metal: refined!(1) },
+            Currencies { keys: 0, metal: refined!(1) },
+        ];
+        
+        assert_eq!(filter_outliers_iqr(&prices, key_price), &prices[..4]);
+    }
+    
+    #[test]
+    fn filters_outliers_z_score_same_prices() {
+        let prices = [Currencies { keys: 1, metal: 0 }; 3];
+        
+        assert_eq!(filter_outliers_z_score(&prices, refined!(50), 2.0), prices);
+    }
+    
+    #[test]
+    fn quantile_interpolates() {
+        assert_eq!(quantile(&[0, 10], 0.25), Some(2.5));
+        assert_eq!(quantile(&[5], 0.75), Some(5.0));
+        assert_eq!(quantile(&[], 0.5), None);
+    }
+    
+    #[test]
+    fn median_of_even_number_of_prices() {
+        let prices = [
+            Currencies { keys: 2, metal: 0 },
+            Currencies { keys: 0, metal: refined!(10) },
+            Currencies { keys: 1, metal: 0 },
+            Currencies { keys: 0, metal: refined!(40) },
+        ];
+        
+        assert_eq!(median(&prices, refined!(50), &Rounding::Refined), Some(Currencies {
+            keys: 0,
+            metal: refined!(45),
+        }));
+    }
+    
+    #[test]
+    fn median_does_not_overflow() {
+        let prices = [
+            Currencies { keys: Currency::MAX, metal: Currency::MAX },
+            Currencies { keys: Currency::MAX, metal: Currency::MAX - 1 },
+        ];
+        
+        assert!(median(&prices, Currency::MAX, &Rounding::None).is_some());
+    }
+    
+    #[test]
+    fn mode_uses_total_value() {
+        let key_price = refined!(50);
+        let prices = [
+            Currencies { keys: 1, metal: 0 },
+            Currencies { keys: 0, metal: refined!(50) },
+            Currencies { keys: 0, metal: refined!(45) },
+        ];
+        
+        assert_eq!(mode(&prices, key_price), Some(Currencies { keys: 1, metal: 0 }));
+    }
+    
+    #[test]
+    fn mode_prefers_lowest_value_on_tie() {
+        let key_price = refined!(50);
+        let prices = [
+            Currencies { keys: 0, metal: refined!(46) },
+            Currencies { keys: 0, metal: refined!(45) },
+        ];
+        
+        assert_eq!(mode(&prices, key_price), Some(Currencies { keys: 0, metal: refined!(45) }));
+    }
+}
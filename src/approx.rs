@@ -0,0 +1,34 @@
+//! [`approx`] support for [`ListingCurrencies`]. Available with the `approx` feature.
+//! 
+//! Key values are compared within the given epsilon while metal values must be equal, the same as
+//! [`ListingCurrencies::eq_approx`].
+
+use crate::ListingCurrencies;
+use approx::AbsDiffEq;
+
+impl AbsDiffEq for ListingCurrencies {
+    type Epsilon = f32;
+    
+    fn default_epsilon() -> Self::Epsilon {
+        f32::EPSILON
+    }
+    
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.eq_approx(other, epsilon)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::{assert_abs_diff_eq, assert_abs_diff_ne};
+    
+    #[test]
+    fn compares_within_epsilon() {
+        let currencies = ListingCurrencies { keys: 1.3 - 1.0, metal: 4 };
+        
+        assert_abs_diff_eq!(currencies, ListingCurrencies { keys: 0.3, metal: 4 });
+        assert_abs_diff_eq!(currencies, ListingCurrencies { keys: 0.35, metal: 4 }, epsilon = 0.1);
+        assert_abs_diff_ne!(currencies, ListingCurrencies { keys: 0.3, metal: 5 });
+    }
+}
@@ -0,0 +1,184 @@
+//! [`arbitrary`] and [`proptest`] implementations for generating values in fuzz and property 
+//! tests. Available with the `arbitrary` and `proptest` features.
+//! 
+//! Generated values are mostly within typical trading ranges, with some values at the limits of
+//! [`Currency`] to exercise saturating arithmetic and overflow handling.
+
+use crate::types::Currency;
+use crate::constants::ONE_REF;
+
+/// Values at the limits of [`Currency`].
+const BOUNDARY_VALUES: [Currency; 7] = [
+    0,
+    1,
+    -1,
+    Currency::MAX,
+    Currency::MAX - 1,
+    Currency::MIN,
+    Currency::MIN + 1,
+];
+/// Key floats at the limits of [`f32`] which are still finite.
+const BOUNDARY_KEY_FLOATS: [f32; 5] = [0.0, f32::MIN_POSITIVE, -f32::MIN_POSITIVE, f32::MAX, f32::MIN];
+/// The largest number of keys in typical values.
+const TYPICAL_KEYS: Currency = 10_000;
+/// The largest metal value in typical values.
+const TYPICAL_METAL: Currency = ONE_REF * 1_000;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls {
+    use super::*;
+    use crate::{Currencies, ListingCurrencies, Rounding};
+    use arbitrary::{Arbitrary, Result, Unstructured};
+    
+    /// Generates a value which is usually within `-typical..=typical`.
+    fn currency(u: &mut Unstructured<'_>, typical: Currency) -> Result<Currency> {
+        match u.int_in_range(0..=7)? {
+            0 => u.choose(&BOUNDARY_VALUES).copied(),
+            1 => u.arbitrary(),
+            _ => u.int_in_range(-typical..=typical),
+        }
+    }
+    
+    impl<'a> Arbitrary<'a> for Currencies {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self {
+                keys: currency(u, TYPICAL_KEYS)?,
+                metal: currency(u, TYPICAL_METAL)?,
+            })
+        }
+    }
+    
+    impl<'a> Arbitrary<'a> for ListingCurrencies {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            let keys = if u.ratio(1, 8)? {
+                u.choose(&BOUNDARY_KEY_FLOATS).copied()?
+            } else {
+                // keys are usually listed with up to 2 decimal places
+                u.int_in_range(-TYPICAL_KEYS * 100..=TYPICAL_KEYS * 100)? as f32 / 100.0
+            };
+            
+            Ok(Self {
+                keys,
+                metal: currency(u, TYPICAL_METAL)?,
+            })
+        }
+    }
+    
+    impl<'a> Arbitrary<'a> for Rounding {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            u.choose(&Rounding::ALL).copied()
+        }
+    }
+}
+
+#[cfg(feature = "proptest")]
+mod proptest_impls {
+    use super::*;
+    use crate::{Currencies, ListingCurrencies, Rounding};
+    use proptest::prelude::*;
+    use proptest::sample::select;
+    
+    /// Generates a value which is usually within `-typical..=typical`.
+    fn currency(typical: Currency) -> impl Strategy<Value = Currency> {
+        prop_oneof![
+            6 => -typical..=typical,
+            1 => select(BOUNDARY_VALUES.to_vec()),
+            1 => any::<Currency>(),
+        ]
+    }
+    
+    impl Arbitrary for Currencies {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+        
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            (currency(TYPICAL_KEYS), currency(TYPICAL_METAL))
+                .prop_map(|(keys, metal)| Self { keys, metal })
+                .boxed()
+        }
+    }
+    
+    impl Arbitrary for ListingCurrencies {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+        
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            let keys = prop_oneof![
+                // keys are usually listed with up to 2 decimal places
+                7 => (-TYPICAL_KEYS * 100..=TYPICAL_KEYS * 100).prop_map(|keys| keys as f32 / 100.0),
+                1 => select(BOUNDARY_KEY_FLOATS.to_vec()),
+            ];
+            
+            (keys, currency(TYPICAL_METAL))
+                .prop_map(|(keys, metal)| Self { keys, metal })
+                .boxed()
+        }
+    }
+    
+    impl Arbitrary for Rounding {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+        
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            select(Rounding::ALL.to_vec()).boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn generates_arbitrary_values() {
+        use crate::{Currencies, ListingCurrencies, Rounding};
+        use arbitrary::{Arbitrary, Unstructured};
+        
+        let bytes = (0..=255).cycle().take(4096).collect::<Vec<u8>>();
+        let mut u = Unstructured::new(&bytes);
+        
+        while !u.is_empty() {
+            let _ = Currencies::arbitrary(&mut u).unwrap();
+            let listing_currencies = ListingCurrencies::arbitrary(&mut u).unwrap();
+            let _ = Rounding::arbitrary(&mut u).unwrap();
+            
+            assert!(listing_currencies.keys.is_finite());
+        }
+    }
+    
+    #[cfg(feature = "proptest")]
+    mod proptests {
+        use crate::{Currencies, ListingCurrencies, Rounding, ONE_REF};
+        use proptest::prelude::*;
+        
+        proptest! {
+            #[test]
+            fn parses_display_round_trip(currencies: Currencies) {
+                // metal is parsed as a float so only values within its precision round trip
+                prop_assume!(currencies.metal.unsigned_abs() < ONE_REF as u64 * 1_000_000_000_000);
+                prop_assume!(currencies != Currencies::default());
+                
+                prop_assert_eq!(currencies.to_string().parse::<Currencies>().unwrap(), currencies);
+            }
+            
+            #[test]
+            fn parses_any_string_without_panicking(string in "\\PC*") {
+                let _ = string.parse::<Currencies>();
+                let _ = string.parse::<ListingCurrencies>();
+            }
+            
+            #[test]
+            fn parses_currency_like_strings_without_panicking(
+                string in r"-?\(?[0-9.,]{0,24} ?(keys?|ref|refined|rec|scrap|k|r)?(, ?-?[0-9.]{0,24} ?(ref|k|r)?)?\)?",
+            ) {
+                let _ = string.parse::<Currencies>();
+                let _ = string.parse::<ListingCurrencies>();
+            }
+            
+            #[test]
+            fn generates_finite_listing_keys(currencies: ListingCurrencies, rounding: Rounding) {
+                prop_assert!(currencies.keys.is_finite());
+                prop_assert!(Rounding::ALL.contains(&rounding));
+            }
+        }
+    }
+}
@@ -0,0 +1,402 @@
+//! Types for the backpack.tf pricing API (`IGetPrices`) and websocket listing events.
+//! 
+//! # Examples
+//! ```
+//! use tf2_price::{Converter, Currencies, refined};
+//! use tf2_price::backpack_tf::Price;
+//! 
+//! let json = r#"{
+//!     "currency": "keys",
+//!     "value": 2,
+//!     "value_high": 2.5,
+//!     "value_raw": 100.44,
+//!     "last_update": 1672531200,
+//!     "difference": 0
+//! }"#;
+//! let price: Price = serde_json::from_str(json).unwrap();
+//! let converter = Converter::new(refined!(50), None);
+//! let range = price.to_price_range(&converter).unwrap();
+//! 
+//! assert_eq!(range.low, Currencies { keys: 2, metal: 0 });
+//! assert_eq!(range.high, Currencies { keys: 2, metal: refined!(25) });
+//! ```
+
+use crate::helpers;
+use crate::types::Currency;
+use crate::constants::{ONE_REF, ONE_HAT};
+use crate::{Converter, Currencies, ListingCurrencies, PriceRange, Rounding};
+use serde::{Serialize, Deserialize};
+use alloc::string::String;
+
+/// The currency a backpack.tf price is given in.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum PriceCurrency {
+    /// Keys.
+    Keys,
+    /// Refined metal.
+    Metal,
+    /// Craft hats.
+    Hat,
+    /// US dollars.
+    Usd,
+}
+
+/// A price from the backpack.tf pricing API.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
+pub struct Price {
+    /// The currency `value` and `value_high` are given in.
+    pub currency: PriceCurrency,
+    /// The price, or the low end of the price if `value_high` is given.
+    pub value: f64,
+    /// The high end of the price.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value_high: Option<f64>,
+    /// The price in refined metal.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value_raw: Option<f64>,
+    /// When the price was last updated as a Unix timestamp in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_update: Option<i64>,
+    /// The change in refined metal from the previous price.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub difference: Option<f64>,
+}
+
+impl Price {
+    /// Converts `value` to currencies using the given converter. `None` if the price is in US 
+    /// dollars and the converter does not have a cash value for keys.
+    /// 
+    /// # Panics
+    /// Panics if the converter's key price is `0`.
+    pub fn to_currencies(&self, converter: &Converter) -> Option<Currencies> {
+        self.value_to_currencies(self.value, converter)
+    }
+    
+    /// Converts `value` and `value_high` to a price range using the given converter. `None` if 
+    /// the price is in US dollars and the converter does not have a cash value for keys, or 
+    /// `value_high` is less than `value`.
+    /// 
+    /// # Panics
+    /// Panics if the converter's key price is `0`.
+    pub fn to_price_range(&self, converter: &Converter) -> Option<PriceRange> {
+        let low = self.to_currencies(converter)?;
+        let high = match self.value_high {
+            Some(value_high) => self.value_to_currencies(value_high, converter)?,
+            None => low,
+        };
+        
+        PriceRange::new(low, high, converter.key_price).ok()
+    }
+    
+    /// `value_raw` as a metal value (represented as weapons).
+    pub fn raw_metal(&self) -> Option<Currency> {
+        self.value_raw
+            .map(|value_raw| helpers::round_metal_float(value_raw * ONE_REF as f64, &Rounding::None))
+    }
+    
+    fn value_to_currencies(&self, value: f64, converter: &Converter) -> Option<Currencies> {
+        let metal = match self.currency {
+            PriceCurrency::Keys => value * converter.key_price as f64,
+            PriceCurrency::Metal => value * ONE_REF as f64,
+            PriceCurrency::Hat => value * ONE_HAT as f64,
+            PriceCurrency::Usd => {
+                let cents = value * 100.0;
+                
+                cents / converter.usd_key_price? as f64 * converter.key_price as f64
+            },
+        };
+        
+        Some(converter.to_currencies(helpers::round_metal_float(metal, &Rounding::None)))
+    }
+}
+
+/// Tolerantly deserializes the `currencies` of backpack.tf listings as [`ListingCurrencies`]. 
+/// Either field may be missing or `null`, and values may be integers, floats or numeric strings. 
+/// Unlike deserializing [`ListingCurrencies`] directly, currencies without any value are 
+/// accepted.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{ListingCurrencies, refined};
+/// use serde::Deserialize;
+/// 
+/// #[derive(Deserialize)]
+/// struct Listing {
+///     #[serde(with = "tf2_price::backpack_tf::listing_currencies")]
+///     currencies: ListingCurrencies,
+/// }
+/// 
+/// let listing: Listing = serde_json::from_str(r#"{"currencies":{"keys":"1.5","metal":null}}"#).unwrap();
+/// 
+/// assert_eq!(listing.currencies, ListingCurrencies { keys: 1.5, metal: 0 });
+/// ```
+pub mod listing_currencies {
+    use crate::constants::ONE_REF;
+    use crate::types::Currency;
+    use crate::ListingCurrencies;
+    #[cfg(not(feature = "std"))]
+    use crate::float::Float;
+    use core::fmt;
+    use serde::{Serialize, Deserialize, Serializer, Deserializer};
+    use serde::de::{self, Visitor};
+    
+    #[derive(Deserialize)]
+    struct TolerantCurrencies {
+        #[serde(default, deserialize_with = "deserialize_number")]
+        keys: f64,
+        #[serde(default, deserialize_with = "deserialize_number")]
+        metal: f64,
+    }
+    
+    struct NumberVisitor;
+    
+    impl<'de> Visitor<'de> for NumberVisitor {
+        type Value = f64;
+        
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a number, numeric string, or null")
+        }
+        
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<f64, E> {
+            Ok(value as f64)
+        }
+        
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<f64, E> {
+            Ok(value as f64)
+        }
+        
+        fn visit_f64<E: de::Error>(self, value: f64) -> Result<f64, E> {
+            Ok(value)
+        }
+        
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<f64, E> {
+            let value = value.trim();
+            
+            if value.is_empty() {
+                return Ok(0.0);
+            }
+            
+            value.parse().map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+        }
+        
+        fn visit_none<E: de::Error>(self) -> Result<f64, E> {
+            Ok(0.0)
+        }
+        
+        fn visit_unit<E: de::Error>(self) -> Result<f64, E> {
+            Ok(0.0)
+        }
+        
+        fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<f64, D::Error> {
+            deserializer.deserialize_any(self)
+        }
+    }
+    
+    fn deserialize_number<'de, D>(deserializer: D) -> Result<f64, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        let value = deserializer.deserialize_any(NumberVisitor)?;
+        
+        if !value.is_finite() {
+            return Err(de::Error::custom("Number is not finite"));
+        }
+        
+        Ok(value)
+    }
+    
+    pub fn serialize<S>(currencies: &ListingCurrencies, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        currencies.serialize(serializer)
+    }
+    
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<ListingCurrencies, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        let currencies = TolerantCurrencies::deserialize(deserializer)?;
+        let keys = currencies.keys as f32;
+        
+        // Finite values beyond the range of f32 become infinite when narrowed.
+        if !keys.is_finite() {
+            return Err(de::Error::custom("Key value is not finite"));
+        }
+        
+        Ok(ListingCurrencies {
+            keys,
+            metal: (currencies.metal * ONE_REF as f64).round() as Currency,
+        })
+    }
+}
+
+/// The intent of a backpack.tf listing.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum ListingIntent {
+    /// Buying the item.
+    Buy,
+    /// Selling the item.
+    Sell,
+}
+
+/// A listing from a backpack.tf websocket event. Only the fields related to pricing are included.
+#[derive(Debug, Deserialize, PartialEq, Clone)]
+pub struct Listing {
+    /// The ID of the listing.
+    pub id: String,
+    /// The SteamID of the user who created the listing.
+    #[serde(default)]
+    pub steamid: Option<String>,
+    /// The intent of the listing.
+    pub intent: ListingIntent,
+    /// The price of the listing.
+    #[serde(default, with = "listing_currencies")]
+    pub currencies: ListingCurrencies,
+    /// The listing's comment.
+    #[serde(default)]
+    pub details: Option<String>,
+}
+
+/// An event from the backpack.tf websocket.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{ListingCurrencies, refined};
+/// use tf2_price::backpack_tf::ListingEvent;
+/// 
+/// let json = r#"{
+///     "id": "1",
+///     "event": "listing-update",
+///     "payload": {
+///         "id": "440_123",
+///         "steamid": "76561198000000000",
+///         "intent": "sell",
+///         "currencies": { "keys": 2, "metal": 1.5 },
+///         "details": "Selling!"
+///     }
+/// }"#;
+/// let event: ListingEvent = serde_json::from_str(json).unwrap();
+/// 
+/// assert_eq!(event.payload.currencies, ListingCurrencies { keys: 2.0, metal: 27 });
+/// ```
+#[derive(Debug, Deserialize, PartialEq, Clone)]
+pub struct ListingEvent {
+    /// The ID of the event.
+    #[serde(default)]
+    pub id: Option<String>,
+    /// The type of event e.g. "listing-update" or "listing-delete".
+    pub event: String,
+    /// The listing.
+    pub payload: Listing,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{refined, scrap};
+    
+    fn price(currency: PriceCurrency, value: f64) -> Price {
+        Price {
+            currency,
+            value,
+            value_high: None,
+            value_raw: None,
+            last_update: None,
+            difference: None,
+        }
+    }
+    
+    #[test]
+    fn converts_metal_price() {
+        let converter = Converter::new(refined!(50), None);
+        
+        assert_eq!(price(PriceCurrency::Metal, 1.33).to_currencies(&converter), Some(Currencies {
+            keys: 0,
+            metal: refined!(1) + scrap!(3),
+        }));
+    }
+    
+    #[test]
+    fn converts_metal_price_over_key_price() {
+        let converter = Converter::new(refined!(50), None);
+        
+        assert_eq!(price(PriceCurrency::Metal, 55.0).to_currencies(&converter), Some(Currencies {
+            keys: 1,
+            metal: refined!(5),
+        }));
+    }
+    
+    #[test]
+    fn converts_hat_price() {
+        let converter = Converter::new(refined!(50), None);
+        
+        assert_eq!(price(PriceCurrency::Hat, 1.5).to_currencies(&converter), Some(Currencies {
+            keys: 0,
+            metal: refined!(2),
+        }));
+    }
+    
+    #[test]
+    fn converts_usd_price() {
+        let price = price(PriceCurrency::Usd, 3.6);
+        
+        assert_eq!(price.to_currencies(&Converter::new(refined!(50), Some(180))), Some(Currencies {
+            keys: 2,
+            metal: 0,
+        }));
+        assert_eq!(price.to_currencies(&Converter::new(refined!(50), None)), None);
+    }
+    
+    #[test]
+    fn converts_raw_metal() {
+        let mut price = price(PriceCurrency::Keys, 1.0);
+        
+        price.value_raw = Some(50.11);
+        
+        assert_eq!(price.raw_metal(), Some(refined!(50) + scrap!(1)));
+    }
+    
+    #[test]
+    fn deserializes_tolerant_listing_currencies() {
+        #[derive(Deserialize)]
+        struct Listing {
+            #[serde(with = "listing_currencies")]
+            currencies: ListingCurrencies,
+        }
+        
+        let cases = [
+            (r#"{"currencies":{}}"#, ListingCurrencies { keys: 0.0, metal: 0 }),
+            (r#"{"currencies":{"keys":null,"metal":1.11}}"#, ListingCurrencies { keys: 0.0, metal: refined!(1) + scrap!(1) }),
+            (r#"{"currencies":{"keys":3}}"#, ListingCurrencies { keys: 3.0, metal: 0 }),
+            (r#"{"currencies":{"keys":1.5,"metal":"2"}}"#, ListingCurrencies { keys: 1.5, metal: refined!(2) }),
+            (r#"{"currencies":{"metal":"","usd":5}}"#, ListingCurrencies { keys: 0.0, metal: 0 }),
+        ];
+        
+        for (json, expected) in cases {
+            assert_eq!(serde_json::from_str::<Listing>(json).unwrap().currencies, expected);
+        }
+        
+        assert!(serde_json::from_str::<Listing>(r#"{"currencies":{"keys":"one"}}"#).is_err());
+        assert!(serde_json::from_str::<Listing>(r#"{"currencies":{"keys":1e39}}"#).is_err());
+    }
+    
+    #[test]
+    fn deserializes_listing_without_currencies() {
+        let json = r#"{"event":"listing-delete","payload":{"id":"440_1","intent":"buy"}}"#;
+        let event: ListingEvent = serde_json::from_str(json).unwrap();
+        
+        assert_eq!(event.payload.intent, ListingIntent::Buy);
+        assert!(event.payload.currencies.is_empty());
+    }
+    
+    #[test]
+    fn deserializes_price_without_optional_fields() {
+        let price: Price = serde_json::from_str(r#"{"currency":"metal","value":0.05}"#).unwrap();
+        
+        assert_eq!(price.currency, PriceCurrency::Metal);
+        assert_eq!(price.value_high, None);
+    }
+}
@@ -0,0 +1,195 @@
+//! Conversions between [`Currencies`] and BSON documents for storage in MongoDB. Available with 
+//! the `bson` feature.
+//! 
+//! Serializing [`Currencies`] using serde writes metal as an integer when the refined value is 
+//! whole and as a double otherwise, which makes the stored type depend on the value. The 
+//! functions in this module always write the same types so that documents can be queried 
+//! consistently: keys are written as 64-bit integers and metal as the chosen [`MetalType`].
+//! 
+//! # Examples
+//! ```
+//! use tf2_price::{Currencies, refined, scrap};
+//! use tf2_price::bson::{self, MetalType};
+//! 
+//! let currencies = Currencies { keys: 2, metal: refined!(3) };
+//! let document = bson::to_document(&currencies, MetalType::Double).unwrap();
+//! 
+//! assert_eq!(document.get_i64("keys").unwrap(), 2);
+//! assert_eq!(document.get_f64("metal").unwrap(), 3.0);
+//! assert_eq!(bson::from_document(&document, MetalType::Double).unwrap(), currencies);
+//! ```
+
+use crate::helpers;
+use crate::types::Currency;
+use crate::error::BsonError;
+use crate::constants::ONE_REF;
+use crate::Currencies;
+use ::bson::{Bson, Document};
+
+const KEYS_FIELD: &str = "keys";
+const METAL_FIELD: &str = "metal";
+
+/// The BSON type metal values are stored as.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum MetalType {
+    /// Refined values as doubles truncated to 2 decimal places, e.g. `3.33`. This matches the 
+    /// values used in JSON.
+    #[default]
+    Double,
+    /// Exact weapon values as 32-bit integers, e.g. `60` for 3.33 refined.
+    Int32,
+}
+
+/// Converts currencies to a BSON document. Errors if the metal value does not fit into a 32-bit 
+/// integer when using [`MetalType::Int32`].
+pub fn to_document(currencies: &Currencies, metal_type: MetalType) -> Result<Document, BsonError> {
+    let metal = match metal_type {
+        MetalType::Double => Bson::Double(helpers::get_metal_float_f64(currencies.metal)),
+        MetalType::Int32 => i32::try_from(currencies.metal)
+            .map(Bson::Int32)
+            .map_err(|_| BsonError::OutOfRange { field: METAL_FIELD })?,
+    };
+    let mut document = Document::new();
+    
+    document.insert(KEYS_FIELD, Bson::Int64(currencies.keys));
+    document.insert(METAL_FIELD, metal);
+    
+    Ok(document)
+}
+
+/// Converts a BSON document to currencies. Keys may be stored as any numeric type as long as the 
+/// value is whole. Missing fields are treated as `0`, but at least one value must be non-zero, 
+/// matching how [`Currencies`] are deserialized.
+pub fn from_document(document: &Document, metal_type: MetalType) -> Result<Currencies, BsonError> {
+    let keys = match document.get(KEYS_FIELD) {
+        Some(value) => get_integer(value, KEYS_FIELD)?,
+        None => 0,
+    };
+    let metal = match (document.get(METAL_FIELD), metal_type) {
+        (None, _) => 0,
+        (Some(value), MetalType::Int32) => get_integer(value, METAL_FIELD)?,
+        (Some(value), MetalType::Double) => {
+            let refined = get_float(value, METAL_FIELD)?;
+            
+            // will fit it into the nearest weapon value
+            (refined * ONE_REF as f64).round() as Currency
+        },
+    };
+    
+    if keys == 0 && metal == 0 {
+        return Err(BsonError::Empty);
+    }
+    
+    Ok(Currencies {
+        keys,
+        metal,
+    })
+}
+
+/// Gets a whole number from a numeric BSON value.
+fn get_integer(value: &Bson, field: &'static str) -> Result<Currency, BsonError> {
+    match *value {
+        Bson::Int32(value) => Ok(value.into()),
+        Bson::Int64(value) => Ok(value),
+        Bson::Double(value) if value.fract() == 0.0 => {
+            if value < Currency::MIN as f64 || value >= Currency::MAX as f64 {
+                return Err(BsonError::OutOfRange { field });
+            }
+            
+            Ok(value as Currency)
+        },
+        _ => Err(BsonError::InvalidType { field }),
+    }
+}
+
+/// Gets a finite float from a numeric BSON value.
+fn get_float(value: &Bson, field: &'static str) -> Result<f64, BsonError> {
+    match *value {
+        Bson::Int32(value) => Ok(value.into()),
+        Bson::Int64(value) => Ok(value as f64),
+        Bson::Double(value) if value.is_finite() => Ok(value),
+        _ => Err(BsonError::InvalidType { field }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{refined, scrap};
+    use ::bson::doc;
+    
+    #[test]
+    fn converts_to_document_with_double_metal() {
+        let currencies = Currencies { keys: 2, metal: refined!(3) + scrap!(3) };
+        let document = to_document(&currencies, MetalType::Double).unwrap();
+        
+        assert_eq!(document, doc! { "keys": 2_i64, "metal": 3.33 });
+        assert_eq!(from_document(&document, MetalType::Double).unwrap(), currencies);
+    }
+    
+    #[test]
+    fn converts_to_document_with_consistent_types() {
+        let currencies = Currencies { keys: 0, metal: refined!(3) };
+        
+        assert_eq!(to_document(&currencies, MetalType::Double).unwrap(), doc! {
+            "keys": 0_i64,
+            "metal": 3.0,
+        });
+        assert_eq!(to_document(&currencies, MetalType::Int32).unwrap(), doc! {
+            "keys": 0_i64,
+            "metal": refined!(3) as i32,
+        });
+    }
+    
+    #[test]
+    fn converts_to_document_with_int32_metal() {
+        let currencies = Currencies { keys: 1, metal: refined!(3) + scrap!(3) + 1 };
+        let document = to_document(&currencies, MetalType::Int32).unwrap();
+        
+        assert_eq!(document.get_i32("metal").unwrap(), 61);
+        assert_eq!(from_document(&document, MetalType::Int32).unwrap(), currencies);
+        assert_eq!(
+            to_document(&Currencies { keys: 0, metal: i64::MAX }, MetalType::Int32),
+            Err(BsonError::OutOfRange { field: "metal" }),
+        );
+    }
+    
+    #[test]
+    fn converts_from_document_with_any_numeric_type() {
+        let expected = Currencies { keys: 2, metal: refined!(3) };
+        
+        for document in [
+            doc! { "keys": 2_i32, "metal": 3_i32 },
+            doc! { "keys": 2_i64, "metal": 3_i64 },
+            doc! { "keys": 2.0, "metal": 3.0 },
+        ] {
+            assert_eq!(from_document(&document, MetalType::Double).unwrap(), expected);
+        }
+        
+        assert_eq!(
+            from_document(&doc! { "metal": 3.0 }, MetalType::Double).unwrap(),
+            Currencies { keys: 0, metal: refined!(3) },
+        );
+    }
+    
+    #[test]
+    fn converts_from_invalid_document() {
+        assert_eq!(
+            from_document(&doc! { "keys": 1.5 }, MetalType::Double),
+            Err(BsonError::InvalidType { field: "keys" }),
+        );
+        assert_eq!(
+            from_document(&doc! { "keys": 1_i64, "metal": "3" }, MetalType::Double),
+            Err(BsonError::InvalidType { field: "metal" }),
+        );
+        assert_eq!(
+            from_document(&doc! { "keys": 1_i64, "metal": 3.5 }, MetalType::Int32),
+            Err(BsonError::InvalidType { field: "metal" }),
+        );
+        assert_eq!(
+            from_document(&doc! { "keys": 1e30 }, MetalType::Double),
+            Err(BsonError::OutOfRange { field: "keys" }),
+        );
+        assert_eq!(from_document(&doc! {}, MetalType::Double), Err(BsonError::Empty));
+    }
+}
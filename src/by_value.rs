@@ -0,0 +1,111 @@
+use crate::helpers;
+use crate::types::Currency;
+use crate::Currencies;
+use core::cmp::{Ord, Ordering};
+
+/// Wraps [`Currencies`] along with a key price (represented as weapons) so that they are ordered 
+/// by their total value rather than field-wise. Useful for sorting listings or using 
+/// [`std::collections::BTreeMap`] keys by effective value.
+/// 
+/// Two [`ByValue`] are equal when their total values are equal, e.g. `1 key` is equal to `50 ref` 
+/// at a key price of `50 ref`.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{ByValue, Currencies, refined};
+/// 
+/// let key_price = refined!(50);
+/// let mut listings = vec![
+///     ByValue::new(Currencies { keys: 1, metal: 0 }, key_price),
+///     ByValue::new(Currencies { keys: 0, metal: refined!(60) }, key_price),
+///     ByValue::new(Currencies { keys: 0, metal: refined!(10) }, key_price),
+/// ];
+/// 
+/// listings.sort();
+/// 
+/// assert_eq!(listings[0].currencies, Currencies { keys: 0, metal: refined!(10) });
+/// assert_eq!(listings[1].currencies, Currencies { keys: 1, metal: 0 });
+/// assert_eq!(listings[2].currencies, Currencies { keys: 0, metal: refined!(60) });
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ByValue {
+    /// The currencies.
+    pub currencies: Currencies,
+    /// The key price used to value the currencies.
+    pub key_price: Currency,
+}
+
+impl ByValue {
+    /// Creates a new [`ByValue`].
+    pub fn new(currencies: Currencies, key_price: Currency) -> Self {
+        Self {
+            currencies,
+            key_price,
+        }
+    }
+    
+    /// The total value of the currencies as weapons.
+    fn value(&self) -> i128 {
+        helpers::to_metal_wide(self.currencies.metal, self.currencies.keys, self.key_price)
+    }
+}
+
+impl PartialEq for ByValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for ByValue {}
+
+impl PartialOrd for ByValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+       Some(self.cmp(other))
+    }
+}
+
+impl Ord for ByValue {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value().cmp(&other.value())
+    }
+}
+
+impl From<ByValue> for Currencies {
+    fn from(by_value: ByValue) -> Currencies {
+        by_value.currencies
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::refined;
+    
+    #[test]
+    fn equal_by_value() {
+        let key_price = refined!(50);
+        
+        assert_eq!(
+            ByValue::new(Currencies { keys: 1, metal: 0 }, key_price),
+            ByValue::new(Currencies { keys: 0, metal: refined!(50) }, key_price),
+        );
+    }
+    
+    #[test]
+    fn greater_than_by_value() {
+        let key_price = refined!(50);
+        
+        assert!(
+            ByValue::new(Currencies { keys: 0, metal: refined!(51) }, key_price) >
+            ByValue::new(Currencies { keys: 1, metal: 0 }, key_price)
+        );
+    }
+    
+    #[test]
+    fn uses_own_key_price() {
+        assert!(
+            ByValue::new(Currencies { keys: 1, metal: 0 }, refined!(60)) >
+            ByValue::new(Currencies { keys: 1, metal: 0 }, refined!(50))
+        );
+    }
+}
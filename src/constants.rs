@@ -1,6 +1,8 @@
 use crate::types::Currency;
 
-/// Value for one weapon.
+/// Value for one weapon. This is the smallest unit of metal, worth half a scrap. All metal values 
+/// are represented as a number of weapons, so prices such as `0.05 ref` (one weapon) need no 
+/// special handling.
 pub const ONE_WEAPON: Currency = 1;
 /// Value for one scrap metal.
 pub const ONE_SCRAP: Currency = ONE_WEAPON * 2;
@@ -8,6 +10,9 @@ pub const ONE_SCRAP: Currency = ONE_WEAPON * 2;
 pub const ONE_REC: Currency = ONE_SCRAP * 3;
 /// Value for one refined metal.
 pub const ONE_REF: Currency = ONE_REC * 3;
+/// Value for one craft hat. By convention a craft hat is worth `1.33 ref`, or three scrap over one 
+/// refined metal.
+pub const ONE_HAT: Currency = ONE_REF + ONE_SCRAP * 3;
 
 /// Symbol for one key.
 pub const KEY_SYMBOL: &str = "key";
@@ -15,5 +20,13 @@ pub const KEY_SYMBOL: &str = "key";
 pub const KEYS_SYMBOL: &str = "keys";
 /// Symbol for metal.
 pub const METAL_SYMBOL: &str = "ref";
+/// Long-form symbol for refined metal.
+pub const REFINED_SYMBOL: &str = "refined";
+/// Symbol for reclaimed metal.
+pub const REC_SYMBOL: &str = "rec";
+/// Long-form symbol for reclaimed metal.
+pub const RECLAIMED_SYMBOL: &str = "reclaimed";
+/// Symbol for scrap metal.
+pub const SCRAP_SYMBOL: &str = "scrap";
 /// Symbol for empty currencies.
 pub const EMPTY_SYMBOL: &str = "nothing";
\ No newline at end of file
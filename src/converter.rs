@@ -0,0 +1,173 @@
+use crate::helpers;
+use crate::types::Currency;
+use crate::{Currencies, KeyPrice, Rounding, Side, USDCurrencies};
+#[cfg(not(feature = "std"))]
+use crate::float::Float;
+
+/// Converts between currencies, metal and cash values using a fixed key price, so that the key 
+/// price does not need to be passed to every conversion.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Converter, Currencies, USDCurrencies, refined};
+/// 
+/// // Keys are 50 ref or $1.80 each.
+/// let converter = Converter::new(refined!(50), Some(180));
+/// let currencies = Currencies { keys: 1, metal: refined!(25) };
+/// 
+/// assert_eq!(converter.to_metal(&currencies), refined!(75));
+/// assert_eq!(converter.to_keys_f32(&currencies), 1.5);
+/// assert_eq!(converter.to_currencies(refined!(75)), currencies);
+/// assert_eq!(converter.to_usd(&currencies), Some(USDCurrencies { usd: 270 }));
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Converter {
+    /// The key price (represented as weapons).
+    pub key_price: Currency,
+    /// The cash value of one key in cents, if known.
+    pub usd_key_price: Option<Currency>,
+}
+
+impl Converter {
+    /// Creates a new [`Converter`].
+    pub fn new(key_price: Currency, usd_key_price: Option<Currency>) -> Self {
+        Self {
+            key_price,
+            usd_key_price,
+        }
+    }
+    
+    /// Creates a new [`Converter`] using the given side of a [`KeyPrice`], e.g. the sell price 
+    /// when valuing items being sold.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Converter, Currencies, KeyPrice, Side, refined};
+    /// 
+    /// let key_price = KeyPrice::new(refined!(50), refined!(51)).unwrap();
+    /// let converter = Converter::from_key_price(&key_price, Side::Sell, None);
+    /// 
+    /// assert_eq!(converter.key_price, refined!(51));
+    /// assert_eq!(converter.to_metal(&Currencies { keys: 1, metal: 0 }), refined!(51));
+    /// ```
+    pub fn from_key_price(
+        key_price: &KeyPrice,
+        side: Side,
+        usd_key_price: Option<Currency>,
+    ) -> Self {
+        Self::new(key_price.price(side), usd_key_price)
+    }
+    
+    /// Converts currencies to a metal value. This method is saturating.
+    pub fn to_metal(&self, currencies: &Currencies) -> Currency {
+        currencies.to_metal(self.key_price)
+    }
+    
+    /// Converts currencies to a key value.
+    pub fn to_keys_f32(&self, currencies: &Currencies) -> f32 {
+        (self.to_metal(currencies) as f64 / self.key_price as f64) as f32
+    }
+    
+    /// Converts a metal value (represented as weapons) to currencies.
+    /// 
+    /// # Panics
+    /// Panics if the key price is `0`.
+    pub fn to_currencies(&self, metal: Currency) -> Currencies {
+        Currencies::from_metal(metal, self.key_price)
+    }
+    
+    /// Converts currencies to a cash value, rounded to the nearest cent. `None` if no cash value 
+    /// for keys is set.
+    pub fn to_usd(&self, currencies: &Currencies) -> Option<USDCurrencies> {
+        let usd_key_price = self.usd_key_price?;
+        let metal = self.to_metal(currencies) as f64;
+        let usd = (metal / self.key_price as f64 * usd_key_price as f64).round() as Currency;
+        
+        Some(USDCurrencies {
+            usd,
+        })
+    }
+    
+    /// Converts a cash value to currencies, rounding the metal value to the nearest weapon. `None` 
+    /// if no cash value for keys is set.
+    /// 
+    /// # Panics
+    /// Panics if the key price is `0`.
+    pub fn from_usd(&self, usd: &USDCurrencies) -> Option<Currencies> {
+        let usd_key_price = self.usd_key_price?;
+        let metal = usd.usd as f64 / usd_key_price as f64 * self.key_price as f64;
+        
+        Some(self.to_currencies(helpers::round_metal_float(metal, &Rounding::None)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{refined, scrap};
+    
+    #[test]
+    fn converts_to_metal() {
+        let converter = Converter::new(refined!(50), None);
+        
+        assert_eq!(converter.to_metal(&Currencies {
+            keys: 2,
+            metal: scrap!(1),
+        }), refined!(100) + scrap!(1));
+    }
+    
+    #[test]
+    fn converts_to_currencies() {
+        let converter = Converter::new(refined!(50), None);
+        
+        assert_eq!(converter.to_currencies(refined!(120)), Currencies {
+            keys: 2,
+            metal: refined!(20),
+        });
+    }
+    
+    #[test]
+    fn converts_to_usd_rounding_to_nearest_cent() {
+        let converter = Converter::new(refined!(50), Some(199));
+        
+        assert_eq!(converter.to_usd(&Currencies {
+            keys: 0,
+            metal: refined!(25),
+        }), Some(USDCurrencies {
+            usd: 100,
+        }));
+    }
+    
+    #[test]
+    fn converts_from_usd() {
+        let converter = Converter::new(refined!(50), Some(180));
+        
+        assert_eq!(converter.from_usd(&USDCurrencies { usd: 270 }), Some(Currencies {
+            keys: 1,
+            metal: refined!(25),
+        }));
+        assert_eq!(Converter::new(refined!(50), None).from_usd(&USDCurrencies { usd: 270 }), None);
+    }
+    
+    #[test]
+    fn converts_to_usd_without_usd_key_price() {
+        let converter = Converter::new(refined!(50), None);
+        
+        assert_eq!(converter.to_usd(&Currencies {
+            keys: 1,
+            metal: 0,
+        }), None);
+    }
+    
+    #[test]
+    fn creates_from_key_price_side() {
+        let key_price = KeyPrice::new(refined!(50), refined!(51)).unwrap();
+        let currencies = Currencies { keys: 1, metal: 0 };
+        
+        assert_eq!(Converter::from_key_price(&key_price, Side::Buy, None).to_metal(&currencies), refined!(50));
+        assert_eq!(
+            Converter::from_key_price(&key_price, Side::Sell, Some(180)),
+            Converter::new(refined!(51), Some(180)),
+        );
+    }
+}
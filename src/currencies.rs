@@ -1,27 +1,40 @@
 use crate::helpers;
 use crate::types::Currency;
 use crate::traits::SerializeCurrencies;
-use crate::error::{TryFromListingCurrenciesError, ParseError};
-use crate::constants::{KEYS_SYMBOL, KEY_SYMBOL, METAL_SYMBOL, EMPTY_SYMBOL};
-use crate::{ListingCurrencies, Rounding};
-use std::fmt;
-use std::cmp::{Ord, Ordering};
-use std::ops::{self, AddAssign, SubAssign, MulAssign, DivAssign};
+use crate::error::{TryFromListingCurrenciesError, ParseError, PaymentError};
+use crate::constants::ONE_SCRAP;
+use crate::parse::{self, ParseOptions};
+use crate::format::{FormatOptions, CurrenciesDisplay, KeysDisplay};
+use crate::{ListingCurrencies, Rounding, RoundingDirection, RoundMetal, Denomination, Stock, Payment};
+#[cfg(not(feature = "std"))]
+use crate::float::Float;
+use core::fmt;
+use core::str::FromStr;
+use core::iter::Sum;
+use core::cmp::{Ord, Ordering};
+use core::ops::{self, AddAssign, SubAssign, MulAssign, DivAssign};
 use serde::{Serialize, Deserialize, Serializer, Deserializer};
 use serde::de::Error;
 use serde::ser::SerializeStruct;
 
 /// For storing item currencies values.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "diesel", derive(diesel::expression::AsExpression))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Jsonb))]
 #[serde(remote = "Self")]
 pub struct Currencies {
     /// Amount of keys.
     #[serde(default)]
+    #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>", optional))]
     pub keys: Currency,
     /// Amount of metal expressed as weapons. A metal value of 6 would be equivalent to 3 scrap. 
     /// It's recommended to use the `ONE_REF`, `ONE_REC`, `ONE_SCRAP`, and `ONE_WEAPON` constants 
     /// to perform arithmatic.
     #[serde(deserialize_with = "helpers::metal_deserializer", default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "f64"))]
+    #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>", optional))]
     pub metal: Currency,
 }
 
@@ -31,6 +44,9 @@ impl PartialOrd for Currencies {
     }
 }
 
+/// Currencies are ordered lexicographically by `keys` and then by `metal`. This does not take a 
+/// key price into account, so `1 key` is always greater than any amount of metal without keys. Use 
+/// [`Currencies::cmp_with_key_price`] or [`ByValue`](crate::ByValue) to compare by total value.
 impl Ord for Currencies {
     fn cmp(&self, other:&Self) -> Ordering {
         if self.keys > other.keys {
@@ -56,16 +72,31 @@ impl Default for Currencies {
 }
 
 impl Currencies {
+    /// [`Currencies`] with `0` keys and `0` metal.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::Currencies;
+    /// 
+    /// assert!(Currencies::ZERO.is_empty());
+    /// assert_eq!(Currencies::ZERO, Currencies::new());
+    /// ```
+    pub const ZERO: Self = Self {
+        keys: 0,
+        metal: 0,
+    };
+    
     /// Creates a new [`Currencies`] with `0` keys and `0` metal.
-    pub fn new() -> Self {
-        Self {
-            keys: 0,
-            metal: 0,
-        }
+    pub const fn new() -> Self {
+        Self::ZERO
     }
     
     /// Converts a metal value into the appropriate number of keys using the given key price 
-    /// (represented as weapons).
+    /// (represented as weapons). This is the inverse of [`Currencies::to_metal`].
+    /// 
+    /// # Panics
+    /// Panics if `key_price` is `0`. Use [`Currencies::checked_from_metal`] if the key price may 
+    /// be `0`.
     /// 
     /// # Examples
     /// ```
@@ -76,7 +107,7 @@ impl Currencies {
     /// 
     /// assert_eq!(currencies, Currencies { keys: 1, metal: refined!(20) });
     /// ```
-    pub fn from_metal(metal: Currency, key_price: Currency) -> Self {
+    pub const fn from_metal(metal: Currency, key_price: Currency) -> Self {
         Self {
             // Will be 0 if metal is 30 and key price is 32 (rounds down)
             keys: metal / key_price,
@@ -84,6 +115,30 @@ impl Currencies {
         }
     }
     
+    /// Converts a metal value into the appropriate number of keys using the given key price 
+    /// (represented as weapons). `None` if `key_price` is `0` or the division results in overflow.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// assert_eq!(
+    ///     Currencies::checked_from_metal(refined!(80), refined!(60)),
+    ///     Some(Currencies { keys: 1, metal: refined!(20) }),
+    /// );
+    /// assert_eq!(Currencies::checked_from_metal(refined!(80), 0), None);
+    /// ```
+    pub const fn checked_from_metal(metal: Currency, key_price: Currency) -> Option<Self> {
+        let Some(keys) = metal.checked_div(key_price) else {
+            return None;
+        };
+        let Some(metal) = metal.checked_rem(key_price) else {
+            return None;
+        };
+        
+        Some(Self { keys, metal })
+    }
+    
     /// Converts from [`ListingCurrencies`] using the given key price (represented as weapons).
     /// 
     /// # Examples
@@ -101,7 +156,7 @@ impl Currencies {
         currencies: ListingCurrencies,
         key_price: Currency,
     ) -> Self {
-        let keys_metal = ((currencies.keys % 1.0) * key_price as f32).round() as Currency;
+        let keys_metal = ((currencies.keys as f64 % 1.0) * key_price as f64).round() as Currency;
         
         Self {
             keys: currencies.keys as Currency,
@@ -125,10 +180,87 @@ impl Currencies {
     pub fn from_keys_f32(keys: f32, key_price: Currency) -> Self {
         Self {
             keys: keys as Currency,
-            metal: ((keys % 1.0) * key_price as f32) as Currency
+            metal: ((keys as f64 % 1.0) * key_price as f64) as Currency
+        }
+    }
+    
+    /// Converts an f64 key value into `Currencies` using the given key price represented as 
+    /// weapons. Unlike [`Currencies::from_keys_f32`], large key values keep their fractional 
+    /// part.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// let key_price = refined!(60);
+    /// let currencies = Currencies::from_keys_f64(1_000_000.5, key_price);
+    /// 
+    /// assert_eq!(currencies.keys, 1_000_000);
+    /// assert_eq!(currencies.metal, refined!(30));
+    /// ```
+    pub fn from_keys_f64(keys: f64, key_price: Currency) -> Self {
+        Self {
+            keys: keys as Currency,
+            metal: ((keys % 1.0) * key_price as f64) as Currency
         }
     }
     
+    /// Parses currencies from a string using the given options. [`FromStr`] uses the default 
+    /// options.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, ParseOptions, refined, scrap};
+    /// 
+    /// let currencies = Currencies::from_str_with("2keys 1.33", &ParseOptions::lenient()).unwrap();
+    /// 
+    /// assert_eq!(currencies, Currencies { keys: 2, metal: refined!(1) + scrap!(3) });
+    /// ```
+    pub fn from_str_with(string: &str, options: &ParseOptions) -> Result<Self, ParseError> {
+        let (keys, metal) = parse::parse_currencies::<Currency>(string, options)?;
+        
+        Ok(Self {
+            keys,
+            metal,
+        })
+    }
+    
+    /// Formats currencies using the given options. [`Display`](fmt::Display) uses the default 
+    /// options.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, FormatOptions, UnitLabel, refined};
+    /// 
+    /// let currencies = Currencies { keys: 0, metal: refined!(3) };
+    /// let options = FormatOptions::new().show_zero(true).unit_label(UnitLabel::Long);
+    /// 
+    /// assert_eq!(currencies.display_with(&options).to_string(), "0 keys, 3 refined");
+    /// ```
+    pub fn display_with(&self, options: &FormatOptions) -> CurrenciesDisplay<'_> {
+        CurrenciesDisplay::new(self, options)
+    }
+    
+    /// Formats currencies as a number of keys using the given key price (represented as weapons), 
+    /// with metal folded into fractional keys, e.g. "2.45 keys". The value is rounded to 
+    /// `precision` decimal places, up to 18.
+    /// 
+    /// # Panics
+    /// Panics if `key_price` is not greater than `0`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined, scrap};
+    /// 
+    /// let key_price = refined!(50);
+    /// let currencies = Currencies { keys: 2, metal: refined!(22) + scrap!(5) };
+    /// 
+    /// assert_eq!(currencies.display_as_keys(key_price, 2).to_string(), "2.45 keys");
+    /// ```
+    pub fn display_as_keys(&self, key_price: Currency, precision: u8) -> KeysDisplay<'_> {
+        KeysDisplay::new(self, key_price, precision)
+    }
+    
     /// Converts currencies to a metal value using the given key price (represented as weapons).
     /// In cases where the result overflows or underflows beyond the limit for [`i64`], the max or 
     /// min i64 will be returned. In most cases values this high are not useful.
@@ -142,7 +274,7 @@ impl Currencies {
     /// 
     /// assert_eq!(currencies.to_metal(key_price), refined!(60));
     /// ```
-    pub fn to_metal(&self, key_price: Currency) -> Currency {
+    pub const fn to_metal(&self, key_price: Currency) -> Currency {
         helpers::to_metal(self.metal, self.keys, key_price)
     }
     
@@ -159,10 +291,74 @@ impl Currencies {
     /// 
     /// assert!(currencies.checked_to_metal(key_price).is_none());
     /// ```
-    pub fn checked_to_metal(&self, key_price: Currency) -> Option<Currency> {
+    pub const fn checked_to_metal(&self, key_price: Currency) -> Option<Currency> {
         helpers::checked_to_metal(self.metal, self.keys, key_price)
     }
     
+    /// Compares currencies by their total value using the given key price (represented as 
+    /// weapons). Unlike the [`Ord`] implementation, which compares `keys` and then `metal`, this 
+    /// takes into account that metal can make up for keys. The comparison is exact and cannot 
+    /// overflow.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// use std::cmp::Ordering;
+    /// 
+    /// let key_price = refined!(50);
+    /// let currencies = Currencies { keys: 1, metal: 0 };
+    /// let other = Currencies { keys: 0, metal: refined!(60) };
+    /// 
+    /// assert_eq!(currencies.cmp_with_key_price(&other, key_price), Ordering::Less);
+    /// // The field-wise ordering would say otherwise.
+    /// assert!(currencies > other);
+    /// ```
+    pub fn cmp_with_key_price(&self, other: &Self, key_price: Currency) -> Ordering {
+        let value = helpers::to_metal_wide(self.metal, self.keys, key_price);
+        let other_value = helpers::to_metal_wide(other.metal, other.keys, key_price);
+        
+        value.cmp(&other_value)
+    }
+    
+    /// Restricts the currencies to a certain interval by comparing their total values using the 
+    /// given key price (represented as weapons). Returns `max` if the currencies are worth more 
+    /// than `max`, `min` if the currencies are worth less than `min`, and `self` otherwise.
+    /// 
+    /// This takes precedence over [`Ord::clamp`], which clamps using the field-wise ordering.
+    /// 
+    /// # Panics
+    /// Panics if `min` is worth more than `max`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// let key_price = refined!(50);
+    /// let min = Currencies { keys: 0, metal: refined!(40) };
+    /// let max = Currencies { keys: 1, metal: refined!(10) };
+    /// 
+    /// assert_eq!(Currencies { keys: 0, metal: refined!(10) }.clamp(min, max, key_price), min);
+    /// assert_eq!(Currencies { keys: 2, metal: 0 }.clamp(min, max, key_price), max);
+    /// assert_eq!(
+    ///     Currencies { keys: 0, metal: refined!(55) }.clamp(min, max, key_price),
+    ///     Currencies { keys: 0, metal: refined!(55) },
+    /// );
+    /// ```
+    pub fn clamp(self, min: Self, max: Self, key_price: Currency) -> Self {
+        assert!(
+            min.cmp_with_key_price(&max, key_price) != Ordering::Greater,
+            "min is worth more than max",
+        );
+        
+        if self.cmp_with_key_price(&min, key_price) == Ordering::Less {
+            min
+        } else if self.cmp_with_key_price(&max, key_price) == Ordering::Greater {
+            max
+        } else {
+            self
+        }
+    }
+    
     /// Checks if the currencies do contain any value.
     /// 
     /// # Examples
@@ -171,10 +367,75 @@ impl Currencies {
     /// 
     /// assert!(Currencies { keys: 0, metal: 0 }.is_empty());
     /// ```
-    pub fn is_empty(&self) -> bool {
+    pub const fn is_empty(&self) -> bool {
         self.keys == 0 && self.metal == 0
     }
     
+    /// Checks if the currencies hold a negative value. The sign of `keys` takes precedence and the 
+    /// sign of `metal` is only considered when there are no keys, which matches the ordering of 
+    /// [`Currencies`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// assert!(Currencies { keys: -1, metal: refined!(5) }.is_negative());
+    /// assert!(Currencies { keys: 0, metal: refined!(-5) }.is_negative());
+    /// assert!(!Currencies { keys: 1, metal: refined!(-5) }.is_negative());
+    /// ```
+    pub const fn is_negative(&self) -> bool {
+        self.keys < 0 || (self.keys == 0 && self.metal < 0)
+    }
+    
+    /// Returns a number representing the sign of the currencies using the same rules as 
+    /// [`Currencies::is_negative`].
+    /// 
+    /// - `0` if the currencies are empty
+    /// - `1` if the currencies are positive
+    /// - `-1` if the currencies are negative
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// assert_eq!(Currencies { keys: -1, metal: refined!(5) }.signum(), -1);
+    /// assert_eq!(Currencies { keys: 0, metal: 0 }.signum(), 0);
+    /// assert_eq!(Currencies { keys: 0, metal: refined!(5) }.signum(), 1);
+    /// ```
+    pub const fn signum(&self) -> Currency {
+        if self.is_empty() {
+            0
+        } else if self.is_negative() {
+            -1
+        } else {
+            1
+        }
+    }
+    
+    /// Computes the absolute value of the currencies. If the currencies are negative (see 
+    /// [`Currencies::is_negative`]) both fields are negated, otherwise the currencies are returned 
+    /// unchanged. This method is saturating.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// assert_eq!(
+    ///     Currencies { keys: -2, metal: refined!(-5) }.abs(),
+    ///     Currencies { keys: 2, metal: refined!(5) },
+    /// );
+    /// ```
+    pub const fn abs(&self) -> Self {
+        if self.is_negative() {
+            Self {
+                keys: self.keys.saturating_neg(),
+                metal: self.metal.saturating_neg(),
+            }
+        } else {
+            *self
+        }
+    }
+    
     /// Rounds the metal value using the given rounding method.
     /// 
     /// # Examples
@@ -186,13 +447,211 @@ impl Currencies {
     /// assert_eq!(currencies.round(&Rounding::Refined).metal, refined!(1));
     /// assert_eq!(currencies.round(&Rounding::UpRefined).metal, refined!(2));
     /// ```
-    pub fn round(mut self, rounding: &Rounding) -> Self {
-        self.metal = helpers::round_metal(self.metal, rounding);
+    pub fn round<R: RoundMetal + ?Sized>(mut self, rounding: &R) -> Self {
+        self.metal = rounding.round_metal(self.metal);
         self
     }
     
-    /// Neatens currencies. If the `metal` value is over `key_price`, the `metal` value will be 
-    /// converted to `keys`, with the remainder remaining as `metal`. This method is saturating.
+    /// Rounds the metal value in place using the given rounding method.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, Rounding, refined, scrap};
+    /// 
+    /// let mut currencies = Currencies { keys: 0, metal: refined!(1) + scrap!(3) };
+    /// 
+    /// currencies.round_mut(&Rounding::DownRefined);
+    /// 
+    /// assert_eq!(currencies.metal, refined!(1));
+    /// ```
+    pub fn round_mut<R: RoundMetal + ?Sized>(&mut self, rounding: &R) {
+        self.metal = rounding.round_metal(self.metal);
+    }
+    
+    /// Rounds the metal value using the given rounding method. `None` if the rounded metal value 
+    /// overflows integer bounds.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, Rounding, refined, scrap};
+    /// 
+    /// let currencies = Currencies { keys: 0, metal: refined!(1) + scrap!(3) };
+    /// 
+    /// assert_eq!(currencies.checked_round(&Rounding::UpRefined).unwrap().metal, refined!(2));
+    /// assert_eq!(Currencies { keys: 0, metal: i64::MAX }.checked_round(&Rounding::UpRefined), None);
+    /// ```
+    pub const fn checked_round(&self, rounding: &Rounding) -> Option<Self> {
+        let Some(metal) = helpers::checked_round_metal(self.metal, rounding) else {
+            return None;
+        };
+        
+        Some(Self {
+            keys: self.keys,
+            metal,
+        })
+    }
+    
+    /// Rounds the metal value using the given rounding method, then carries any metal at or over 
+    /// the key price (represented as weapons) into keys. This method is saturating.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, Rounding, refined, scrap};
+    /// 
+    /// let key_price = refined!(60);
+    /// // 1 key, 59.88 ref
+    /// let currencies = Currencies { keys: 1, metal: refined!(59) + scrap!(8) };
+    /// 
+    /// assert_eq!(
+    ///     currencies.round_with_key_price(&Rounding::UpRefined, key_price),
+    ///     Currencies { keys: 2, metal: 0 },
+    /// );
+    /// ```
+    pub fn round_with_key_price<R: RoundMetal + ?Sized>(
+        self,
+        rounding: &R,
+        key_price: Currency,
+    ) -> Self {
+        self.round(rounding).neaten(key_price)
+    }
+    
+    /// Rounds the total value to a whole number of keys in the given direction using the given 
+    /// key price (represented as weapons), resulting in currencies with `0` metal. This method is 
+    /// saturating.
+    /// 
+    /// # Panics
+    /// Panics if `key_price` is not positive.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, RoundingDirection, refined};
+    /// 
+    /// let key_price = refined!(60);
+    /// let currencies = Currencies { keys: 1, metal: refined!(58) };
+    /// 
+    /// assert_eq!(
+    ///     currencies.round_to_keys(RoundingDirection::Nearest, key_price),
+    ///     Currencies { keys: 2, metal: 0 },
+    /// );
+    /// assert_eq!(
+    ///     currencies.round_to_keys(RoundingDirection::Down, key_price),
+    ///     Currencies { keys: 1, metal: 0 },
+    /// );
+    /// ```
+    pub fn round_to_keys(&self, direction: RoundingDirection, key_price: Currency) -> Self {
+        assert!(key_price > 0, "key price must be positive");
+        
+        let total = helpers::to_metal_wide(self.metal, self.keys, key_price);
+        let rounded = helpers::round_wide_to_multiple(total, key_price as i128, direction);
+        
+        Self {
+            keys: helpers::saturate(rounded / key_price as i128),
+            metal: 0,
+        }
+    }
+    
+    /// Multiplies the currencies by a float, rounding the resulting metal value using the given 
+    /// rounding method. Keys are rounded to the nearest whole key as with the `*` operator.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, Rounding, refined, scrap};
+    /// 
+    /// let currencies = Currencies { keys: 0, metal: refined!(1) };
+    /// 
+    /// // 1.1 ref is 19.8 weapons.
+    /// assert_eq!(
+    ///     currencies.mul_f32_rounded(1.1, &Rounding::UpScrap),
+    ///     Currencies { keys: 0, metal: refined!(1) + scrap!(1) },
+    /// );
+    /// assert_eq!(
+    ///     currencies.mul_f32_rounded(1.1, &Rounding::DownRefined),
+    ///     Currencies { keys: 0, metal: refined!(1) },
+    /// );
+    /// ```
+    /// 
+    /// # Notes
+    /// Fractional keys are dropped rather than converted into metal, e.g. 1 key multiplied by 
+    /// `0.9` is still 1 key and 1 key multiplied by `0.4` is nothing. To scale the total value of 
+    /// a price by a percentage, use [`Currencies::with_markup`] or [`Currencies::with_markdown`], 
+    /// which take a key price and carry fractional keys into metal.
+    pub fn mul_f32_rounded<R: RoundMetal + ?Sized>(&self, factor: f32, rounding: &R) -> Self {
+        Self {
+            keys: (self.keys as f64 * factor as f64).round() as Currency,
+            metal: rounding.round_metal_float(self.metal as f64 * factor as f64),
+        }
+    }
+    
+    /// Divides the currencies by a float, rounding the resulting metal value using the given 
+    /// rounding method. Keys are rounded to the nearest whole key as with the `/` operator.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, Rounding, refined, scrap};
+    /// 
+    /// let currencies = Currencies { keys: 0, metal: refined!(1) };
+    /// 
+    /// // 1 ref / 4 is 4.5 weapons.
+    /// assert_eq!(
+    ///     currencies.div_f32_rounded(4.0, &Rounding::UpScrap),
+    ///     Currencies { keys: 0, metal: scrap!(3) },
+    /// );
+    /// assert_eq!(
+    ///     currencies.div_f32_rounded(4.0, &Rounding::DownScrap),
+    ///     Currencies { keys: 0, metal: scrap!(2) },
+    /// );
+    /// ```
+    /// 
+    /// # Notes
+    /// Fractional keys are dropped rather than converted into metal, e.g. 1 key divided by `4.0` 
+    /// is nothing. To scale the total value of a price, use [`Currencies::with_markdown`], which 
+    /// takes a key price and carries fractional keys into metal.
+    pub fn div_f32_rounded<R: RoundMetal + ?Sized>(&self, divisor: f32, rounding: &R) -> Self {
+        Self {
+            keys: (self.keys as f64 / divisor as f64).round() as Currency,
+            metal: rounding.round_metal_float(self.metal as f64 / divisor as f64),
+        }
+    }
+    
+    /// Splits the currencies into `n` equal shares. Returns a tuple of one share along with the 
+    /// remainder which could not be divided evenly. Metal shares are divided at scrap granularity 
+    /// so that each share can be paid using whole scrap, with any leftover weapons kept in the 
+    /// remainder.
+    /// 
+    /// # Panics
+    /// Panics if `n` is `0` or the division results in overflow.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined, scrap};
+    /// 
+    /// let currencies = Currencies { keys: 5, metal: refined!(1) };
+    /// let (share, remainder) = currencies.div_rem(2);
+    /// 
+    /// assert_eq!(share, Currencies { keys: 2, metal: scrap!(4) });
+    /// assert_eq!(remainder, Currencies { keys: 1, metal: scrap!(1) });
+    /// assert_eq!(share * 2 + remainder, currencies);
+    /// ```
+    pub fn div_rem(&self, n: Currency) -> (Self, Self) {
+        let keys = self.keys / n;
+        let metal = self.metal / n;
+        // Always a whole number of scrap.
+        let metal = metal - metal % ONE_SCRAP;
+        let share = Self {
+            keys,
+            metal,
+        };
+        let remainder = Self {
+            keys: self.keys % n,
+            metal: self.metal - metal * n,
+        };
+        
+        (share, remainder)
+    }
+    
+    /// Neatens (normalizes) currencies. If the `metal` value is over `key_price`, the `metal` 
+    /// value will be converted to `keys`, with the remainder remaining as `metal`. This method is 
+    /// saturating. The reverse of this operation is [`Currencies::denormalize`].
     /// 
     /// # Examples
     /// ```
@@ -207,56 +666,503 @@ impl Currencies {
         Self::from_metal(self.to_metal(key_price), key_price)
     }
     
-    /// Checks whether the currencies have enough keys and metal to afford the `other` currencies.
-    /// This is simply `self.keys >= other.keys && self.metal >= other.metal`.
+    /// Converts all `keys` into `metal` using the given key price (represented as weapons), 
+    /// resulting in currencies with `0` keys. This is the reverse of [`Currencies::neaten`]. This 
+    /// method is saturating.
     /// 
     /// # Examples
     /// ```
     /// use tf2_price::{Currencies, refined};
     /// 
-    /// let currencies = Currencies { keys: 100, metal: refined!(30) };
+    /// let key_price = refined!(50);
+    /// let currencies = Currencies { keys: 2, metal: refined!(10) };
     /// 
-    /// // We have at least 50 keys and 30 refined.
-    /// assert!(currencies.can_afford(&Currencies { keys: 50, metal: refined!(30) }));
-    /// // Not enough metal - we can't afford this.
-    /// assert!(!currencies.can_afford(&Currencies { keys: 50, metal: refined!(100) }));
+    /// assert_eq!(currencies.denormalize(key_price), Currencies { keys: 0, metal: refined!(110) });
+    /// assert_eq!(currencies.denormalize(key_price).neaten(key_price), currencies);
     /// ```
-    pub fn can_afford(&self, other: &Self) -> bool {
-        self.keys >= other.keys && self.metal >= other.metal
-    }
-    
-    /// Checked integer multiplication. Computes `self * rhs` for each field, returning `None` if 
-    /// overflow occurred
-    pub fn checked_mul(&self, rhs: Currency) -> Option<Self> {
-        let keys = self.keys.checked_mul(rhs)?;
-        let metal = self.metal.checked_mul(rhs)?;
-        
-        Some(Self { keys, metal })
+    pub fn denormalize(&self, key_price: Currency) -> Self {
+        Self {
+            keys: 0,
+            metal: self.to_metal(key_price),
+        }
     }
     
-    /// Checked integer division. Computes `self / rhs`, returning `None` if `rhs == 0` or the 
-    /// division results in overflow.
-    pub fn checked_div(&self, rhs: Currency) -> Option<Self> {
-        let keys = self.keys.checked_div(rhs)?;
-        let metal = self.metal.checked_div(rhs)?;
-        
-        Some(Self { keys, metal })
+    /// Breaks the currencies into the physical items needed to represent them. Metal is broken 
+    /// into the fewest number of refined, reclaimed, scrap and weapons.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined, reclaimed, scrap};
+    /// 
+    /// let denomination = Currencies { keys: 2, metal: refined!(3) + scrap!(5) }.denominate();
+    /// 
+    /// assert_eq!(denomination.keys, 2);
+    /// assert_eq!(denomination.refined, 3);
+    /// assert_eq!(denomination.reclaimed, 1);
+    /// assert_eq!(denomination.scrap, 2);
+    /// assert_eq!(denomination.weapons, 0);
+    /// ```
+    pub fn denominate(&self) -> Denomination {
+        Denomination::new(self.keys, self.metal)
     }
     
-    /// Adds currencies. `None` if the result overflows integer bounds.
-    pub fn checked_add(&self, other: &Self) -> Option<Self> {
-        let keys = self.keys.checked_add(other.keys)?;
-        let metal = self.metal.checked_add(other.metal)?;
-        
-        Some(Self { keys, metal })
+    /// Computes the items to pay the currencies with from the given [`Stock`], along with the 
+    /// change that must be returned by the other party. See [`Stock::pay`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, Stock, error::PaymentError, refined};
+    /// 
+    /// let stock = Stock { keys: 1, refined: 10, ..Default::default() };
+    /// let price = Currencies { keys: 1, metal: refined!(5) };
+    /// 
+    /// assert_eq!(price.denominate_with_stock(&stock).unwrap().pay.refined, 5);
+    /// assert!(matches!(
+    ///     Currencies { keys: 2, metal: 0 }.denominate_with_stock(&stock),
+    ///     Err(PaymentError::InsufficientKeys { .. }),
+    /// ));
+    /// ```
+    pub fn denominate_with_stock(&self, stock: &Stock) -> Result<Payment, PaymentError> {
+        stock.pay(self)
     }
     
-    /// Subtracts currencies. `None` if the result overflows integer bounds.
-    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
-        let keys = self.keys.checked_sub(other.keys)?;
-        let metal = self.metal.checked_sub(other.metal)?;
-        
-        Some(Self { keys, metal })
+    /// Computes the average of two currencies using the given key price (represented as weapons).
+    /// The total values are averaged and the metal value is rounded using the given rounding 
+    /// method before being converted back into keys and metal.
+    /// 
+    /// # Panics
+    /// Panics if `key_price` is `0`, as with [`Currencies::from_metal`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, Rounding, refined, scrap};
+    /// 
+    /// let key_price = refined!(50);
+    /// let buy = Currencies { keys: 0, metal: refined!(45) };
+    /// let sell = Currencies { keys: 1, metal: refined!(5) + scrap!(1) };
+    /// 
+    /// assert_eq!(
+    ///     buy.midpoint(&sell, key_price, &Rounding::UpScrap),
+    ///     Currencies { keys: 1, metal: scrap!(1) },
+    /// );
+    /// ```
+    pub fn midpoint<R: RoundMetal + ?Sized>(
+        &self,
+        other: &Self,
+        key_price: Currency,
+        rounding: &R,
+    ) -> Self {
+        let a = helpers::to_metal_wide(self.metal, self.keys, key_price);
+        let b = helpers::to_metal_wide(other.metal, other.keys, key_price);
+        // Halving each value first cannot overflow.
+        let odd = a.rem_euclid(2) + b.rem_euclid(2);
+        let whole = a.div_euclid(2) + b.div_euclid(2) + odd / 2;
+        let fraction = if odd == 1 { 0.5 } else { 0.0 };
+        let metal = helpers::round_metal_parts(whole, fraction, rounding);
+        
+        Self::from_metal(metal, key_price)
+    }
+    
+    /// Linearly interpolates between two currencies using the given key price (represented as 
+    /// weapons). A `t` of `0.0` results in `self` and a `t` of `1.0` results in `other`. The 
+    /// interpolation is done using total values and the metal value is rounded using the given 
+    /// rounding method before being converted back into keys and metal, which avoids artifacts 
+    /// at key boundaries.
+    /// 
+    /// # Panics
+    /// Panics if `key_price` is `0`, as with [`Currencies::from_metal`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, Rounding, refined};
+    /// 
+    /// let key_price = refined!(50);
+    /// let from = Currencies { keys: 2, metal: 0 };
+    /// let to = Currencies { keys: 1, metal: 0 };
+    /// 
+    /// assert_eq!(
+    ///     from.lerp(&to, 0.25, key_price, &Rounding::DownRefined),
+    ///     Currencies { keys: 1, metal: refined!(37) },
+    /// );
+    /// ```
+    pub fn lerp<R: RoundMetal + ?Sized>(
+        &self,
+        other: &Self,
+        t: f32,
+        key_price: Currency,
+        rounding: &R,
+    ) -> Self {
+        self.lerp_f64(other, t as f64, key_price, rounding)
+    }
+    
+    /// [`Currencies::lerp`] with a double precision `t`.
+    pub(crate) fn lerp_f64<R: RoundMetal + ?Sized>(
+        &self,
+        other: &Self,
+        t: f64,
+        key_price: Currency,
+        rounding: &R,
+    ) -> Self {
+        let from = helpers::to_metal_wide(self.metal, self.keys, key_price);
+        let to = helpers::to_metal_wide(other.metal, other.keys, key_price);
+        // Only the offset from the nearest end is computed as a float so that large values keep 
+        // their precision and a `t` of `0.0` or `1.0` is exact.
+        let (start, offset) = if t < 0.5 {
+            (from, to.saturating_sub(from) as f64 * t)
+        } else {
+            (to, from.saturating_sub(to) as f64 * (1.0 - t))
+        };
+        let (whole, fraction) = helpers::split_fraction(offset);
+        let metal = helpers::round_metal_parts(start.saturating_add(whole), fraction, rounding);
+        
+        Self::from_metal(metal, key_price)
+    }
+    
+    /// Increases the total value of the currencies by a percentage using the given key price 
+    /// (represented as weapons). The metal value is rounded using the given rounding method 
+    /// before being converted back into keys and metal. A `percent` of `5.0` results in currencies 
+    /// worth 105% of the original value.
+    /// 
+    /// # Panics
+    /// Panics if `key_price` is `0`, as with [`Currencies::from_metal`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, Rounding, refined, scrap};
+    /// 
+    /// let key_price = refined!(50);
+    /// let currencies = Currencies { keys: 1, metal: 0 };
+    /// 
+    /// assert_eq!(
+    ///     currencies.with_markup(5.0, key_price, &Rounding::UpScrap),
+    ///     Currencies { keys: 1, metal: refined!(2) + scrap!(5) },
+    /// );
+    /// ```
+    pub fn with_markup<R: RoundMetal + ?Sized>(
+        &self,
+        percent: f32,
+        key_price: Currency,
+        rounding: &R,
+    ) -> Self {
+        self.scale_value(100.0 + percent as f64, key_price, rounding)
+    }
+    
+    /// Decreases the total value of the currencies by a percentage using the given key price 
+    /// (represented as weapons). The metal value is rounded using the given rounding method 
+    /// before being converted back into keys and metal. A `percent` of `10.0` results in 
+    /// currencies worth 90% of the original value.
+    /// 
+    /// # Panics
+    /// Panics if `key_price` is `0`, as with [`Currencies::from_metal`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, Rounding, refined};
+    /// 
+    /// let key_price = refined!(50);
+    /// let currencies = Currencies { keys: 1, metal: 0 };
+    /// 
+    /// assert_eq!(
+    ///     currencies.with_markdown(10.0, key_price, &Rounding::DownScrap),
+    ///     Currencies { keys: 0, metal: refined!(45) },
+    /// );
+    /// ```
+    pub fn with_markdown<R: RoundMetal + ?Sized>(
+        &self,
+        percent: f32,
+        key_price: Currency,
+        rounding: &R,
+    ) -> Self {
+        self.scale_value(100.0 - percent as f64, key_price, rounding)
+    }
+    
+    /// Computes the percentage difference from the total value of `self` to the total value of 
+    /// `other` using the given key price (represented as weapons). The result is positive if 
+    /// `other` is worth more than `self` and negative if it is worth less. If `self` has no value 
+    /// the result is infinite, or `NaN` if neither have a value.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// let key_price = refined!(50);
+    /// let old_price = Currencies { keys: 1, metal: 0 };
+    /// let new_price = Currencies { keys: 1, metal: refined!(5) };
+    /// 
+    /// assert_eq!(old_price.percent_difference(&new_price, key_price), 10.0);
+    /// assert_eq!(new_price.percent_difference(&old_price, key_price).round(), -9.0);
+    /// ```
+    pub fn percent_difference(&self, other: &Self, key_price: Currency) -> f32 {
+        let value = helpers::to_metal_wide(self.metal, self.keys, key_price);
+        let other_value = helpers::to_metal_wide(other.metal, other.keys, key_price);
+        
+        ((other_value - value) as f64 / value.abs() as f64 * 100.0) as f32
+    }
+    
+    /// Scales the total value of the currencies to `percent` of its value.
+    fn scale_value<R: RoundMetal + ?Sized>(
+        &self,
+        percent: f64,
+        key_price: Currency,
+        rounding: &R,
+    ) -> Self {
+        let value = helpers::to_metal_wide(self.metal, self.keys, key_price);
+        // Whole percentages are applied as integers so that large values keep their precision and 
+        // e.g. 110% of 180 is exactly 198. Only a fractional percentage is applied as a float.
+        let whole_percent = percent.floor();
+        let (whole, fraction) = match value.checked_mul(whole_percent as i128) {
+            Some(scaled) => {
+                let rest = scaled.rem_euclid(100) as f64 + value as f64 * (percent - whole_percent);
+                let (rest_whole, fraction) = helpers::split_fraction(rest / 100.0);
+                
+                (scaled.div_euclid(100).saturating_add(rest_whole), fraction)
+            },
+            None => helpers::split_fraction(value as f64 * percent / 100.0),
+        };
+        let metal = helpers::round_metal_parts(whole, fraction, rounding);
+        
+        Self::from_metal(metal, key_price)
+    }
+    
+    /// Checks whether the currencies have enough keys and metal to afford the `other` currencies.
+    /// This is simply `self.keys >= other.keys && self.metal >= other.metal`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, refined};
+    /// 
+    /// let currencies = Currencies { keys: 100, metal: refined!(30) };
+    /// 
+    /// // We have at least 50 keys and 30 refined.
+    /// assert!(currencies.can_afford(&Currencies { keys: 50, metal: refined!(30) }));
+    /// // Not enough metal - we can't afford this.
+    /// assert!(!currencies.can_afford(&Currencies { keys: 50, metal: refined!(100) }));
+    /// ```
+    pub fn can_afford(&self, other: &Self) -> bool {
+        self.keys >= other.keys && self.metal >= other.metal
+    }
+    
+    /// Checked integer multiplication. Computes `self * rhs` for each field, returning `None` if 
+    /// overflow occurred
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::Currencies;
+    /// 
+    /// let currencies = Currencies { keys: 2, metal: 10 };
+    /// 
+    /// assert_eq!(currencies.checked_mul(2), Some(Currencies { keys: 4, metal: 20 }));
+    /// assert_eq!(currencies.checked_mul(i64::MAX), None);
+    /// ```
+    pub const fn checked_mul(&self, rhs: Currency) -> Option<Self> {
+        let Some(keys) = self.keys.checked_mul(rhs) else {
+            return None;
+        };
+        let Some(metal) = self.metal.checked_mul(rhs) else {
+            return None;
+        };
+        
+        Some(Self { keys, metal })
+    }
+    
+    /// Checked integer division. Computes `self / rhs`, returning `None` if `rhs == 0` or the 
+    /// division results in overflow.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::Currencies;
+    /// 
+    /// let currencies = Currencies { keys: 2, metal: 10 };
+    /// 
+    /// assert_eq!(currencies.checked_div(2), Some(Currencies { keys: 1, metal: 5 }));
+    /// assert_eq!(currencies.checked_div(0), None);
+    /// ```
+    pub const fn checked_div(&self, rhs: Currency) -> Option<Self> {
+        let Some(keys) = self.keys.checked_div(rhs) else {
+            return None;
+        };
+        let Some(metal) = self.metal.checked_div(rhs) else {
+            return None;
+        };
+        
+        Some(Self { keys, metal })
+    }
+    
+    /// Adds currencies. `None` if the result overflows integer bounds.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::Currencies;
+    /// 
+    /// let currencies = Currencies { keys: 2, metal: 10 };
+    /// 
+    /// assert_eq!(
+    ///     currencies.checked_add(&Currencies { keys: 1, metal: 5 }),
+    ///     Some(Currencies { keys: 3, metal: 15 }),
+    /// );
+    /// assert_eq!(currencies.checked_add(&Currencies { keys: i64::MAX, metal: 0 }), None);
+    /// ```
+    pub const fn checked_add(&self, other: &Self) -> Option<Self> {
+        let Some(keys) = self.keys.checked_add(other.keys) else {
+            return None;
+        };
+        let Some(metal) = self.metal.checked_add(other.metal) else {
+            return None;
+        };
+        
+        Some(Self { keys, metal })
+    }
+    
+    /// Subtracts currencies. `None` if the result overflows integer bounds.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::Currencies;
+    /// 
+    /// let currencies = Currencies { keys: 2, metal: 10 };
+    /// 
+    /// assert_eq!(
+    ///     currencies.checked_sub(&Currencies { keys: 1, metal: 5 }),
+    ///     Some(Currencies { keys: 1, metal: 5 }),
+    /// );
+    /// assert_eq!(currencies.checked_sub(&Currencies { keys: i64::MIN, metal: 0 }), None);
+    /// ```
+    pub const fn checked_sub(&self, other: &Self) -> Option<Self> {
+        let Some(keys) = self.keys.checked_sub(other.keys) else {
+            return None;
+        };
+        let Some(metal) = self.metal.checked_sub(other.metal) else {
+            return None;
+        };
+        
+        Some(Self { keys, metal })
+    }
+    
+    /// Saturating addition. Adds currencies, saturating at the numeric bounds instead of 
+    /// overflowing. This is equivalent to using the `+` operator.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::Currencies;
+    /// 
+    /// let currencies = Currencies { keys: i64::MAX, metal: 10 };
+    /// 
+    /// assert_eq!(
+    ///     currencies.saturating_add(&Currencies { keys: 1, metal: 5 }),
+    ///     Currencies { keys: i64::MAX, metal: 15 },
+    /// );
+    /// ```
+    pub const fn saturating_add(&self, other: &Self) -> Self {
+        Self {
+            keys: self.keys.saturating_add(other.keys),
+            metal: self.metal.saturating_add(other.metal),
+        }
+    }
+    
+    /// Saturating subtraction. Subtracts currencies, saturating at the numeric bounds instead of 
+    /// overflowing. This is equivalent to using the `-` operator.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::Currencies;
+    /// 
+    /// let currencies = Currencies { keys: i64::MIN, metal: 10 };
+    /// 
+    /// assert_eq!(
+    ///     currencies.saturating_sub(&Currencies { keys: 1, metal: 5 }),
+    ///     Currencies { keys: i64::MIN, metal: 5 },
+    /// );
+    /// ```
+    pub const fn saturating_sub(&self, other: &Self) -> Self {
+        Self {
+            keys: self.keys.saturating_sub(other.keys),
+            metal: self.metal.saturating_sub(other.metal),
+        }
+    }
+    
+    /// Saturating integer multiplication. Computes `self * rhs` for each field, saturating at the 
+    /// numeric bounds instead of overflowing. This is equivalent to using the `*` operator.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::Currencies;
+    /// 
+    /// let currencies = Currencies { keys: 2, metal: -10 };
+    /// 
+    /// assert_eq!(
+    ///     currencies.saturating_mul(i64::MAX),
+    ///     Currencies { keys: i64::MAX, metal: i64::MIN },
+    /// );
+    /// ```
+    pub const fn saturating_mul(&self, rhs: Currency) -> Self {
+        Self {
+            keys: self.keys.saturating_mul(rhs),
+            metal: self.metal.saturating_mul(rhs),
+        }
+    }
+    
+    /// Calculates `self + other`. Returns a tuple of the addition along with a boolean indicating 
+    /// whether an arithmetic overflow would occur in either field. If an overflow would have 
+    /// occurred then the wrapped value is returned.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::Currencies;
+    /// 
+    /// let currencies = Currencies { keys: i64::MAX, metal: 10 };
+    /// 
+    /// assert_eq!(
+    ///     currencies.overflowing_add(&Currencies { keys: 1, metal: 5 }),
+    ///     (Currencies { keys: i64::MIN, metal: 15 }, true),
+    /// );
+    /// ```
+    pub const fn overflowing_add(&self, other: &Self) -> (Self, bool) {
+        let (keys, keys_overflowed) = self.keys.overflowing_add(other.keys);
+        let (metal, metal_overflowed) = self.metal.overflowing_add(other.metal);
+        
+        (Self { keys, metal }, keys_overflowed || metal_overflowed)
+    }
+    
+    /// Calculates `self - other`. Returns a tuple of the subtraction along with a boolean 
+    /// indicating whether an arithmetic overflow would occur in either field. If an overflow would 
+    /// have occurred then the wrapped value is returned.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::Currencies;
+    /// 
+    /// let currencies = Currencies { keys: 2, metal: i64::MIN };
+    /// 
+    /// assert_eq!(
+    ///     currencies.overflowing_sub(&Currencies { keys: 1, metal: 1 }),
+    ///     (Currencies { keys: 1, metal: i64::MAX }, true),
+    /// );
+    /// ```
+    pub const fn overflowing_sub(&self, other: &Self) -> (Self, bool) {
+        let (keys, keys_overflowed) = self.keys.overflowing_sub(other.keys);
+        let (metal, metal_overflowed) = self.metal.overflowing_sub(other.metal);
+        
+        (Self { keys, metal }, keys_overflowed || metal_overflowed)
+    }
+    
+    /// Calculates `self * rhs` for each field. Returns a tuple of the multiplication along with a 
+    /// boolean indicating whether an arithmetic overflow would occur in either field. If an 
+    /// overflow would have occurred then the wrapped value is returned.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::Currencies;
+    /// 
+    /// let currencies = Currencies { keys: 2, metal: 10 };
+    /// 
+    /// assert_eq!(currencies.overflowing_mul(2), (Currencies { keys: 4, metal: 20 }, false));
+    /// assert!(currencies.overflowing_mul(i64::MAX).1);
+    /// ```
+    pub const fn overflowing_mul(&self, rhs: Currency) -> (Self, bool) {
+        let (keys, keys_overflowed) = self.keys.overflowing_mul(rhs);
+        let (metal, metal_overflowed) = self.metal.overflowing_mul(rhs);
+        
+        (Self { keys, metal }, keys_overflowed || metal_overflowed)
     }
 }
 
@@ -270,6 +1176,13 @@ impl PartialEq<ListingCurrencies> for Currencies {
     }
 }
 
+impl_op_ex!(- |a: &Currencies| -> Currencies { 
+    Currencies {
+        keys: a.keys.saturating_neg(),
+        metal: a.metal.saturating_neg(),
+    }
+});
+
 impl_op_ex!(+ |a: &Currencies, b: &Currencies| -> Currencies { 
     Currencies {
         keys: a.keys.saturating_add(b.keys),
@@ -300,15 +1213,15 @@ impl_op_ex!(/ |currencies: &Currencies, num: Currency| -> Currencies {
 
 impl_op_ex!(* |currencies: &Currencies, num: f32| -> Currencies {
     Currencies { 
-        keys: (currencies.keys as f32 * num).round() as Currency,
-        metal: (currencies.metal as f32 * num).round() as Currency,
+        keys: (currencies.keys as f64 * num as f64).round() as Currency,
+        metal: (currencies.metal as f64 * num as f64).round() as Currency,
     }
 });
 
 impl_op_ex!(/ |currencies: &Currencies, num: f32| -> Currencies {
     Currencies {
-        keys: (currencies.keys as f32 / num).round() as Currency,
-        metal: (currencies.metal as f32 / num).round() as Currency,
+        keys: (currencies.keys as f64 / num as f64).round() as Currency,
+        metal: (currencies.metal as f64 / num as f64).round() as Currency,
     }
 });
 
@@ -349,8 +1262,8 @@ impl MulAssign<Currency> for Currencies {
 
 impl MulAssign<f32> for Currencies {
     fn mul_assign(&mut self, other: f32) {
-        self.keys = (self.keys as f32 * other).round() as Currency;
-        self.metal = (self.metal as f32 * other).round() as Currency;
+        self.keys = (self.keys as f64 * other as f64).round() as Currency;
+        self.metal = (self.metal as f64 * other as f64).round() as Currency;
     }
 }
 
@@ -363,14 +1276,35 @@ impl DivAssign<Currency> for Currencies {
 
 impl DivAssign<f32> for Currencies {
     fn div_assign(&mut self, other: f32) {
-        self.keys = (self.keys as f32 / other).round() as Currency;
-        self.metal = (self.metal as f32 / other).round() as Currency;
+        self.keys = (self.keys as f64 / other as f64).round() as Currency;
+        self.metal = (self.metal as f64 / other as f64).round() as Currency;
+    }
+}
+
+impl Sum for Currencies {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::ZERO, |total, currencies| total + currencies)
+    }
+}
+
+impl<'a> Sum<&'a Currencies> for Currencies {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.fold(Self::ZERO, |total, currencies| total + currencies)
     }
 }
 
 impl<'a> TryFrom<&'a str> for Currencies {
     type Error = ParseError;
     
+    /// Parses currencies from a string, e.g. "2 keys, 3.33 ref". A unit appearing more than once, 
+    /// e.g. "2 keys, 3 keys", results in [`ParseError::DuplicateCurrency`]. To sum duplicates, use 
+    /// [`Currencies::from_str_with`] with [`ParseOptions::allow_duplicates`].
     fn try_from(string: &'a str) -> Result<Self, Self::Error>  {
         let (keys, metal) = helpers::parse_from_string::<Currency>(string)?;
         
@@ -381,66 +1315,59 @@ impl<'a> TryFrom<&'a str> for Currencies {
     }
 }
 
-/// Results in error if [`ListingCurrencies`] contains a fractional key value.
+impl FromStr for Currencies {
+    type Err = ParseError;
+    
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Self::try_from(string)
+    }
+}
+
+/// Results in error if [`ListingCurrencies`] contains a key value which is fractional, not finite 
+/// or out of bounds, rather than rounding or saturating it.
 impl TryFrom<ListingCurrencies> for Currencies {
     type Error = TryFromListingCurrenciesError;
     
     fn try_from(currencies: ListingCurrencies) -> Result<Self, Self::Error> {
-        if currencies.is_fract() {
-            return Err(TryFromListingCurrenciesError { fract: currencies.keys.fract() });
-        }
-        
-        Ok(Currencies {
-            keys: currencies.keys as Currency,
-            metal: currencies.metal,
-        })
+        Self::try_from(&currencies)
     }
 }
 
-/// Results in error if [`ListingCurrencies`] contains a fractional key value.
+/// Results in error if [`ListingCurrencies`] contains a key value which is fractional, not finite 
+/// or out of bounds, rather than rounding or saturating it.
 impl TryFrom<&ListingCurrencies> for Currencies {
-    type Error = &'static str;
+    type Error = TryFromListingCurrenciesError;
     
     fn try_from(currencies: &ListingCurrencies) -> Result<Self, Self::Error> {
+        let keys = currencies.keys;
+        
+        if !keys.is_finite() {
+            return Err(TryFromListingCurrenciesError::NonFinite { keys });
+        }
+        
         if currencies.is_fract() {
-            return Err("Currencies contain fractional key value");
+            return Err(TryFromListingCurrenciesError::Fractional { keys });
+        }
+        
+        // i64::MAX as f32 rounds up to 2^63 which is already out of bounds.
+        if !(keys >= Currency::MIN as f32 && keys < Currency::MAX as f32) {
+            return Err(TryFromListingCurrenciesError::OutOfRange { keys });
         }
         
         Ok(Currencies {
-            keys: currencies.keys as Currency,
+            keys: keys as Currency,
             metal: currencies.metal,
         })
     }
 }
 
+/// The alternate flag creates a compact form, e.g. `format!("{:#}", currencies)` creates 
+/// "2k, 3.33r" rather than "2 keys, 3.33 ref".
 impl fmt::Display for Currencies {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if self.keys != 0 && self.metal != 0 {
-            write!(
-                f,
-                "{} {}, {} {}",
-                self.keys,
-                helpers::pluralize(self.keys, KEY_SYMBOL, KEYS_SYMBOL),
-                helpers::get_metal_float(self.metal),
-                METAL_SYMBOL,
-            )
-        } else if self.keys != 0 {
-            write!(
-                f,
-                "{} {}",
-                self.keys,
-                helpers::pluralize(self.keys, KEY_SYMBOL, KEYS_SYMBOL),
-            )
-        } else if self.metal != 0 {
-            write!(
-                f,
-                "{} {}",
-                helpers::get_metal_float(self.metal),
-                METAL_SYMBOL,
-            )
-        } else {
-            write!(f, "{}", EMPTY_SYMBOL)
-        }
+        let options = FormatOptions::default().compact(f.alternate());
+        
+        fmt::Display::fmt(&self.display_with(&options), f)
     }
 }
 
@@ -464,8 +1391,18 @@ impl Serialize for Currencies {
     where
         S: Serializer,
     {
+        let is_human_readable = serializer.is_human_readable();
         let mut currencies = serializer.serialize_struct("Currencies", 2)?;
         
+        // Formats which are not self-describing need every field in a fixed layout. Metal is 
+        // written as weapons so the value is exact.
+        if !is_human_readable {
+            currencies.serialize_field("keys", &self.keys)?;
+            currencies.serialize_field("metal", &self.metal)?;
+            
+            return currencies.end();
+        }
+        
         if self.keys == 0 {
             currencies.skip_field("keys")?;
         } else {
@@ -475,7 +1412,7 @@ impl Serialize for Currencies {
         if self.metal == 0 {
             currencies.skip_field("metal")?;
         } else {
-            let float = helpers::get_metal_float(self.metal);
+            let float = helpers::get_metal_float_f64(self.metal);
             
             if float.fract() == 0.0 {
                 currencies.serialize_field("metal", &(float as Currency))?;
@@ -491,15 +1428,125 @@ impl Serialize for Currencies {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{refined, scrap};
+    use crate::{refined, reclaimed, scrap, ONE_WEAPON};
+    use crate::constants::KEYS_SYMBOL;
     use assert_json_diff::assert_json_eq;
     use serde_json::{self, json, Value};
 
     #[test]
-    fn currencies_equal() {
-        assert_eq!(Currencies {
-            keys: 2,
-            metal: refined!(23) + scrap!(4),
+    fn converts_from_listing_currencies() {
+        let currencies = ListingCurrencies { keys: 2.0, metal: refined!(3) + ONE_WEAPON };
+        
+        assert_eq!(
+            Currencies::try_from(currencies),
+            Ok(Currencies { keys: 2, metal: refined!(3) + ONE_WEAPON }),
+        );
+        assert_eq!(
+            Currencies::try_from(&ListingCurrencies { keys: -1.0, metal: 0 }),
+            Ok(Currencies { keys: -1, metal: 0 }),
+        );
+    }
+    
+    #[test]
+    fn converts_from_listing_currencies_invalid() {
+        assert_eq!(
+            Currencies::try_from(&ListingCurrencies { keys: 1.5, metal: 0 }),
+            Err(TryFromListingCurrenciesError::Fractional { keys: 1.5 }),
+        );
+        assert!(matches!(
+            Currencies::try_from(ListingCurrencies { keys: f32::NAN, metal: 0 }),
+            Err(TryFromListingCurrenciesError::NonFinite { .. }),
+        ));
+        assert_eq!(
+            Currencies::try_from(ListingCurrencies { keys: f32::NEG_INFINITY, metal: 0 }),
+            Err(TryFromListingCurrenciesError::NonFinite { keys: f32::NEG_INFINITY }),
+        );
+        assert_eq!(
+            Currencies::try_from(ListingCurrencies { keys: 1e20, metal: 0 }),
+            Err(TryFromListingCurrenciesError::OutOfRange { keys: 1e20 }),
+        );
+        assert_eq!(
+            Currencies::try_from(ListingCurrencies { keys: Currency::MIN as f32, metal: 0 }),
+            Ok(Currencies { keys: Currency::MIN, metal: 0 }),
+        );
+        assert_eq!(
+            TryFromListingCurrenciesError::Fractional { keys: 1.5 }.to_string(),
+            "Currencies contain fractional key value: 1.5",
+        );
+    }
+    
+    #[test]
+    fn multiplies_large_metal_by_float() {
+        let currencies = Currencies { keys: 0, metal: refined!(500_000) + scrap!(1) };
+        
+        assert_eq!(currencies * 1.0_f32, currencies);
+        assert_eq!(currencies / 1.0_f32, currencies);
+        assert_eq!(
+            Currencies::from_listing_currencies(
+                ListingCurrencies { keys: 20_000.5, metal: 0 },
+                refined!(60),
+            ),
+            Currencies { keys: 20_000, metal: refined!(30) },
+        );
+    }
+    
+    #[test]
+    fn defines_const_price_table() {
+        const KEY_PRICE: Currency = refined!(60);
+        const PRICES: [Currencies; 3] = [
+            Currencies::new(),
+            Currencies { keys: 1, metal: reclaimed!(2) },
+            Currencies::from_metal(refined!(130) + scrap!(1), KEY_PRICE),
+        ];
+        const TOTAL: Option<Currencies> = PRICES[1].checked_add(&PRICES[2]);
+        const METAL: Currency = PRICES[2].to_metal(KEY_PRICE);
+        
+        assert_eq!(PRICES[2], Currencies { keys: 2, metal: refined!(10) + scrap!(1) });
+        assert_eq!(
+            TOTAL,
+            Some(Currencies { keys: 3, metal: refined!(10) + reclaimed!(2) + scrap!(1) }),
+        );
+        assert_eq!(METAL, refined!(130) + scrap!(1));
+        assert!(PRICES[0].is_empty());
+    }
+    
+    #[test]
+    fn pads_currencies_to_width() {
+        let currencies = Currencies { keys: 2, metal: refined!(3) };
+        
+        assert_eq!(format!("{:>16}|", currencies), "   2 keys, 3 ref|");
+        assert_eq!(format!("{:-<16}|", currencies), "2 keys, 3 ref---|");
+        assert_eq!(format!("{:>#10}|", currencies), "    2k, 3r|");
+    }
+    
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn generates_json_schema() {
+        let schema = serde_json::to_value(schemars::schema_for!(Currencies)).unwrap();
+        
+        assert_eq!(schema["title"], "Currencies");
+        assert_eq!(schema["properties"]["keys"]["type"], "integer");
+        assert_eq!(schema["properties"]["metal"]["type"], "number");
+        assert!(schema.get("required").is_none());
+    }
+    
+    #[cfg(feature = "ts-rs")]
+    #[test]
+    fn generates_typescript_declaration() {
+        use ts_rs::TS;
+        
+        let decl = Currencies::decl();
+        
+        assert!(decl.starts_with("type Currencies = {"));
+        assert!(decl.contains("keys?: number"));
+        assert!(decl.contains("metal?: number"));
+    }
+    
+    #[test]
+    fn currencies_equal() {
+        assert_eq!(Currencies {
+            keys: 2,
+            metal: refined!(23) + scrap!(4),
         }, Currencies {
             keys: 2,
             metal: refined!(23) + scrap!(4),
@@ -545,6 +1592,28 @@ mod tests {
         });
     }
     
+    #[test]
+    fn currencies_negated() {
+        assert_eq!(-Currencies {
+            keys: 10,
+            metal: refined!(-10),
+        }, Currencies {
+            keys: -10,
+            metal: refined!(10),
+        });
+    }
+    
+    #[test]
+    fn currencies_negated_saturating() {
+        assert_eq!(-Currencies {
+            keys: Currency::MIN,
+            metal: 0,
+        }, Currencies {
+            keys: Currency::MAX,
+            metal: 0,
+        });
+    }
+    
     #[test]
     fn currencies_multiplied_by_metal() {
         assert_eq!(Currencies {
@@ -578,6 +1647,16 @@ mod tests {
         });
     }
     
+    #[test]
+    fn borrowed_currencies_scalar_arithmetic() {
+        let currencies = &Currencies { keys: 10, metal: refined!(10) };
+        
+        assert_eq!(currencies * 2, Currencies { keys: 20, metal: refined!(20) });
+        assert_eq!(currencies / 2, Currencies { keys: 5, metal: refined!(5) });
+        assert_eq!(currencies * 2.5, Currencies { keys: 25, metal: refined!(25) });
+        assert_eq!(currencies / 2.5, Currencies { keys: 4, metal: refined!(4) });
+    }
+    
     #[test]
     fn currencies_multiplied_by_f32() {
         assert_eq!(Currencies {
@@ -649,6 +1728,41 @@ mod tests {
         });
     }
     
+    #[test]
+    fn sums_currencies() {
+        let currencies = vec![
+            Currencies { keys: 1, metal: refined!(2) },
+            Currencies { keys: 2, metal: scrap!(1) },
+        ];
+        
+        assert_eq!(currencies.iter().sum::<Currencies>(), Currencies {
+            keys: 3,
+            metal: refined!(2) + scrap!(1),
+        });
+        assert_eq!(currencies.into_iter().sum::<Currencies>(), Currencies {
+            keys: 3,
+            metal: refined!(2) + scrap!(1),
+        });
+    }
+    
+    #[test]
+    fn sums_currencies_saturating() {
+        let currencies = [
+            Currencies { keys: Currency::MAX, metal: 0 },
+            Currencies { keys: 1, metal: 0 },
+        ];
+        
+        assert_eq!(currencies.iter().sum::<Currencies>(), Currencies {
+            keys: Currency::MAX,
+            metal: 0,
+        });
+    }
+    
+    #[test]
+    fn sums_empty_iterator() {
+        assert_eq!(Vec::<Currencies>::new().into_iter().sum::<Currencies>(), Currencies::ZERO);
+    }
+    
     #[test]
     fn parses_currencies_from_string() {
         let currencies = Currencies::try_from("2 keys, 23.44 ref").unwrap();
@@ -673,6 +1787,21 @@ mod tests {
         assert_eq!(currencies.metal, refined!(2));
     }
     
+    #[test]
+    fn parses_currencies_from_string_weapon_granularity() {
+        assert_eq!(Currencies::try_from("0.05 ref").unwrap().metal, ONE_WEAPON);
+        assert_eq!(Currencies::try_from("1.05 ref").unwrap().metal, refined!(1) + ONE_WEAPON);
+        assert_eq!(Currencies::try_from("0.16 ref").unwrap().metal, scrap!(1) + ONE_WEAPON);
+    }
+    
+    #[test]
+    fn formats_currencies_weapon_granularity() {
+        assert_eq!(Currencies {
+            keys: 0,
+            metal: scrap!(1) + ONE_WEAPON,
+        }.to_string(), "0.16 ref");
+    }
+    
     #[test]
     fn parses_currencies_from_string_invalid_currencies() {
         assert!(Currencies::try_from("what").is_err());
@@ -683,6 +1812,64 @@ mod tests {
         assert!(Currencies::try_from("2 keys, 3 what").is_err());
     }
     
+    #[test]
+    fn parses_currencies_from_string_duplicate_currencies() {
+        assert_eq!(
+            Currencies::try_from("1 key, 2 keys"),
+            Err(ParseError::DuplicateCurrency {
+                currency: KEYS_SYMBOL.into(),
+                token: "2 keys".into(),
+                span: 7..13,
+            }),
+        );
+    }
+    
+    #[test]
+    fn parses_currencies_from_string_duplicate_currencies_summed() {
+        let options = ParseOptions::new().allow_duplicates(true);
+        
+        assert!(Currencies::try_from("2 keys, 3 keys").is_err());
+        assert!(Currencies::try_from("2 keys, 3 ref, 3 keys").is_err());
+        assert_eq!(Currencies::from_str_with("2 keys, 3 keys", &options).unwrap(), Currencies {
+            keys: 5,
+            metal: 0,
+        });
+    }
+    
+    #[test]
+    fn parses_currencies_from_string_long_form_units() {
+        assert_eq!(Currencies::try_from("1 key, 2 rec, 1 scrap").unwrap(), Currencies {
+            keys: 1,
+            metal: reclaimed!(2) + scrap!(1),
+        });
+    }
+    
+    #[test]
+    fn parses_negative_currencies_display_round_trip() {
+        let currencies = Currencies {
+            keys: -2,
+            metal: -(refined!(3) + scrap!(3) + 1),
+        };
+        
+        assert_eq!(currencies.to_string(), "-2 keys, -3.38 ref");
+        assert_eq!(Currencies::try_from(currencies.to_string().as_str()).unwrap(), currencies);
+        assert_eq!(Currencies::try_from("-(2 keys, 3.38 ref)").unwrap(), currencies);
+    }
+    
+    #[test]
+    fn parses_currencies_with_from_str() {
+        let currencies: Currencies = "2 keys, 23.44 ref".parse().unwrap();
+        
+        assert_eq!(currencies, Currencies {
+            keys: 2,
+            metal: refined!(23) + scrap!(4),
+        });
+        assert_eq!("what".parse::<Currencies>(), Err(ParseError::InvalidFormat {
+            token: "what".into(),
+            span: 0..4,
+        }));
+    }
+    
     #[test]
     fn gets_correct_value_from_metal() {
         assert_eq!(Currencies::from_metal(9, 10), Currencies {
@@ -707,6 +1894,30 @@ mod tests {
         });
     }
     
+    #[test]
+    fn gets_correct_value_from_metal_negative() {
+        assert_eq!(Currencies::from_metal(-21, 10), Currencies {
+            keys: -2,
+            metal: -1,
+        });
+    }
+    
+    #[test]
+    fn from_metal_is_inverse_of_to_metal() {
+        let key_price = refined!(50);
+        let currencies = Currencies {
+            keys: 20,
+            metal: refined!(12) + scrap!(3),
+        };
+        
+        assert_eq!(Currencies::from_metal(currencies.to_metal(key_price), key_price), currencies);
+    }
+    
+    #[test]
+    fn checked_from_metal_zero_key_price() {
+        assert_eq!(Currencies::checked_from_metal(10, 0), None);
+    }
+    
     #[test]
     fn gets_correct_value_from_keys_f32() {
         assert_eq!(Currencies::from_keys_f32(1.5, 10), Currencies {
@@ -723,6 +1934,21 @@ mod tests {
         }), "2 keys, 23.44 ref");
     }
     
+    #[test]
+    fn formats_currencies_alternate() {
+        let currencies = Currencies {
+            keys: 2,
+            metal: refined!(3) + scrap!(3),
+        };
+        
+        assert_eq!(format!("{:#}", currencies), "2k, 3.33r");
+        assert_eq!(format!("{}", currencies), "2 keys, 3.33 ref");
+        
+        let compact = format!("{:#}", currencies);
+        
+        assert_eq!(Currencies::from_str_with(&compact, &ParseOptions::lenient()).unwrap(), currencies);
+    }
+    
     #[test]
     fn formats_currencies_singular() {
         assert_eq!(format!("{}", Currencies {
@@ -808,59 +2034,388 @@ mod tests {
     }
     
     #[test]
-    fn rounds_metal_down_refined_whole_value() {
-        assert_eq!(Currencies {
+    fn rounds_metal_down_refined_whole_value() {
+        assert_eq!(Currencies {
+            keys: 1,
+            metal: refined!(23),
+        }.round(&Rounding::DownRefined).metal, refined!(23));
+    }
+    
+    #[test]
+    fn rounds_metal_up_refined() {
+        assert_eq!(Currencies {
+            keys: 1,
+            metal: refined!(23) + scrap!(4),
+        }.round(&Rounding::UpRefined).metal, refined!(24));
+    }
+    
+    #[test]
+    fn rounds_metal_up_refined_whole_value() {
+        assert_eq!(Currencies {
+            keys: 1,
+            metal: refined!(23),
+        }.round(&Rounding::UpRefined).metal, refined!(23));
+    }
+    
+    #[test]
+    fn rounds_metal_refined_down_correctly() {
+        assert_eq!(Currencies {
+            keys: 1,
+            metal: refined!(23) + scrap!(3),
+        }.round(&Rounding::Refined).metal, refined!(23));
+    }
+    
+    #[test]
+    fn rounds_metal_refined_down_correctly_whole_value() {
+        assert_eq!(Currencies {
+            keys: 1,
+            metal: refined!(23),
+        }.round(&Rounding::Refined).metal, refined!(23));
+    }
+    
+    #[test]
+    fn rounds_metal_refined_up_correctly() {
+        assert_eq!(Currencies {
+            keys: 1,
+            metal: refined!(23) + scrap!(5),
+        }.round(&Rounding::Refined).metal, refined!(24));
+    }
+    
+    #[test]
+    fn rounds_metal_up() {
+        assert_eq!(Currencies {
+            keys: 1,
+            metal: refined!(23) + scrap!(4) + 1,
+        }.round(&Rounding::UpScrap).metal, 424);
+    }
+    
+    #[test]
+    fn filters_empty_currencies() {
+        let currencies = [
+            Currencies { keys: 1, metal: 0 },
+            Currencies::ZERO,
+            Currencies { keys: 0, metal: 2 },
+        ];
+        let filtered = currencies
+            .into_iter()
+            .filter(|currencies| !currencies.is_empty())
+            .collect::<Vec<_>>();
+        
+        assert_eq!(filtered, vec![
+            Currencies { keys: 1, metal: 0 },
+            Currencies { keys: 0, metal: 2 },
+        ]);
+    }
+    
+    #[test]
+    fn is_negative() {
+        assert!(Currencies { keys: -1, metal: 0 }.is_negative());
+        assert!(Currencies { keys: 0, metal: -1 }.is_negative());
+        assert!(!Currencies { keys: 0, metal: 0 }.is_negative());
+        assert!(!Currencies { keys: 1, metal: -1 }.is_negative());
+    }
+    
+    #[test]
+    fn signum() {
+        assert_eq!(Currencies { keys: -1, metal: 10 }.signum(), -1);
+        assert_eq!(Currencies { keys: 0, metal: 0 }.signum(), 0);
+        assert_eq!(Currencies { keys: 1, metal: -10 }.signum(), 1);
+    }
+    
+    #[test]
+    fn abs() {
+        assert_eq!(Currencies { keys: -1, metal: 10 }.abs(), Currencies { keys: 1, metal: -10 });
+        assert_eq!(Currencies { keys: 0, metal: -10 }.abs(), Currencies { keys: 0, metal: 10 });
+        assert_eq!(Currencies { keys: 2, metal: 10 }.abs(), Currencies { keys: 2, metal: 10 });
+    }
+    
+    #[test]
+    fn multiplies_f32_rounded() {
+        assert_eq!(Currencies {
+            keys: 2,
+            metal: refined!(10),
+        }.mul_f32_rounded(1.05, &Rounding::UpRefined), Currencies {
+            keys: 2,
+            metal: refined!(11),
+        });
+    }
+    
+    #[test]
+    fn divides_f32_rounded() {
+        assert_eq!(Currencies {
+            keys: 0,
+            metal: scrap!(3),
+        }.div_f32_rounded(2.0, &Rounding::DownScrap), Currencies {
+            keys: 0,
+            metal: scrap!(1),
+        });
+    }
+    
+    #[test]
+    fn div_rem() {
+        assert_eq!(Currencies {
+            keys: 10,
+            metal: refined!(10) + 1,
+        }.div_rem(3), (Currencies {
+            keys: 3,
+            metal: refined!(3) + scrap!(3),
+        }, Currencies {
+            keys: 1,
+            metal: 1,
+        }));
+    }
+    
+    #[test]
+    fn div_rem_negative() {
+        let currencies = Currencies {
+            keys: -5,
+            metal: -refined!(1),
+        };
+        let (share, remainder) = currencies.div_rem(4);
+        
+        assert_eq!(share, Currencies {
+            keys: -1,
+            metal: -scrap!(2),
+        });
+        assert_eq!(share * 4 + remainder, currencies);
+    }
+    
+    #[test]
+    #[should_panic]
+    fn div_rem_by_zero() {
+        Currencies {
+            keys: 1,
+            metal: 0,
+        }.div_rem(0);
+    }
+    
+    #[test]
+    fn midpoint() {
+        let key_price = refined!(50);
+        
+        assert_eq!(Currencies {
+            keys: 1,
+            metal: 0,
+        }.midpoint(&Currencies {
+            keys: 2,
+            metal: 0,
+        }, key_price, &Rounding::None), Currencies {
+            keys: 1,
+            metal: refined!(25),
+        });
+    }
+    
+    #[test]
+    fn midpoint_rounds_half_weapons() {
+        let key_price = refined!(50);
+        let a = Currencies { keys: 0, metal: 3 };
+        let b = Currencies { keys: 0, metal: 6 };
+        
+        // 4.5 weapons
+        assert_eq!(a.midpoint(&b, key_price, &Rounding::UpScrap).metal, 6);
+        assert_eq!(a.midpoint(&b, key_price, &Rounding::DownScrap).metal, 4);
+    }
+    
+    #[test]
+    fn midpoint_near_integer_bounds() {
+        let key_price = refined!(50);
+        let a = Currencies { keys: 0, metal: Currency::MAX };
+        
+        assert_eq!(a.midpoint(&a, key_price, &Rounding::None), Currencies::from_metal(Currency::MAX, key_price));
+        
+        // Beyond the precision of f64.
+        let a = Currencies { keys: 0, metal: (1 << 60) + 1 };
+        let b = Currencies { keys: 0, metal: (1 << 60) + 4 };
+        
+        assert_eq!(a.midpoint(&b, i64::MAX, &Rounding::None).metal, (1 << 60) + 3);
+        assert_eq!(a.midpoint(&b, i64::MAX, &Rounding::UpScrap).metal, (1 << 60) + 4);
+        assert_eq!(a.midpoint(&b, i64::MAX, &Rounding::DownScrap).metal, (1 << 60) + 2);
+    }
+    
+    #[test]
+    fn lerp_bounds() {
+        let key_price = refined!(50);
+        let from = Currencies { keys: 1, metal: refined!(10) };
+        let to = Currencies { keys: 3, metal: scrap!(1) };
+        
+        assert_eq!(from.lerp(&to, 0.0, key_price, &Rounding::None), from);
+        assert_eq!(from.lerp(&to, 1.0, key_price, &Rounding::None), to);
+        
+        // Beyond the precision of f64.
+        let from = Currencies { keys: 0, metal: (1 << 60) + 1 };
+        let to = Currencies { keys: 0, metal: (1 << 60) + 5 };
+        
+        assert_eq!(from.lerp(&to, 0.0, i64::MAX, &Rounding::None), from);
+        assert_eq!(from.lerp(&to, 0.25, i64::MAX, &Rounding::None).metal, (1 << 60) + 2);
+        assert_eq!(from.lerp(&to, 1.0, i64::MAX, &Rounding::None), to);
+    }
+    
+    #[test]
+    fn lerp_across_key_boundary() {
+        let key_price = refined!(50);
+        let from = Currencies { keys: 0, metal: refined!(40) };
+        let to = Currencies { keys: 1, metal: refined!(10) };
+        
+        assert_eq!(from.lerp(&to, 0.5, key_price, &Rounding::None), Currencies {
+            keys: 1,
+            metal: 0,
+        });
+    }
+    
+    #[test]
+    fn with_markup() {
+        let key_price = refined!(50);
+        
+        assert_eq!(Currencies {
+            keys: 0,
+            metal: refined!(10),
+        }.with_markup(10.0, key_price, &Rounding::UpScrap), Currencies {
+            keys: 0,
+            metal: refined!(11),
+        });
+    }
+    
+    #[test]
+    fn with_markup_rounds_to_scrap() {
+        let key_price = refined!(50);
+        
+        // 1.05 ref is 18.9 weapons.
+        assert_eq!(Currencies {
+            keys: 0,
+            metal: refined!(1),
+        }.with_markup(5.0, key_price, &Rounding::UpScrap), Currencies {
+            keys: 0,
+            metal: refined!(1) + scrap!(1),
+        });
+        // Beyond the precision of f64.
+        assert_eq!(Currencies {
+            keys: 0,
+            metal: (1 << 60) + 10,
+        }.with_markup(10.0, i64::MAX, &Rounding::None).metal, 1_268_213_655_067_531_685);
+    }
+    
+    #[test]
+    fn with_markdown() {
+        let key_price = refined!(50);
+        
+        assert_eq!(Currencies {
+            keys: 2,
+            metal: 0,
+        }.with_markdown(50.0, key_price, &Rounding::DownScrap), Currencies {
+            keys: 1,
+            metal: 0,
+        });
+    }
+    
+    #[test]
+    fn percent_difference() {
+        let key_price = refined!(50);
+        
+        assert_eq!(Currencies {
+            keys: 0,
+            metal: refined!(40),
+        }.percent_difference(&Currencies {
+            keys: 0,
+            metal: refined!(30),
+        }, key_price), -25.0);
+    }
+    
+    #[test]
+    fn percent_difference_from_negative_value() {
+        let key_price = refined!(50);
+        
+        assert_eq!(Currencies {
+            keys: 0,
+            metal: -refined!(10),
+        }.percent_difference(&Currencies {
+            keys: 0,
+            metal: refined!(10),
+        }, key_price), 200.0);
+    }
+    
+    #[test]
+    fn percent_difference_from_zero() {
+        let key_price = refined!(50);
+        
+        assert!(Currencies::ZERO.percent_difference(&Currencies {
             keys: 1,
-            metal: refined!(23),
-        }.round(&Rounding::DownRefined).metal, refined!(23));
+            metal: 0,
+        }, key_price).is_infinite());
     }
     
     #[test]
-    fn rounds_metal_up_refined() {
-        assert_eq!(Currencies {
+    fn rounds_in_place() {
+        let mut currencies = Currencies {
             keys: 1,
-            metal: refined!(23) + scrap!(4),
-        }.round(&Rounding::UpRefined).metal, refined!(24));
+            metal: scrap!(1),
+        };
+        
+        currencies.round_mut(&Rounding::UpRefined);
+        
+        assert_eq!(currencies, Currencies {
+            keys: 1,
+            metal: refined!(1),
+        });
     }
     
     #[test]
-    fn rounds_metal_up_refined_whole_value() {
+    fn rounds_with_key_price_carrying_into_keys() {
+        let key_price = refined!(50);
+        
         assert_eq!(Currencies {
+            keys: 0,
+            metal: refined!(49) + scrap!(5),
+        }.round_with_key_price(&Rounding::Refined, key_price), Currencies {
             keys: 1,
-            metal: refined!(23),
-        }.round(&Rounding::UpRefined).metal, refined!(23));
+            metal: 0,
+        });
     }
     
     #[test]
-    fn rounds_metal_refined_down_correctly() {
+    fn rounds_with_key_price_without_carry() {
+        let key_price = refined!(50);
+        
         assert_eq!(Currencies {
-            keys: 1,
-            metal: refined!(23) + scrap!(3),
-        }.round(&Rounding::Refined).metal, refined!(23));
+            keys: 0,
+            metal: refined!(49) + scrap!(5),
+        }.round_with_key_price(&Rounding::DownRefined, key_price), Currencies {
+            keys: 0,
+            metal: refined!(49),
+        });
     }
     
     #[test]
-    fn rounds_metal_refined_down_correctly_whole_value() {
+    fn neatens_raw_metal_value() {
+        let key_price = refined!(50);
+        
         assert_eq!(Currencies {
-            keys: 1,
-            metal: refined!(23),
-        }.round(&Rounding::Refined).metal, refined!(23));
+            keys: 0,
+            metal: refined!(183),
+        }.neaten(key_price), Currencies {
+            keys: 3,
+            metal: refined!(33),
+        });
     }
     
     #[test]
-    fn rounds_metal_refined_up_correctly() {
+    fn denormalizes() {
+        let key_price = refined!(50);
+        
         assert_eq!(Currencies {
             keys: 1,
-            metal: refined!(23) + scrap!(5),
-        }.round(&Rounding::Refined).metal, refined!(24));
+            metal: -refined!(5),
+        }.denormalize(key_price), Currencies {
+            keys: 0,
+            metal: refined!(45),
+        });
     }
     
     #[test]
-    fn rounds_metal_up() {
+    fn denormalize_saturates() {
         assert_eq!(Currencies {
-            keys: 1,
-            metal: refined!(23) + scrap!(4) + 1,
-        }.round(&Rounding::UpScrap).metal, 424);
+            keys: Currency::MAX,
+            metal: 0,
+        }.denormalize(refined!(50)).metal, Currency::MAX);
     }
     
     #[test]
@@ -936,6 +2491,39 @@ mod tests {
         }, currencies);
     }
     
+    #[test]
+    fn deserializes_currencies_with_string_metal() {
+        let currencies: Currencies = serde_json::from_str(r#"{"keys":1,"metal":"23.44"}"#).unwrap();
+        
+        assert_eq!(Currencies {
+            keys: 1,
+            metal: refined!(23) + scrap!(4),
+        }, currencies);
+    }
+    
+    #[test]
+    fn serializes_bincode() {
+        for currencies in [
+            Currencies { keys: 2, metal: refined!(3) + scrap!(3) + 1 },
+            Currencies { keys: 0, metal: 1 },
+            Currencies { keys: -5, metal: 0 },
+            Currencies { keys: i64::MAX, metal: i64::MIN },
+        ] {
+            let bytes = bincode::serialize(&currencies).unwrap();
+            
+            // each field is written as an 8-byte integer
+            assert_eq!(bytes.len(), 16);
+            assert_eq!(bincode::deserialize::<Currencies>(&bytes).unwrap(), currencies);
+        }
+    }
+    
+    #[test]
+    fn deserializes_bincode_without_values() {
+        let bytes = bincode::serialize(&(0i64, 0i64)).unwrap();
+        
+        assert!(bincode::deserialize::<Currencies>(&bytes).is_err());
+    }
+    
     #[test]
     fn deserializes_currencies_with_no_keys() {
         let currencies: Currencies = serde_json::from_str(r#"{"metal": 23.44}"#).unwrap();
@@ -966,6 +2554,16 @@ mod tests {
         }, currencies);
     }
     
+    #[test]
+    fn deserializes_currencies_with_large_metal_value() {
+        let currencies: Currencies = serde_json::from_str(r#"{"metal": 1000000.33}"#).unwrap();
+        
+        assert_eq!(Currencies {
+            keys: 0,
+            metal: refined!(1000000) + scrap!(3),
+        }, currencies);
+    }
+    
     #[test]
     fn serializes_currencies() {
         let currencies = Currencies {
@@ -1004,6 +2602,55 @@ mod tests {
         );
     }
     
+    #[test]
+    fn serializes_metal_round_trip() {
+        for metal in -refined!(200)..=refined!(200) {
+            let currencies = Currencies {
+                keys: 2,
+                metal,
+            };
+            let currencies_json = serde_json::to_string(&currencies).unwrap();
+            
+            assert_eq!(serde_json::from_str::<Currencies>(&currencies_json).unwrap(), currencies);
+        }
+    }
+    
+    #[test]
+    fn serializes_large_metal_round_trip() {
+        for metal in refined!(1_000_000_000)..refined!(1_000_000_001) {
+            let currencies = Currencies {
+                keys: 0,
+                metal,
+            };
+            let currencies_json = serde_json::to_string(&currencies).unwrap();
+            
+            assert_eq!(serde_json::from_str::<Currencies>(&currencies_json).unwrap(), currencies);
+        }
+    }
+    
+    #[test]
+    fn formats_large_metal() {
+        assert_eq!(Currencies {
+            keys: 0,
+            metal: refined!(1_000_000) + scrap!(2),
+        }.to_string(), "1000000.22 ref");
+    }
+    
+    #[test]
+    fn deserializes_listing_currencies_round_trip() {
+        for json in [
+            r#"{"keys":2,"metal":35.66}"#,
+            r#"{"keys":2}"#,
+            r#"{"metal":0.05}"#,
+            r#"{"metal":-1.11}"#,
+            r#"{"keys":1,"metal":7}"#,
+        ] {
+            let currencies: Currencies = serde_json::from_str(json).unwrap();
+            
+            assert_eq!(serde_json::to_string(&currencies).unwrap(), json);
+        }
+    }
+    
     #[test]
     fn accepts_trait_currencies() {
         fn get_keys<T>(currencies: &T) -> String
@@ -1027,9 +2674,93 @@ mod tests {
         assert!(Currencies { keys: 0, metal: 1 } < Currencies { keys: 0, metal: 4});
     }
     
+    #[test]
+    fn orders_lexicographically() {
+        assert!(Currencies { keys: 1, metal: 0 } > Currencies { keys: 0, metal: refined!(1000) });
+        assert!(Currencies { keys: 1, metal: 2 } > Currencies { keys: 1, metal: 1 });
+        assert!(Currencies { keys: -1, metal: refined!(1000) } < Currencies { keys: 0, metal: -1 });
+    }
+    
+    #[test]
+    fn usable_as_btree_map_key() {
+        use std::collections::BTreeMap;
+        
+        let mut map = BTreeMap::new();
+        
+        map.insert(Currencies { keys: 2, metal: 0 }, "b");
+        map.insert(Currencies { keys: 0, metal: refined!(5) }, "a");
+        map.insert(Currencies { keys: 2, metal: refined!(5) }, "c");
+        
+        assert_eq!(map.values().copied().collect::<Vec<_>>(), vec!["a", "b", "c"]);
+    }
+    
+    #[test]
+    fn usable_as_hash_set_value() {
+        use std::collections::HashSet;
+        
+        let currencies = [
+            Currencies { keys: 1, metal: refined!(5) },
+            Currencies { keys: 1, metal: refined!(5) },
+            Currencies { keys: 0, metal: refined!(5) },
+        ];
+        let unique = currencies.into_iter().collect::<HashSet<_>>();
+        
+        assert_eq!(unique.len(), 2);
+        assert!(unique.contains(&Currencies { keys: 1, metal: refined!(5) }));
+    }
+    
+    #[test]
+    fn compares_with_key_price() {
+        let key_price = refined!(50);
+        
+        assert_eq!(
+            Currencies { keys: 1, metal: 0 }.cmp_with_key_price(&Currencies { keys: 0, metal: refined!(50) }, key_price),
+            Ordering::Equal,
+        );
+        assert_eq!(
+            Currencies { keys: 2, metal: -refined!(10) }.cmp_with_key_price(&Currencies { keys: 1, metal: refined!(30) }, key_price),
+            Ordering::Greater,
+        );
+    }
+    
+    #[test]
+    fn compares_with_key_price_near_integer_bounds() {
+        assert_eq!(
+            Currencies { keys: Currency::MAX, metal: 0 }.cmp_with_key_price(&Currencies { keys: Currency::MAX, metal: 1 }, 10),
+            Ordering::Less,
+        );
+    }
+    
+    #[test]
+    fn clamps_using_total_value() {
+        let key_price = refined!(50);
+        let min = Currencies { keys: 1, metal: 0 };
+        let max = Currencies { keys: 2, metal: 0 };
+        
+        // Field-wise this would be less than min.
+        assert_eq!(Currencies { keys: 0, metal: refined!(60) }.clamp(min, max, key_price), Currencies {
+            keys: 0,
+            metal: refined!(60),
+        });
+        assert_eq!(Currencies { keys: 0, metal: refined!(40) }.clamp(min, max, key_price), min);
+        assert_eq!(Currencies { keys: 1, metal: refined!(51) }.clamp(min, max, key_price), max);
+    }
+    
+    #[test]
+    #[should_panic]
+    fn clamp_panics_when_min_greater_than_max() {
+        let key_price = refined!(50);
+        
+        Currencies { keys: 1, metal: 0 }.clamp(
+            Currencies { keys: 2, metal: 0 },
+            Currencies { keys: 0, metal: refined!(60) },
+            key_price,
+        );
+    }
+    
     #[test]
     fn sorts() {
-        let mut currencies = vec![
+        let mut currencies = [
             Currencies { keys: 2, metal: 4},
             Currencies { keys: 0, metal: 2},
             Currencies { keys: 10, metal: 4},
@@ -1038,7 +2769,7 @@ mod tests {
         // lowest to highest
         currencies.sort();
         
-        assert_eq!(*currencies.iter().rev().next().unwrap(), Currencies { keys: 10, metal: 4});
+        assert_eq!(*currencies.iter().next_back().unwrap(), Currencies { keys: 10, metal: 4});
     }
     
     #[test]
@@ -1065,6 +2796,117 @@ mod tests {
         );
     }
     
+    #[test]
+    fn checked_mul_correct_value() {
+        assert_eq!(
+            Currencies { keys: 2, metal: 5 }.checked_mul(3),
+            Some(Currencies { keys: 6, metal: 15 }),
+        );
+    }
+    
+    #[test]
+    fn checked_mul_metal_overflow() {
+        assert_eq!(Currencies { keys: 0, metal: 2 }.checked_mul(Currency::MAX), None);
+    }
+    
+    #[test]
+    fn checked_div() {
+        assert_eq!(Currencies { keys: 2, metal: 0 }.checked_div(0), None);
+    }
+    
+    #[test]
+    fn checked_div_overflow() {
+        assert_eq!(Currencies { keys: Currency::MIN, metal: 0 }.checked_div(-1), None);
+    }
+    
+    #[test]
+    fn checked_div_correct_value() {
+        assert_eq!(
+            Currencies { keys: 6, metal: 15 }.checked_div(3),
+            Some(Currencies { keys: 2, metal: 5 }),
+        );
+    }
+    
+    #[test]
+    fn checked_sub() {
+        assert_eq!(
+            Currencies { keys: -2, metal: 0 }.checked_sub(&Currencies { keys: Currency::MAX, metal: 0 }),
+            None,
+        );
+    }
+    
+    #[test]
+    fn checked_sub_metal_overflow() {
+        assert_eq!(
+            Currencies { keys: 0, metal: Currency::MIN }.checked_sub(&Currencies { keys: 0, metal: 1 }),
+            None,
+        );
+    }
+    
+    #[test]
+    fn checked_sub_correct_value() {
+        assert_eq!(
+            Currencies { keys: 5, metal: 10 }.checked_sub(&Currencies { keys: 2, metal: 4 }),
+            Some(Currencies { keys: 3, metal: 6 }),
+        );
+    }
+    
+    #[test]
+    fn saturating_add() {
+        assert_eq!(
+            Currencies { keys: 2, metal: Currency::MAX }.saturating_add(&Currencies { keys: 1, metal: 1 }),
+            Currencies { keys: 3, metal: Currency::MAX },
+        );
+    }
+    
+    #[test]
+    fn saturating_sub() {
+        assert_eq!(
+            Currencies { keys: -2, metal: 5 }.saturating_sub(&Currencies { keys: Currency::MAX, metal: 1 }),
+            Currencies { keys: Currency::MIN, metal: 4 },
+        );
+    }
+    
+    #[test]
+    fn saturating_mul() {
+        assert_eq!(
+            Currencies { keys: -2, metal: 5 }.saturating_mul(Currency::MAX),
+            Currencies { keys: Currency::MIN, metal: Currency::MAX },
+        );
+    }
+    
+    #[test]
+    fn overflowing_add() {
+        assert_eq!(
+            Currencies { keys: 2, metal: Currency::MAX }.overflowing_add(&Currencies { keys: 1, metal: 1 }),
+            (Currencies { keys: 3, metal: Currency::MIN }, true),
+        );
+    }
+    
+    #[test]
+    fn overflowing_add_no_overflow() {
+        assert_eq!(
+            Currencies { keys: 2, metal: 5 }.overflowing_add(&Currencies { keys: 1, metal: 1 }),
+            (Currencies { keys: 3, metal: 6 }, false),
+        );
+    }
+    
+    #[test]
+    fn overflowing_sub() {
+        assert_eq!(
+            Currencies { keys: Currency::MIN, metal: 5 }.overflowing_sub(&Currencies { keys: 1, metal: 1 }),
+            (Currencies { keys: Currency::MAX, metal: 4 }, true),
+        );
+    }
+    
+    #[test]
+    fn overflowing_mul() {
+        assert_eq!(
+            Currencies { keys: 1, metal: Currency::MAX }.overflowing_mul(2),
+            (Currencies { keys: 2, metal: -2 }, true),
+        );
+    }
+    
     #[test]
     fn checked_to_metal() {
         assert_eq!(
@@ -1080,4 +2922,82 @@ mod tests {
             Some(105),
         );
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn rounds_to_keys() {
+        let key_price = refined!(60);
+        let currencies = Currencies { keys: 1, metal: refined!(30) };
+        
+        assert_eq!(currencies.round_to_keys(RoundingDirection::Nearest, key_price), Currencies { keys: 2, metal: 0 });
+        assert_eq!(currencies.round_to_keys(RoundingDirection::Down, key_price), Currencies { keys: 1, metal: 0 });
+        assert_eq!((-currencies).round_to_keys(RoundingDirection::TowardZero, key_price), Currencies { keys: -1, metal: 0 });
+        assert_eq!((-currencies).round_to_keys(RoundingDirection::AwayFromZero, key_price), Currencies { keys: -2, metal: 0 });
+        assert_eq!(
+            Currencies { keys: 2, metal: -refined!(1) }.round_to_keys(RoundingDirection::Up, key_price),
+            Currencies { keys: 2, metal: 0 },
+        );
+        assert_eq!(
+            Currencies { keys: 0, metal: refined!(130) }.round_to_keys(RoundingDirection::Down, key_price),
+            Currencies { keys: 2, metal: 0 },
+        );
+    }
+    
+    #[test]
+    fn rounds_to_keys_edge_cases() {
+        assert_eq!(
+            Currencies { keys: Currency::MAX, metal: Currency::MAX }.round_to_keys(RoundingDirection::Up, 2),
+            Currencies { keys: Currency::MAX, metal: 0 },
+        );
+        assert_eq!(
+            Currencies { keys: Currency::MAX, metal: 1 }.round_to_keys(RoundingDirection::Down, Currency::MAX),
+            Currencies { keys: Currency::MAX, metal: 0 },
+        );
+    }
+    
+    #[test]
+    #[should_panic]
+    fn round_to_keys_panics_with_zero_key_price() {
+        Currencies { keys: 1, metal: refined!(30) }.round_to_keys(RoundingDirection::Up, 0);
+    }
+    
+    #[test]
+    #[should_panic]
+    fn round_to_keys_panics_with_negative_key_price() {
+        Currencies { keys: 1, metal: refined!(30) }.round_to_keys(RoundingDirection::Up, -1);
+    }
+    
+    #[test]
+    fn checked_round() {
+        assert_eq!(
+            Currencies { keys: 2, metal: 5 }.checked_round(&Rounding::UpScrap),
+            Some(Currencies { keys: 2, metal: 6 }),
+        );
+        assert_eq!(Currencies { keys: 2, metal: Currency::MAX }.checked_round(&Rounding::UpScrap), None);
+        assert_eq!(
+            Currencies { keys: 2, metal: Currency::MAX }.round(&Rounding::UpScrap),
+            Currencies { keys: 2, metal: Currency::MAX },
+        );
+    }
+    
+    #[test]
+    fn rounds_negative_refined_consistently() {
+        let currencies = Currencies { keys: 0, metal: -10 };
+        let expected = Currencies { keys: 0, metal: -refined!(1) };
+        
+        assert_eq!(currencies.round(&Rounding::Refined), expected);
+        assert_eq!(currencies.mul_f32_rounded(1.0, &Rounding::Refined), expected);
+        assert_eq!(currencies.midpoint(&currencies, refined!(50), &Rounding::Refined), expected);
+    }
+    
+    #[test]
+    fn mul_f32_rounded_drops_fractional_keys() {
+        let currencies = Currencies { keys: 1, metal: 0 };
+        
+        assert_eq!(currencies.mul_f32_rounded(0.9, &Rounding::None), currencies);
+        assert_eq!(currencies.mul_f32_rounded(0.4, &Rounding::None), Currencies::default());
+        assert_eq!(
+            currencies.with_markdown(10.0, refined!(50), &Rounding::None),
+            Currencies { keys: 0, metal: refined!(45) },
+        );
+    }
+}
@@ -0,0 +1,199 @@
+//! [`rust_decimal`] support. Available with the `decimal` feature.
+//! 
+//! Conversions between metal values (represented as weapons) and refined [`Decimal`] values are
+//! done without binary floats, so `scrap!(3)` is exactly `0.33` rather than `0.329999…`. Key
+//! values can also be parsed exactly, e.g. for fractional key prices in listings.
+//! 
+//! # Examples
+//! ```
+//! use tf2_price::{refined, scrap};
+//! use tf2_price::decimal::{get_metal_decimal, get_metal_from_decimal};
+//! use rust_decimal::Decimal;
+//! 
+//! let refined = get_metal_decimal(refined!(3) + scrap!(3));
+//! 
+//! assert_eq!(refined, Decimal::new(333, 2));
+//! assert_eq!(get_metal_from_decimal(refined), Some(refined!(3) + scrap!(3)));
+//! ```
+
+use crate::error::ParseError;
+use crate::types::Currency;
+use crate::constants::ONE_REF;
+use crate::parse::{self, KeyCount, ParseOptions};
+use crate::{helpers, Currencies};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::{Decimal, RoundingStrategy};
+
+impl KeyCount for Decimal {
+    fn add_keys(self, other: Self) -> Self {
+        self.saturating_add(other)
+    }
+}
+
+/// Converts a metal value into refined, truncated to 2 decimal places. This is the exact
+/// equivalent of [`get_metal_float`](crate::get_metal_float).
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::decimal::get_metal_decimal;
+/// use rust_decimal::Decimal;
+/// 
+/// assert_eq!(get_metal_decimal(6), Decimal::new(33, 2));
+/// assert_eq!(get_metal_decimal(-6).to_string(), "-0.33");
+/// ```
+pub fn get_metal_decimal(value: Currency) -> Decimal {
+    Decimal::new(helpers::get_metal_hundredths(value), 2)
+}
+
+/// Converts a refined value into a metal value, rounding half away from zero to the nearest
+/// weapon. `None` if the result overflows. This is the exact equivalent of
+/// [`get_metal_from_float`](crate::get_metal_from_float).
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{refined, scrap};
+/// use tf2_price::decimal::get_metal_from_decimal;
+/// use rust_decimal::Decimal;
+/// 
+/// assert_eq!(get_metal_from_decimal(Decimal::new(133, 2)), Some(refined!(1) + scrap!(3)));
+/// assert_eq!(get_metal_from_decimal(Decimal::MAX), None);
+/// ```
+pub fn get_metal_from_decimal(value: Decimal) -> Option<Currency> {
+    value.checked_mul(Decimal::from(ONE_REF))?
+        .round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
+        .to_i64()
+}
+
+/// Converts currencies into their total value in keys using the given key price (represented as
+/// weapons). `None` if `key_price` is `0`. Results which cannot be represented exactly are
+/// rounded to the precision of [`Decimal`].
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, refined};
+/// use tf2_price::decimal::get_keys_decimal;
+/// use rust_decimal::Decimal;
+/// 
+/// let currencies = Currencies { keys: 2, metal: refined!(25) };
+/// 
+/// assert_eq!(get_keys_decimal(&currencies, refined!(50)), Some(Decimal::new(25, 1)));
+/// ```
+pub fn get_keys_decimal(currencies: &Currencies, key_price: Currency) -> Option<Decimal> {
+    let metal = Decimal::from(currencies.metal).checked_div(Decimal::from(key_price))?;
+    
+    Decimal::from(currencies.keys).checked_add(metal)
+}
+
+/// Converts a key value into currencies using the given key price (represented as weapons). The
+/// fractional part of the keys is converted into metal, rounding half away from zero to the
+/// nearest weapon. `None` if the result overflows.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, refined};
+/// use tf2_price::decimal::currencies_from_keys_decimal;
+/// use rust_decimal::Decimal;
+/// 
+/// assert_eq!(
+///     currencies_from_keys_decimal(Decimal::new(15, 1), refined!(60)),
+///     Some(Currencies { keys: 1, metal: refined!(30) }),
+/// );
+/// ```
+pub fn currencies_from_keys_decimal(keys: Decimal, key_price: Currency) -> Option<Currencies> {
+    let metal = keys.fract()
+        .checked_mul(Decimal::from(key_price))?
+        .round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
+        .to_i64()?;
+    
+    Some(Currencies {
+        keys: keys.trunc().to_i64()?,
+        metal,
+    })
+}
+
+/// Parses a key value and a metal value (represented as weapons) from a string using the given
+/// options. Keys are parsed exactly as a [`Decimal`], e.g. "1.1 keys, 3.33 ref".
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{ParseOptions, refined, scrap};
+/// use tf2_price::decimal::parse_keys_decimal;
+/// use rust_decimal::Decimal;
+/// 
+/// let (keys, metal) = parse_keys_decimal("1.1 keys, 3.33 ref", &ParseOptions::new()).unwrap();
+/// 
+/// assert_eq!(keys, Decimal::new(11, 1));
+/// assert_eq!(metal, refined!(3) + scrap!(3));
+/// ```
+pub fn parse_keys_decimal(
+    string: &str,
+    options: &ParseOptions,
+) -> Result<(Decimal, Currency), ParseError> {
+    parse::parse_currencies::<Decimal>(string, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{refined, reclaimed, scrap, get_metal_float};
+    
+    #[test]
+    fn converts_metal_to_decimal() {
+        assert_eq!(get_metal_decimal(0), Decimal::ZERO);
+        assert_eq!(get_metal_decimal(refined!(1) + reclaimed!(2)).to_string(), "1.66");
+        
+        for metal in [1, scrap!(1), refined!(70) + scrap!(5), -reclaimed!(1)] {
+            assert_eq!(
+                get_metal_decimal(metal).to_string(),
+                get_metal_float(metal).to_string(),
+            );
+        }
+    }
+    
+    #[test]
+    fn converts_large_metal_to_decimal_exactly() {
+        let metal = refined!(500_000_000) + scrap!(1);
+        
+        assert_eq!(get_metal_decimal(metal).to_string(), "500000000.11");
+        assert_eq!(get_metal_from_decimal(get_metal_decimal(metal)), Some(metal));
+    }
+    
+    #[test]
+    fn converts_decimal_to_metal() {
+        assert_eq!(get_metal_from_decimal(Decimal::new(33, 2)), Some(scrap!(3)));
+        assert_eq!(get_metal_from_decimal(Decimal::new(-33, 2)), Some(-scrap!(3)));
+        // 0.25 ref is 4.5 weapons
+        assert_eq!(get_metal_from_decimal(Decimal::new(25, 2)), Some(5));
+        assert_eq!(get_metal_from_decimal(Decimal::from(i64::MAX)), None);
+    }
+    
+    #[test]
+    fn converts_keys_decimal() {
+        let key_price = refined!(60);
+        let currencies = Currencies { keys: 1, metal: refined!(20) };
+        let keys = get_keys_decimal(&currencies, key_price).unwrap();
+        
+        assert_eq!(keys.round_dp(4), Decimal::new(13333, 4));
+        assert_eq!(currencies_from_keys_decimal(keys, key_price), Some(currencies));
+        assert_eq!(get_keys_decimal(&currencies, 0), None);
+        assert_eq!(currencies_from_keys_decimal(Decimal::MAX, key_price), None);
+    }
+    
+    #[test]
+    fn parses_keys_decimal() {
+        let options = ParseOptions::new();
+        
+        assert_eq!(
+            parse_keys_decimal("0.1 keys, 0.2 keys", &options.allow_duplicates(true)),
+            Ok((Decimal::new(3, 1), 0)),
+        );
+        assert_eq!(
+            parse_keys_decimal("-(2.5 keys, 1 ref)", &options),
+            Ok((Decimal::new(-25, 1), -refined!(1))),
+        );
+        assert!(matches!(
+            parse_keys_decimal("NaN keys", &options),
+            Err(ParseError::InvalidKeyCount { .. }),
+        ));
+    }
+}
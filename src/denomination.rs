@@ -0,0 +1,388 @@
+use crate::types::Currency;
+use crate::error::PaymentError;
+use crate::constants::{ONE_REF, ONE_REC, ONE_SCRAP, ONE_WEAPON};
+use crate::Currencies;
+
+/// A breakdown of currencies into the physical items needed to represent them, e.g.
+/// `1 key, 1.44 ref` is `1` key, `1` refined, `1` reclaimed, `1` scrap and `0` weapons.
+/// 
+/// Counts of negative currencies are negative.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, Denomination, refined, scrap};
+/// 
+/// let currencies = Currencies { keys: 1, metal: refined!(1) + scrap!(4) };
+/// 
+/// assert_eq!(currencies.denominate(), Denomination {
+///     keys: 1,
+///     refined: 1,
+///     reclaimed: 1,
+///     scrap: 1,
+///     weapons: 0,
+/// });
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub struct Denomination {
+    /// Number of keys.
+    pub keys: Currency,
+    /// Number of refined metal.
+    pub refined: Currency,
+    /// Number of reclaimed metal.
+    pub reclaimed: Currency,
+    /// Number of scrap metal.
+    pub scrap: Currency,
+    /// Number of craft weapons.
+    pub weapons: Currency,
+}
+
+impl Denomination {
+    /// Breaks `metal` (represented as weapons) into the fewest number of refined, reclaimed, scrap
+    /// and weapons, keeping `keys` as-is.
+    pub fn new(keys: Currency, metal: Currency) -> Self {
+        let refined = metal / ONE_REF;
+        let metal = metal % ONE_REF;
+        let reclaimed = metal / ONE_REC;
+        let metal = metal % ONE_REC;
+        let scrap = metal / ONE_SCRAP;
+        let weapons = (metal % ONE_SCRAP) / ONE_WEAPON;
+        
+        Self {
+            keys,
+            refined,
+            reclaimed,
+            scrap,
+            weapons,
+        }
+    }
+    
+    /// The total metal value of the denomination (represented as weapons). This method is
+    /// saturating.
+    pub fn metal(&self) -> Currency {
+        self.refined.saturating_mul(ONE_REF)
+            .saturating_add(self.reclaimed.saturating_mul(ONE_REC))
+            .saturating_add(self.scrap.saturating_mul(ONE_SCRAP))
+            .saturating_add(self.weapons.saturating_mul(ONE_WEAPON))
+    }
+    
+    /// Converts the denomination back to [`Currencies`]. This method is saturating.
+    pub fn to_currencies(&self) -> Currencies {
+        Currencies {
+            keys: self.keys,
+            metal: self.metal(),
+        }
+    }
+}
+
+impl From<Denomination> for Currencies {
+    fn from(denomination: Denomination) -> Self {
+        denomination.to_currencies()
+    }
+}
+
+impl From<&Denomination> for Currencies {
+    fn from(denomination: &Denomination) -> Self {
+        denomination.to_currencies()
+    }
+}
+
+/// The items a party has available to pay with. Negative counts are treated as `0`.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, Denomination, Stock, refined, scrap};
+/// 
+/// let stock = Stock { keys: 2, refined: 5, reclaimed: 0, scrap: 0, weapons: 0 };
+/// let payment = stock.pay(&Currencies { keys: 1, metal: refined!(1) + scrap!(4) }).unwrap();
+/// 
+/// // 2 ref is given with 0.55 ref returned as change.
+/// assert_eq!(payment.pay, Denomination { keys: 1, refined: 2, ..Default::default() });
+/// assert_eq!(payment.change, Denomination { reclaimed: 1, scrap: 2, ..Default::default() });
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub struct Stock {
+    /// Number of keys.
+    pub keys: Currency,
+    /// Number of refined metal.
+    pub refined: Currency,
+    /// Number of reclaimed metal.
+    pub reclaimed: Currency,
+    /// Number of scrap metal.
+    pub scrap: Currency,
+    /// Number of craft weapons.
+    pub weapons: Currency,
+}
+
+/// The result of paying a price from a [`Stock`].
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Payment {
+    /// The items given by the paying party.
+    pub pay: Denomination,
+    /// The items the other party must give back as change.
+    pub change: Denomination,
+}
+
+impl Stock {
+    /// Computes the items to pay `price` with from this stock, along with the change the other 
+    /// party must return. Metal is paid exactly when possible, otherwise overpaid by as little as 
+    /// possible. Keys must be paid exactly since no key price is available to convert them.
+    pub fn pay(&self, price: &Currencies) -> Result<Payment, PaymentError> {
+        if price.keys < 0 || price.metal < 0 {
+            return Err(PaymentError::NegativeValue);
+        }
+        
+        let keys = self.keys.max(0);
+        
+        if keys < price.keys {
+            return Err(PaymentError::InsufficientKeys {
+                needed: price.keys,
+                available: keys,
+            });
+        }
+        
+        let available = self.metal();
+        
+        if available < price.metal {
+            return Err(PaymentError::InsufficientMetal {
+                needed: price.metal,
+                available,
+            });
+        }
+        
+        // Every item is worth at most one refined, so the smallest amount that can be paid 
+        // exactly is always less than one refined over the price.
+        for overpay in 0..ONE_REF {
+            let Some(amount) = price.metal.checked_add(overpay) else {
+                break;
+            };
+            
+            if let Some(mut pay) = self.take_exact(amount) {
+                pay.keys = price.keys;
+                
+                return Ok(Payment {
+                    pay,
+                    change: Denomination::new(0, overpay),
+                });
+            }
+        }
+        
+        Err(PaymentError::InsufficientMetal {
+            needed: price.metal,
+            available,
+        })
+    }
+    
+    /// The total metal value of the stock (represented as weapons). This method is saturating.
+    pub fn metal(&self) -> Currency {
+        self.to_denomination().metal()
+    }
+    
+    fn to_denomination(self) -> Denomination {
+        Denomination {
+            keys: self.keys.max(0),
+            refined: self.refined.max(0),
+            reclaimed: self.reclaimed.max(0),
+            scrap: self.scrap.max(0),
+            weapons: self.weapons.max(0),
+        }
+    }
+    
+    /// Takes items totalling exactly `metal`. Since each metal unit is a multiple of the next 
+    /// smallest, taking as many of the largest items as possible always finds a combination if 
+    /// one exists.
+    fn take_exact(&self, metal: Currency) -> Option<Denomination> {
+        let stock = self.to_denomination();
+        let mut remaining = metal;
+        let mut take = |count: Currency, value: Currency| {
+            let taken = count.min(remaining / value);
+            
+            remaining -= taken * value;
+            taken
+        };
+        let refined = take(stock.refined, ONE_REF);
+        let reclaimed = take(stock.reclaimed, ONE_REC);
+        let scrap = take(stock.scrap, ONE_SCRAP);
+        let weapons = take(stock.weapons, ONE_WEAPON);
+        
+        if remaining != 0 {
+            return None;
+        }
+        
+        Some(Denomination {
+            keys: 0,
+            refined,
+            reclaimed,
+            scrap,
+            weapons,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{refined, reclaimed, scrap};
+    
+    #[test]
+    fn denominates_metal() {
+        assert_eq!(Denomination::new(0, refined!(3) + reclaimed!(2) + scrap!(2) + 1), Denomination {
+            keys: 0,
+            refined: 3,
+            reclaimed: 2,
+            scrap: 2,
+            weapons: 1,
+        });
+    }
+    
+    #[test]
+    fn denominates_negative_metal() {
+        assert_eq!(Denomination::new(-1, -(refined!(1) + scrap!(1))), Denomination {
+            keys: -1,
+            refined: -1,
+            reclaimed: 0,
+            scrap: -1,
+            weapons: 0,
+        });
+    }
+    
+    #[test]
+    fn pays_exact_with_stock() {
+        let stock = Stock {
+            keys: 1,
+            refined: 1,
+            reclaimed: 3,
+            scrap: 1,
+            weapons: 0,
+        };
+        let payment = stock.pay(&Currencies {
+            keys: 1,
+            metal: refined!(1) + reclaimed!(2) + scrap!(1),
+        }).unwrap();
+        
+        assert_eq!(payment.pay, Denomination {
+            keys: 1,
+            refined: 1,
+            reclaimed: 2,
+            scrap: 1,
+            weapons: 0,
+        });
+        assert_eq!(payment.change, Denomination::default());
+    }
+    
+    #[test]
+    fn pays_using_smaller_items_when_larger_would_overpay() {
+        let stock = Stock {
+            keys: 0,
+            refined: 1,
+            reclaimed: 0,
+            scrap: 9,
+            weapons: 0,
+        };
+        let payment = stock.pay(&Currencies {
+            keys: 0,
+            metal: scrap!(4),
+        }).unwrap();
+        
+        assert_eq!(payment.pay.scrap, 4);
+        assert_eq!(payment.pay.refined, 0);
+        assert_eq!(payment.change, Denomination::default());
+    }
+    
+    #[test]
+    fn pays_with_change() {
+        let stock = Stock {
+            keys: 0,
+            refined: 2,
+            reclaimed: 1,
+            scrap: 0,
+            weapons: 0,
+        };
+        let payment = stock.pay(&Currencies {
+            keys: 0,
+            metal: refined!(1) + scrap!(1),
+        }).unwrap();
+        
+        assert_eq!(payment.pay, Denomination {
+            keys: 0,
+            refined: 1,
+            reclaimed: 1,
+            scrap: 0,
+            weapons: 0,
+        });
+        assert_eq!(payment.change, Denomination {
+            keys: 0,
+            refined: 0,
+            reclaimed: 0,
+            scrap: 2,
+            weapons: 0,
+        });
+    }
+    
+    #[test]
+    fn pays_weapons() {
+        let stock = Stock {
+            keys: 0,
+            refined: 0,
+            reclaimed: 0,
+            scrap: 1,
+            weapons: 1,
+        };
+        let payment = stock.pay(&Currencies {
+            keys: 0,
+            metal: 3,
+        }).unwrap();
+        
+        assert_eq!(payment.pay.weapons, 1);
+        assert_eq!(payment.pay.scrap, 1);
+    }
+    
+    #[test]
+    fn insufficient_keys() {
+        let stock = Stock {
+            keys: 1,
+            ..Default::default()
+        };
+        
+        assert_eq!(stock.pay(&Currencies {
+            keys: 2,
+            metal: 0,
+        }), Err(PaymentError::InsufficientKeys {
+            needed: 2,
+            available: 1,
+        }));
+    }
+    
+    #[test]
+    fn insufficient_metal() {
+        let stock = Stock {
+            refined: 1,
+            ..Default::default()
+        };
+        
+        assert_eq!(stock.pay(&Currencies {
+            keys: 0,
+            metal: refined!(1) + scrap!(1),
+        }), Err(PaymentError::InsufficientMetal {
+            needed: refined!(1) + scrap!(1),
+            available: refined!(1),
+        }));
+    }
+    
+    #[test]
+    fn negative_value_cannot_be_paid() {
+        assert_eq!(Stock::default().pay(&Currencies {
+            keys: 0,
+            metal: -1,
+        }), Err(PaymentError::NegativeValue));
+    }
+    
+    #[test]
+    fn converts_to_currencies() {
+        let currencies = Currencies {
+            keys: 4,
+            metal: refined!(12) + reclaimed!(1) + 1,
+        };
+        
+        assert_eq!(Currencies::from(currencies.denominate()), currencies);
+    }
+}
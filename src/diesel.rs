@@ -0,0 +1,175 @@
+//! [`diesel`] support for [`Currencies`]. Available with the `diesel` feature.
+//! 
+//! Currencies can be loaded from a pair of `BIGINT` columns for keys and metal (represented as
+//! weapons) and written to them using [`Currencies::to_columns`], or stored in a Postgres `JSONB` 
+//! column using their serde representation.
+//! 
+//! # Examples
+//! ```
+//! use tf2_price::{Currencies, refined};
+//! use diesel::prelude::*;
+//! 
+//! diesel::table! {
+//!     items (id) {
+//!         id -> Integer,
+//!         keys -> BigInt,
+//!         metal -> BigInt,
+//!     }
+//! }
+//! 
+//! #[derive(Queryable)]
+//! struct Item {
+//!     id: i32,
+//!     price: Currencies,
+//! }
+//! 
+//! let mut connection = SqliteConnection::establish(":memory:").unwrap();
+//! 
+//! diesel::sql_query("CREATE TABLE items (id INTEGER PRIMARY KEY, keys BIGINT, metal BIGINT)")
+//!     .execute(&mut connection)
+//!     .unwrap();
+//! 
+//! let price = Currencies { keys: 2, metal: refined!(3) };
+//! 
+//! diesel::insert_into(items::table)
+//!     .values((items::id.eq(1), price.to_columns(items::keys, items::metal)))
+//!     .execute(&mut connection)
+//!     .unwrap();
+//! 
+
+//! // keys and metal are selected as a nested tuple
+//! let item = items::table
+//!     .select((items::id, (items::keys, items::metal)))
+//!     .first::<Item>(&mut connection)
+//!     .unwrap();
+//! 
+//! assert_eq!(item.price, Currencies { keys: 2, metal: refined!(3) });
+//! ```
+
+use crate::types::Currency;
+use crate::Currencies;
+use diesel::deserialize::{self, FromSql, Queryable};
+use diesel::pg::{Pg, PgValue};
+use diesel::serialize::{self, Output, ToSql};
+use diesel::sql_types::{BigInt, Jsonb};
+use diesel::backend::Backend;
+use diesel::dsl;
+use diesel::expression::Expression;
+use diesel::ExpressionMethods;
+
+impl Currencies {
+    /// Assigns the keys and metal (represented as weapons) to a pair of `BIGINT` columns. The 
+    /// result can be used as the values of an insert or the changes of an update.
+    pub fn to_columns<K, M>(
+        &self,
+        keys: K,
+        metal: M,
+    ) -> (dsl::Eq<K, Currency>, dsl::Eq<M, Currency>)
+    where
+        K: Expression<SqlType = BigInt> + ExpressionMethods,
+        M: Expression<SqlType = BigInt> + ExpressionMethods,
+    {
+        (keys.eq(self.keys), metal.eq(self.metal))
+    }
+}
+
+impl<DB> Queryable<(BigInt, BigInt), DB> for Currencies
+where
+    DB: Backend,
+    Currency: FromSql<BigInt, DB>,
+{
+    type Row = (Currency, Currency);
+    
+    fn build((keys, metal): Self::Row) -> deserialize::Result<Self> {
+        Ok(Self {
+            keys,
+            metal,
+        })
+    }
+}
+
+impl Queryable<Jsonb, Pg> for Currencies {
+    type Row = Self;
+    
+    fn build(row: Self::Row) -> deserialize::Result<Self> {
+        Ok(row)
+    }
+}
+
+impl FromSql<Jsonb, Pg> for Currencies {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        let value = <serde_json::Value as FromSql<Jsonb, Pg>>::from_sql(value)?;
+        
+        Ok(serde_json::from_value(value)?)
+    }
+}
+
+impl ToSql<Jsonb, Pg> for Currencies {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        let value = serde_json::to_value(self)?;
+        
+        <serde_json::Value as ToSql<Jsonb, Pg>>::to_sql(&value, &mut out.reborrow())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{refined, scrap};
+    use diesel::{Connection, QueryDsl, RunQueryDsl, IntoSql, SqliteConnection};
+    use diesel::expression::AsExpression;
+    
+    #[test]
+    fn loads_from_integer_columns() {
+        let mut connection = SqliteConnection::establish(":memory:").unwrap();
+        let metal = refined!(3) + scrap!(3) + 1;
+        let currencies = diesel::select((2_i64.into_sql::<BigInt>(), metal.into_sql::<BigInt>()))
+            .get_result::<Currencies>(&mut connection)
+            .unwrap();
+        
+        assert_eq!(currencies, Currencies { keys: 2, metal });
+    }
+    
+    #[test]
+    fn writes_to_integer_columns() {
+        diesel::table! {
+            prices (id) {
+                id -> Integer,
+                keys -> BigInt,
+                metal -> BigInt,
+            }
+        }
+        
+        let mut connection = SqliteConnection::establish(":memory:").unwrap();
+        let currencies = Currencies { keys: -1, metal: refined!(3) + 1 };
+        
+        diesel::sql_query("CREATE TABLE prices (id INTEGER PRIMARY KEY, keys BIGINT, metal BIGINT)")
+            .execute(&mut connection)
+            .unwrap();
+        diesel::insert_into(prices::table)
+            .values((prices::id.eq(1), Currencies::ZERO.to_columns(prices::keys, prices::metal)))
+            .execute(&mut connection)
+            .unwrap();
+        diesel::update(prices::table)
+            .set(currencies.to_columns(prices::keys, prices::metal))
+            .execute(&mut connection)
+            .unwrap();
+        
+        let loaded = prices::table
+            .select((prices::keys, prices::metal))
+            .first::<Currencies>(&mut connection)
+            .unwrap();
+        
+        assert_eq!(loaded, currencies);
+    }
+    
+    #[test]
+    fn converts_to_jsonb_expression() {
+        let currencies = Currencies { keys: 2, metal: refined!(3) };
+        let expression = diesel::debug_query::<Pg, _>(&diesel::select(
+            AsExpression::<Jsonb>::as_expression(currencies),
+        )).to_string();
+        
+        assert!(expression.starts_with("SELECT $1"));
+    }
+}
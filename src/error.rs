@@ -1,18 +1,125 @@
-use std::num::{ParseFloatError, ParseIntError};
+use crate::types::Currency;
+use crate::Currencies;
+use core::num::{ParseFloatError, ParseIntError};
+use core::ops::Range;
+use alloc::string::String;
 
-/// Error converting listing currencies to currencies.
-#[derive(Debug, thiserror::Error)]
-#[error("Currencies contains fractional value: {fract}")]
-pub struct TryFromListingCurrenciesError {
-    /// Fractional key values are invalid.
-    pub fract: f32,
+/// Error converting listing currencies to currencies. Metal is already represented as weapons so 
+/// it is never rounded; only the key value can fail to convert.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum TryFromListingCurrenciesError {
+    /// The key value has a fractional part, e.g. `1.5`.
+    #[error("Currencies contain fractional key value: {keys}")]
+    Fractional {
+        /// The key value.
+        keys: f32,
+    },
+    /// The key value is `NaN` or infinite.
+    #[error("Currencies contain non-finite key value: {keys}")]
+    NonFinite {
+        /// The key value.
+        keys: f32,
+    },
+    /// The key value is a whole number beyond the bounds of [`i64`].
+    #[error("Currencies contain key value out of range: {keys}")]
+    OutOfRange {
+        /// The key value.
+        keys: f32,
+    },
 }
 
-#[derive(Debug, thiserror::Error)]
+/// Error creating an invalid key price.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum InvalidKeyPriceError {
+    /// The buy or sell price is `0` or less.
+    #[error("Key buy price ({buy}) and sell price ({sell}) must be positive")]
+    NotPositive {
+        /// The buy price (represented as weapons).
+        buy: Currency,
+        /// The sell price (represented as weapons).
+        sell: Currency,
+    },
+    /// The sell price is less than the buy price.
+    #[error("Key sell price ({sell}) is less than buy price ({buy})")]
+    SellLessThanBuy {
+        /// The buy price (represented as weapons).
+        buy: Currency,
+        /// The sell price (represented as weapons).
+        sell: Currency,
+    },
+}
+
+/// Error creating a price range where the low price is greater than the high price.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+#[error("Low price ({low}) is greater than high price ({high})")]
+pub struct InvalidPriceRangeError {
+    /// The low price.
+    pub low: Currencies,
+    /// The high price.
+    pub high: Currencies,
+}
+
+/// Error creating pricing where the sell price is worth less than the buy price.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+#[error("Sell price ({sell}) is worth less than buy price ({buy})")]
+pub struct InvalidPricingError {
+    /// The buy price.
+    pub buy: Currencies,
+    /// The sell price.
+    pub sell: Currencies,
+}
+
+/// Error parsing currencies from a string.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
 pub enum ParseError {
-    /// String was invalid.
+    /// The string is not in the expected format, e.g. a value is missing its unit or the unit is 
+    /// not recognized.
+    #[error(r#"Invalid format "{token}" at {}..{}"#, span.start, span.end)]
+    InvalidFormat {
+        /// The text which failed to parse.
+        token: String,
+        /// The byte range of the text in the input.
+        span: Range<usize>,
+    },
+    /// The key count failed to parse.
+    #[error(r#"Invalid key count "{token}" at {}..{}"#, span.start, span.end)]
+    InvalidKeyCount {
+        /// The text which failed to parse.
+        token: String,
+        /// The byte range of the text in the input.
+        span: Range<usize>,
+    },
+    /// The metal value failed to parse.
+    #[error(r#"Invalid metal value "{token}" at {}..{}"#, span.start, span.end)]
+    InvalidMetalValue {
+        /// The text which failed to parse.
+        token: String,
+        /// The byte range of the text in the input.
+        span: Range<usize>,
+    },
+    /// The string did not contain any value.
     #[error("No currencies could be parsed from string")]
-    Invalid,
+    Empty,
+    /// A currency appeared more than once, e.g. "1 key, 2 keys".
+    #[error(r#"Duplicate currency "{currency}" at {}..{}"#, span.start, span.end)]
+    DuplicateCurrency {
+        /// The symbol of the duplicated currency.
+        currency: String,
+        /// The text of the duplicate value.
+        token: String,
+        /// The byte range of the duplicate value in the input.
+        span: Range<usize>,
+    },
+    /// A value was given in a unit which is not allowed by the parse options.
+    #[error(r#"Unit "{unit}" is not allowed at {}..{}"#, span.start, span.end)]
+    UnitNotAllowed {
+        /// The symbol of the unit.
+        unit: String,
+        /// The text of the value.
+        token: String,
+        /// The byte range of the value in the input.
+        span: Range<usize>,
+    },
     /// A value expected to be number failed to parse. 
     #[error(r#"Failed to parse "{}" as numeric"#, .0)]
     ParseNumeric(String),
@@ -22,4 +129,133 @@ pub enum ParseError {
     /// A string failed to parse to a float.
     #[error("{}", .0)]
     ParseFloat(#[from] ParseFloatError),
+}
+
+impl ParseError {
+    /// The byte range in the input of the text which caused the error, if any.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::Currencies;
+    /// 
+    /// let input = "2 keys, 3.x3 ref";
+    /// let error = Currencies::try_from(input).unwrap_err();
+    /// let span = error.span().unwrap();
+    /// 
+    /// assert_eq!(&input[span], "3.x3");
+    /// assert_eq!(error.token(), Some("3.x3"));
+    /// ```
+    pub fn span(&self) -> Option<Range<usize>> {
+        match self {
+            Self::InvalidFormat { span, .. } |
+            Self::InvalidKeyCount { span, .. } |
+            Self::InvalidMetalValue { span, .. } |
+            Self::DuplicateCurrency { span, .. } |
+            Self::UnitNotAllowed { span, .. } => Some(span.clone()),
+            _ => None,
+        }
+    }
+    
+    /// The text in the input which caused the error, if any.
+    pub fn token(&self) -> Option<&str> {
+        match self {
+            Self::InvalidFormat { token, .. } |
+            Self::InvalidKeyCount { token, .. } |
+            Self::InvalidMetalValue { token, .. } |
+            Self::DuplicateCurrency { token, .. } |
+            Self::UnitNotAllowed { token, .. } => Some(token),
+            _ => None,
+        }
+    }
+    
+    /// Shifts the span by `offset` bytes, used when the parsed string is a slice of a larger 
+    /// input.
+    pub(crate) fn offset(mut self, offset: usize) -> Self {
+        match &mut self {
+            Self::InvalidFormat { span, .. } |
+            Self::InvalidKeyCount { span, .. } |
+            Self::InvalidMetalValue { span, .. } |
+            Self::DuplicateCurrency { span, .. } |
+            Self::UnitNotAllowed { span, .. } => {
+                span.start += offset;
+                span.end += offset;
+            },
+            _ => {},
+        }
+        
+        self
+    }
+}
+
+/// Error parsing a cash value from a string.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum ParseUSDError {
+    /// The string did not contain a value.
+    #[error("No value could be parsed from string")]
+    Empty,
+    /// The string is not in the expected format.
+    #[error("Invalid format")]
+    InvalidFormat,
+    /// Thousands separators are not followed by groups of three digits.
+    #[error("Invalid thousands separator")]
+    InvalidThousandsSeparator,
+    /// The value has more decimal places than can be represented, e.g. "$4.999" or "4.5¢".
+    #[error("Too many decimal places")]
+    TooManyDecimalPlaces,
+    /// The value is too large to be represented in cents.
+    #[error("Value is too large")]
+    Overflow,
+}
+
+/// Error parsing a rounding method from a string.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+#[error(r#"Unknown rounding method "{name}""#)]
+pub struct ParseRoundingError {
+    /// The string which could not be parsed.
+    pub name: String,
+}
+
+/// Error converting currencies to or from a BSON document.
+#[cfg(feature = "bson")]
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum BsonError {
+    /// The field is not a numeric type which can hold the value.
+    #[error(r#"Field "{field}" has an invalid type"#)]
+    InvalidType {
+        /// The name of the field.
+        field: &'static str,
+    },
+    /// The value of the field does not fit into its type.
+    #[error(r#"Field "{field}" is out of range"#)]
+    OutOfRange {
+        /// The name of the field.
+        field: &'static str,
+    },
+    /// The document does not contain values for keys or metal.
+    #[error("Does not contain values for keys or metal")]
+    Empty,
+}
+
+/// Error paying a price using a limited stock of items.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum PaymentError {
+    /// The stock does not contain enough keys.
+    #[error("Not enough keys: needed {needed}, have {available}")]
+    InsufficientKeys {
+        /// The number of keys needed.
+        needed: Currency,
+        /// The number of keys in stock.
+        available: Currency,
+    },
+    /// The stock does not contain enough metal.
+    #[error("Not enough metal: needed {needed} weapons, have {available} weapons")]
+    InsufficientMetal {
+        /// The metal value needed (represented as weapons).
+        needed: Currency,
+        /// The metal value in stock (represented as weapons).
+        available: Currency,
+    },
+    /// Negative prices cannot be paid.
+    #[error("Price contains a negative value")]
+    NegativeValue,
 }
\ No newline at end of file
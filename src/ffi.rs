@@ -0,0 +1,245 @@
+//! C bindings for [`Currencies`]. Available with the `ffi` feature.
+//! 
+//! [`CCurrencies`] is a plain-old-data struct with the same fields as [`Currencies`]. Like
+//! [`Currencies`], `metal` is represented as weapons. All functions are prefixed with
+//! `tf2_price_`. Arithmetic is saturating and functions which can fail return `false` without
+//! writing to their output.
+//! 
+//! To link against the crate, build it as a C library, e.g.
+//! `cargo rustc --release --features ffi --crate-type cdylib` (or `staticlib`).
+//! 
+//! ```c
+//! typedef struct {
+//!     int64_t keys;
+//!     int64_t metal;
+//! } CCurrencies;
+//! 
+//! CCurrencies currencies;
+//! char buffer[64];
+//! 
+//! if (tf2_price_parse("2 keys, 3.33 ref", &currencies)) {
+//!     tf2_price_format(currencies, buffer, sizeof(buffer));
+//! }
+//! ```
+
+use crate::types::Currency;
+use crate::{get_metal_float, get_metal_from_float, Currencies};
+use std::ffi::{c_char, CStr};
+use std::cmp::Ordering;
+use std::ptr;
+
+/// A C representation of [`Currencies`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CCurrencies {
+    /// Amount of keys.
+    pub keys: Currency,
+    /// Amount of metal expressed as weapons.
+    pub metal: Currency,
+}
+
+impl From<Currencies> for CCurrencies {
+    fn from(currencies: Currencies) -> Self {
+        Self {
+            keys: currencies.keys,
+            metal: currencies.metal,
+        }
+    }
+}
+
+impl From<CCurrencies> for Currencies {
+    fn from(currencies: CCurrencies) -> Self {
+        Self {
+            keys: currencies.keys,
+            metal: currencies.metal,
+        }
+    }
+}
+
+/// Parses currencies from a NUL-terminated UTF-8 string, e.g. "2 keys, 3.33 ref", into `out`.
+/// Returns `false` if either pointer is null or the string could not be parsed.
+/// 
+/// # Safety
+/// `string` must be null or point to a NUL-terminated string. `out` must be null or valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn tf2_price_parse(string: *const c_char, out: *mut CCurrencies) -> bool {
+    if string.is_null() || out.is_null() {
+        return false;
+    }
+    
+    let Ok(string) = CStr::from_ptr(string).to_str() else {
+        return false;
+    };
+    let Ok(currencies) = string.parse::<Currencies>() else {
+        return false;
+    };
+    
+    ptr::write(out, currencies.into());
+    true
+}
+
+/// Formats currencies into `buffer` as a NUL-terminated string, e.g. "2 keys, 3.33 ref". The
+/// output is truncated to fit `len` bytes including the terminator. Returns the length of the
+/// full string excluding the terminator, so a return value of `len` or greater means the output
+/// was truncated. Nothing is written if `buffer` is null or `len` is `0`.
+/// 
+/// # Safety
+/// `buffer` must be null or valid for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn tf2_price_format(
+    currencies: CCurrencies,
+    buffer: *mut c_char,
+    len: usize,
+) -> usize {
+    let string = Currencies::from(currencies).to_string();
+    
+    if !buffer.is_null() && len > 0 {
+        let count = string.len().min(len - 1);
+        
+        ptr::copy_nonoverlapping(string.as_ptr(), buffer.cast::<u8>(), count);
+        *buffer.add(count) = 0;
+    }
+    
+    string.len()
+}
+
+/// Converts currencies to a metal value using the given key price (represented as weapons).
+/// This function is saturating.
+#[no_mangle]
+pub extern "C" fn tf2_price_to_metal(currencies: CCurrencies, key_price: Currency) -> Currency {
+    Currencies::from(currencies).to_metal(key_price)
+}
+
+/// Converts a metal value into keys and metal using the given key price (represented as weapons)
+/// into `out`. Returns `false` if `out` is null or `key_price` is `0`.
+/// 
+/// # Safety
+/// `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn tf2_price_from_metal(
+    metal: Currency,
+    key_price: Currency,
+    out: *mut CCurrencies,
+) -> bool {
+    if out.is_null() {
+        return false;
+    }
+    
+    let Some(currencies) = Currencies::checked_from_metal(metal, key_price) else {
+        return false;
+    };
+    
+    ptr::write(out, currencies.into());
+    true
+}
+
+/// Adds currencies. This function is saturating.
+#[no_mangle]
+pub extern "C" fn tf2_price_add(a: CCurrencies, b: CCurrencies) -> CCurrencies {
+    (Currencies::from(a) + Currencies::from(b)).into()
+}
+
+/// Subtracts currencies. This function is saturating.
+#[no_mangle]
+pub extern "C" fn tf2_price_sub(a: CCurrencies, b: CCurrencies) -> CCurrencies {
+    (Currencies::from(a) - Currencies::from(b)).into()
+}
+
+/// Compares currencies by their total value using the given key price (represented as weapons).
+/// Returns `-1`, `0` or `1`.
+#[no_mangle]
+pub extern "C" fn tf2_price_compare(a: CCurrencies, b: CCurrencies, key_price: Currency) -> i32 {
+    match Currencies::from(a).cmp_with_key_price(&b.into(), key_price) {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    }
+}
+
+/// Converts a metal value into refined, truncated to 2 decimal places, e.g. `1.33`.
+#[no_mangle]
+pub extern "C" fn tf2_price_get_metal_float(metal: Currency) -> f32 {
+    get_metal_float(metal)
+}
+
+/// Converts a refined value, e.g. `1.33`, into a metal value.
+#[no_mangle]
+pub extern "C" fn tf2_price_get_metal_from_float(value: f32) -> Currency {
+    get_metal_from_float(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{refined, scrap};
+    use std::ffi::CString;
+    
+    #[test]
+    fn parses_currencies() {
+        let string = CString::new("2 keys, 3.33 ref").unwrap();
+        let mut currencies = CCurrencies::default();
+        
+        assert!(unsafe { tf2_price_parse(string.as_ptr(), &mut currencies) });
+        assert_eq!(currencies, CCurrencies { keys: 2, metal: refined!(3) + scrap!(3) });
+    }
+    
+    #[test]
+    fn rejects_invalid_input() {
+        let string = CString::new("2 kittens").unwrap();
+        let mut currencies = CCurrencies::default();
+        
+        assert!(!unsafe { tf2_price_parse(string.as_ptr(), &mut currencies) });
+        assert!(!unsafe { tf2_price_parse(ptr::null(), &mut currencies) });
+        assert!(!unsafe { tf2_price_parse(string.as_ptr(), ptr::null_mut()) });
+        assert!(!unsafe { tf2_price_from_metal(refined!(10), 0, &mut currencies) });
+        assert_eq!(currencies, CCurrencies::default());
+    }
+    
+    #[test]
+    fn formats_currencies() {
+        let currencies = CCurrencies { keys: 2, metal: refined!(3) + scrap!(3) };
+        let mut buffer = [0 as c_char; 32];
+        let len = unsafe { tf2_price_format(currencies, buffer.as_mut_ptr(), buffer.len()) };
+        let string = unsafe { CStr::from_ptr(buffer.as_ptr()) };
+        
+        assert_eq!(len, 16);
+        assert_eq!(string.to_str().unwrap(), "2 keys, 3.33 ref");
+    }
+    
+    #[test]
+    fn truncates_formatted_currencies() {
+        let currencies = CCurrencies { keys: 2, metal: refined!(3) + scrap!(3) };
+        let mut buffer = [0 as c_char; 7];
+        let len = unsafe { tf2_price_format(currencies, buffer.as_mut_ptr(), buffer.len()) };
+        let string = unsafe { CStr::from_ptr(buffer.as_ptr()) };
+        
+        assert_eq!(len, 16);
+        assert_eq!(string.to_str().unwrap(), "2 keys");
+        assert_eq!(unsafe { tf2_price_format(currencies, ptr::null_mut(), 0) }, 16);
+    }
+    
+    #[test]
+    fn converts_metal() {
+        let key_price = refined!(50);
+        let currencies = CCurrencies { keys: 1, metal: refined!(10) };
+        let mut out = CCurrencies::default();
+        
+        assert_eq!(tf2_price_to_metal(currencies, key_price), refined!(60));
+        assert!(unsafe { tf2_price_from_metal(refined!(60), key_price, &mut out) });
+        assert_eq!(out, currencies);
+        assert_eq!(tf2_price_get_metal_float(scrap!(3)), 0.33);
+        assert_eq!(tf2_price_get_metal_from_float(0.33), scrap!(3));
+    }
+    
+    #[test]
+    fn performs_arithmetic() {
+        let a = CCurrencies { keys: 2, metal: refined!(3) };
+        let b = CCurrencies { keys: 1, metal: refined!(1) };
+        
+        assert_eq!(tf2_price_add(a, b), CCurrencies { keys: 3, metal: refined!(4) });
+        assert_eq!(tf2_price_sub(a, b), CCurrencies { keys: 1, metal: refined!(2) });
+        assert_eq!(tf2_price_compare(a, b, refined!(50)), 1);
+        assert_eq!(tf2_price_compare(b, a, refined!(50)), -1);
+    }
+}
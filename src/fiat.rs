@@ -0,0 +1,250 @@
+use crate::helpers;
+use crate::types::Currency;
+use crate::USDCurrencies;
+#[cfg(not(feature = "std"))]
+use crate::float::Float;
+use core::fmt;
+use serde::{Serialize, Deserialize};
+use alloc::string::ToString;
+
+/// Common ISO 4217 currency codes.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[serde(rename_all = "UPPERCASE")]
+pub enum FiatCurrency {
+    /// United States dollar.
+    USD,
+    /// Euro.
+    EUR,
+    /// Pound sterling.
+    GBP,
+    /// Canadian dollar.
+    CAD,
+    /// Australian dollar.
+    AUD,
+    /// Russian ruble.
+    RUB,
+    /// Brazilian real.
+    BRL,
+    /// Polish złoty.
+    PLN,
+    /// Chinese yuan.
+    CNY,
+    /// Japanese yen.
+    JPY,
+    /// South Korean won.
+    KRW,
+}
+
+impl FiatCurrency {
+    /// The ISO 4217 code for the currency e.g. "USD".
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::USD => "USD",
+            Self::EUR => "EUR",
+            Self::GBP => "GBP",
+            Self::CAD => "CAD",
+            Self::AUD => "AUD",
+            Self::RUB => "RUB",
+            Self::BRL => "BRL",
+            Self::PLN => "PLN",
+            Self::CNY => "CNY",
+            Self::JPY => "JPY",
+            Self::KRW => "KRW",
+        }
+    }
+    
+    /// The number of decimal places used for minor units e.g. `2` for cents.
+    pub fn decimal_places(&self) -> u32 {
+        match self {
+            Self::JPY | Self::KRW => 0,
+            _ => 2,
+        }
+    }
+    
+    /// The number of minor units in one major unit e.g. `100` cents in a dollar.
+    fn minor_units_per_unit(&self) -> Currency {
+        10_i64.pow(self.decimal_places())
+    }
+}
+
+impl fmt::Display for FiatCurrency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+/// For storing cash values in any [`FiatCurrency`]. Values are stored as an integer number of 
+/// minor units (e.g. cents) so arithmetic is exact.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Fiat, FiatCurrency};
+/// 
+/// let usd = Fiat::new(FiatCurrency::USD, 250);
+/// // 1 USD is 0.92 EUR
+/// let eur = usd.convert(FiatCurrency::EUR, 0.92);
+/// 
+/// assert_eq!(eur, Fiat::new(FiatCurrency::EUR, 230));
+/// assert_eq!(eur.to_string(), "2.30 EUR");
+/// ```
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct Fiat {
+    /// The currency.
+    pub currency: FiatCurrency,
+    /// The value in minor units of the currency (e.g. cents).
+    #[cfg_attr(feature = "ts-rs", ts(type = "number"))]
+    pub minor_units: Currency,
+}
+
+impl Fiat {
+    /// Creates a new [`Fiat`].
+    pub fn new(currency: FiatCurrency, minor_units: Currency) -> Self {
+        Self {
+            currency,
+            minor_units,
+        }
+    }
+    
+    /// Converts the value to another currency using `rate`, the value of one unit of this 
+    /// currency in the other currency. The result is rounded to the nearest minor unit and 
+    /// saturates at integer bounds.
+    pub fn convert(&self, currency: FiatCurrency, rate: f64) -> Self {
+        let units = self.minor_units as f64 / self.currency.minor_units_per_unit() as f64;
+        let minor_units = (units * rate * currency.minor_units_per_unit() as f64).round();
+        
+        Self {
+            currency,
+            minor_units: minor_units as Currency,
+        }
+    }
+    
+    /// Checks if the value is `0`.
+    pub fn is_empty(&self) -> bool {
+        self.minor_units == 0
+    }
+    
+    /// Adds values. `None` if the currencies differ or the result overflows integer bounds.
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        if self.currency != other.currency {
+            return None;
+        }
+        
+        Some(Self::new(self.currency, self.minor_units.checked_add(other.minor_units)?))
+    }
+    
+    /// Subtracts values. `None` if the currencies differ or the result overflows integer bounds.
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        if self.currency != other.currency {
+            return None;
+        }
+        
+        Some(Self::new(self.currency, self.minor_units.checked_sub(other.minor_units)?))
+    }
+    
+    /// Checked integer multiplication. `None` if the result overflows integer bounds.
+    pub fn checked_mul(&self, rhs: Currency) -> Option<Self> {
+        Some(Self::new(self.currency, self.minor_units.checked_mul(rhs)?))
+    }
+    
+    /// Checked integer division. `None` if `rhs == 0` or the division results in overflow.
+    pub fn checked_div(&self, rhs: Currency) -> Option<Self> {
+        Some(Self::new(self.currency, self.minor_units.checked_div(rhs)?))
+    }
+}
+
+impl From<USDCurrencies> for Fiat {
+    fn from(currencies: USDCurrencies) -> Self {
+        Self::new(FiatCurrency::USD, currencies.usd)
+    }
+}
+
+impl From<&USDCurrencies> for Fiat {
+    fn from(currencies: &USDCurrencies) -> Self {
+        Self::new(FiatCurrency::USD, currencies.usd)
+    }
+}
+
+impl fmt::Display for Fiat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sign = if self.minor_units < 0 { "-" } else { "" };
+        let minor_units = self.minor_units.unsigned_abs();
+        let per_unit = self.currency.minor_units_per_unit() as u64;
+        let units = helpers::thousands((minor_units / per_unit).to_string());
+        
+        if per_unit == 1 {
+            write!(f, "{sign}{units} {}", self.currency)
+        } else {
+            let width = self.currency.decimal_places() as usize;
+            
+            write!(f, "{sign}{units}.{:0width$} {}", minor_units % per_unit, self.currency)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{self, json, Value};
+    use assert_json_diff::assert_json_eq;
+    
+    #[test]
+    fn converts_between_currencies() {
+        assert_eq!(Fiat::new(FiatCurrency::EUR, 1000).convert(FiatCurrency::USD, 1.085), Fiat {
+            currency: FiatCurrency::USD,
+            minor_units: 1085,
+        });
+    }
+    
+    #[test]
+    fn converts_to_currency_without_minor_units() {
+        assert_eq!(Fiat::new(FiatCurrency::USD, 199).convert(FiatCurrency::JPY, 150.0), Fiat {
+            currency: FiatCurrency::JPY,
+            minor_units: 299,
+        });
+    }
+    
+    #[test]
+    fn adds_same_currency() {
+        assert_eq!(
+            Fiat::new(FiatCurrency::EUR, 100).checked_add(&Fiat::new(FiatCurrency::EUR, 50)),
+            Some(Fiat::new(FiatCurrency::EUR, 150)),
+        );
+    }
+    
+    #[test]
+    fn does_not_add_different_currencies() {
+        assert_eq!(
+            Fiat::new(FiatCurrency::EUR, 100).checked_add(&Fiat::new(FiatCurrency::USD, 50)),
+            None,
+        );
+    }
+    
+    #[test]
+    fn formats_fiat() {
+        assert_eq!(Fiat::new(FiatCurrency::GBP, -123456).to_string(), "-1,234.56 GBP");
+        assert_eq!(Fiat::new(FiatCurrency::JPY, 5000).to_string(), "5,000 JPY");
+    }
+    
+    #[test]
+    fn converts_from_usd_currencies() {
+        assert_eq!(Fiat::from(USDCurrencies { usd: 499 }), Fiat::new(FiatCurrency::USD, 499));
+    }
+    
+    #[test]
+    fn correct_json_format() {
+        let actual: Value = serde_json::to_value(Fiat::new(FiatCurrency::EUR, 499)).unwrap();
+        let expected: Value = json!({
+            "currency": "EUR",
+            "minor_units": 499
+        });
+        
+        assert_json_eq!(
+            actual,
+            expected,
+        );
+    }
+}
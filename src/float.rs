@@ -0,0 +1,83 @@
+//! Floating point functions for `no_std` builds.
+//! 
+//! The rounding and square root functions on [`f32`] and [`f64`] are only available with `std`. Without it, the
+//! same methods are provided by this trait using `libm`. Import it only when `std` is disabled so
+//! that the inherent methods are used otherwise.
+
+/// Rounding functions for floats without `std`.
+pub(crate) trait Float {
+    /// Rounds half-way cases away from zero.
+    fn round(self) -> Self;
+    /// Returns the integer part.
+    fn trunc(self) -> Self;
+    /// Rounds towards negative infinity.
+    fn floor(self) -> Self;
+    /// Rounds towards positive infinity.
+    fn ceil(self) -> Self;
+    /// Returns the fractional part.
+    fn fract(self) -> Self;
+    /// Rounds half-way cases to the nearest even integer.
+    fn round_ties_even(self) -> Self;
+    /// Returns the square root.
+    fn sqrt(self) -> Self;
+}
+
+impl Float for f32 {
+    fn round(self) -> Self {
+        libm::roundf(self)
+    }
+    
+    fn trunc(self) -> Self {
+        libm::truncf(self)
+    }
+    
+    fn floor(self) -> Self {
+        libm::floorf(self)
+    }
+    
+    fn ceil(self) -> Self {
+        libm::ceilf(self)
+    }
+    
+    fn fract(self) -> Self {
+        self - libm::truncf(self)
+    }
+    
+    fn round_ties_even(self) -> Self {
+        libm::rintf(self)
+    }
+    
+    fn sqrt(self) -> Self {
+        libm::sqrtf(self)
+    }
+}
+
+impl Float for f64 {
+    fn round(self) -> Self {
+        libm::round(self)
+    }
+    
+    fn trunc(self) -> Self {
+        libm::trunc(self)
+    }
+    
+    fn floor(self) -> Self {
+        libm::floor(self)
+    }
+    
+    fn ceil(self) -> Self {
+        libm::ceil(self)
+    }
+    
+    fn fract(self) -> Self {
+        self - libm::trunc(self)
+    }
+    
+    fn round_ties_even(self) -> Self {
+        libm::rint(self)
+    }
+    
+    fn sqrt(self) -> Self {
+        libm::sqrt(self)
+    }
+}
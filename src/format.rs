@@ -0,0 +1,630 @@
+use crate::types::Currency;
+use crate::constants::{KEYS_SYMBOL, KEY_SYMBOL, METAL_SYMBOL, REFINED_SYMBOL, EMPTY_SYMBOL, ONE_REF};
+use crate::Currencies;
+use core::fmt;
+use alloc::string::ToString;
+
+/// Symbol for keys in compact formatting.
+pub(crate) const KEY_COMPACT_SYMBOL: &str = "k";
+/// Symbol for metal in compact formatting.
+pub(crate) const METAL_COMPACT_SYMBOL: &str = "r";
+
+/// The label written after metal values.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum UnitLabel {
+    /// "ref", e.g. "3.33 ref".
+    #[default]
+    Short,
+    /// "refined", e.g. "3.33 refined".
+    Long,
+    /// No label, e.g. "3.33".
+    None,
+}
+
+/// How the signs of negative values are written.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum SignStyle {
+    /// A sign on each negative component, e.g. "-2 keys, -3.33 ref". This is the only style 
+    /// which is parsed back into the same currencies.
+    #[default]
+    PerComponent,
+    /// A single leading sign, e.g. "-2 keys, 3.33 ref".
+    Leading,
+    /// Parentheses around the value, e.g. "(2 keys, 3.33 ref)".
+    Accounting,
+}
+
+/// Options for formatting currencies. The default options create the same string as
+/// [`Display`](std::fmt::Display).
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, FormatOptions, UnitLabel, refined, scrap};
+/// 
+/// let currencies = Currencies { keys: 1, metal: refined!(3) + scrap!(3) };
+/// let options = FormatOptions::new()
+///     .unit_label(UnitLabel::Long)
+///     .pluralize_keys(false)
+///     .separator(" + ");
+/// 
+/// assert_eq!(currencies.display_with(&options).to_string(), "1 keys + 3.33 refined");
+/// 
+/// let options = FormatOptions::new()
+///     .show_zero(true)
+///     .decimal_places(Some(2));
+/// 
+/// let currencies = Currencies { keys: 2, metal: 0 };
+/// 
+/// assert_eq!(currencies.display_with(&options).to_string(), "2 keys, 0.00 ref");
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct FormatOptions {
+    show_zero: bool,
+    unit_label: UnitLabel,
+    pluralize_keys: bool,
+    decimal_places: Option<u8>,
+    separator: &'static str,
+    thousands_separator: Option<&'static str>,
+    sign_style: SignStyle,
+    compact: bool,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            show_zero: false,
+            unit_label: UnitLabel::default(),
+            pluralize_keys: true,
+            decimal_places: None,
+            separator: ", ",
+            thousands_separator: None,
+            sign_style: SignStyle::default(),
+            compact: false,
+        }
+    }
+}
+
+impl FormatOptions {
+    /// Creates the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Sets whether zero values are shown, e.g. "0 keys, 3 ref" rather than "3 ref". When not
+    /// shown, currencies with no value are written as "nothing".
+    pub fn show_zero(mut self, show_zero: bool) -> Self {
+        self.show_zero = show_zero;
+        self
+    }
+    
+    /// Sets the label written after metal values.
+    pub fn unit_label(mut self, unit_label: UnitLabel) -> Self {
+        self.unit_label = unit_label;
+        self
+    }
+    
+    /// Sets whether "key" is written for a single key. When disabled "keys" is always written.
+    pub fn pluralize_keys(mut self, pluralize_keys: bool) -> Self {
+        self.pluralize_keys = pluralize_keys;
+        self
+    }
+    
+    /// Sets the number of decimal places metal values are written with, up to 2. Metal values
+    /// are truncated, as with [`Display`](std::fmt::Display). When `None`, trailing zeros are
+    /// removed, e.g. "3.5 ref" or "3 ref".
+    pub fn decimal_places(mut self, decimal_places: Option<u8>) -> Self {
+        self.decimal_places = decimal_places.map(|decimal_places| decimal_places.min(2));
+        self
+    }
+    
+    /// Sets the separator written between keys and metal.
+    pub fn separator(mut self, separator: &'static str) -> Self {
+        self.separator = separator;
+        self
+    }
+    
+    /// Sets the separator written between groups of thousands in keys and whole metal values, 
+    /// e.g. "12,500 keys" with `Some(",")`. When `None`, digits are not grouped.
+    pub fn thousands_separator(mut self, thousands_separator: Option<&'static str>) -> Self {
+        self.thousands_separator = thousands_separator;
+        self
+    }
+    
+    /// Sets how the signs of negative values are written. [`SignStyle::Leading`] and 
+    /// [`SignStyle::Accounting`] only apply when no component is positive, otherwise each 
+    /// component is written with its own sign.
+    pub fn sign_style(mut self, sign_style: SignStyle) -> Self {
+        self.sign_style = sign_style;
+        self
+    }
+    
+    /// Sets whether units are abbreviated and written without a space, e.g. "2k, 3.33r". Key 
+    /// pluralization and the unit label are ignored when enabled. This is the format used by the 
+    /// alternate flag for [`Display`](std::fmt::Display), e.g. `format!("{:#}", currencies)`.
+    pub fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+}
+
+/// Formats [`Currencies`] using [`FormatOptions`]. Created using [`Currencies::display_with`].
+#[derive(Debug, Clone, Copy)]
+pub struct CurrenciesDisplay<'a> {
+    currencies: &'a Currencies,
+    options: FormatOptions,
+}
+
+impl<'a> CurrenciesDisplay<'a> {
+    pub(crate) fn new(currencies: &'a Currencies, options: &FormatOptions) -> Self {
+        Self {
+            currencies,
+            options: *options,
+        }
+    }
+    
+    /// Whether the sign is written once for the whole value rather than for each component.
+    fn is_wholly_negative(&self) -> bool {
+        let Currencies { keys, metal } = *self.currencies;
+        
+        self.options.sign_style != SignStyle::PerComponent &&
+        keys <= 0 &&
+        metal <= 0 &&
+        (keys < 0 || metal < 0)
+    }
+    
+    fn write_keys(&self, f: &mut fmt::Formatter, signed: bool) -> fmt::Result {
+        let keys = self.currencies.keys;
+        let sign = if signed && keys < 0 { "-" } else { "" };
+        let count = Grouped::new(keys.unsigned_abs() as u128, self.options.thousands_separator);
+        
+        if self.options.compact {
+            return write!(f, "{sign}{count}{KEY_COMPACT_SYMBOL}");
+        }
+        
+        // When the sign is written separately, the count shown is the absolute value.
+        let is_one = if signed { keys == 1 } else { keys.unsigned_abs() == 1 };
+        let symbol = if is_one && self.options.pluralize_keys {
+            KEY_SYMBOL
+        } else {
+            KEYS_SYMBOL
+        };
+        
+        write!(f, "{sign}{count} {symbol}")
+    }
+    
+    fn write_metal(&self, f: &mut fmt::Formatter, signed: bool) -> fmt::Result {
+        let metal = self.currencies.metal as i128;
+        
+        write_metal(
+            f,
+            if signed { metal } else { metal.abs() },
+            self.options.decimal_places,
+            self.options.thousands_separator,
+        )?;
+        
+        if self.options.compact {
+            return write!(f, "{METAL_COMPACT_SYMBOL}");
+        }
+        
+        match self.options.unit_label {
+            UnitLabel::Short => write!(f, " {METAL_SYMBOL}"),
+            UnitLabel::Long => write!(f, " {REFINED_SYMBOL}"),
+            UnitLabel::None => Ok(()),
+        }
+    }
+}
+
+impl<'a> fmt::Display for CurrenciesDisplay<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        pad(f, |f| self.write(f))
+    }
+}
+
+impl<'a> CurrenciesDisplay<'a> {
+    fn write(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let show_keys = self.currencies.keys != 0 || self.options.show_zero;
+        let show_metal = self.currencies.metal != 0 || self.options.show_zero;
+        let wholly_negative = self.is_wholly_negative();
+        let signed = !wholly_negative;
+        
+        if !show_keys && !show_metal {
+            return write!(f, "{EMPTY_SYMBOL}");
+        }
+        
+        if wholly_negative {
+            match self.options.sign_style {
+                SignStyle::Accounting => write!(f, "(")?,
+                _ => write!(f, "-")?,
+            }
+        }
+        
+        if show_keys && show_metal {
+            self.write_keys(f, signed)?;
+            write!(f, "{}", self.options.separator)?;
+            self.write_metal(f, signed)?;
+        } else if show_keys {
+            self.write_keys(f, signed)?;
+        } else {
+            self.write_metal(f, signed)?;
+        }
+        
+        if wholly_negative && self.options.sign_style == SignStyle::Accounting {
+            write!(f, ")")?;
+        }
+        
+        Ok(())
+    }
+}
+
+/// Formats [`Currencies`] as a number of keys. Created using [`Currencies::display_as_keys`].
+#[derive(Debug, Clone, Copy)]
+pub struct KeysDisplay<'a> {
+    currencies: &'a Currencies,
+    key_price: Currency,
+    precision: u8,
+}
+
+impl<'a> KeysDisplay<'a> {
+    /// The maximum number of decimal places.
+    const MAX_PRECISION: u8 = 18;
+    
+    pub(crate) fn new(currencies: &'a Currencies, key_price: Currency, precision: u8) -> Self {
+        assert!(key_price > 0, "key price must be greater than 0");
+        
+        Self {
+            currencies,
+            key_price,
+            precision: precision.min(Self::MAX_PRECISION),
+        }
+    }
+}
+
+impl<'a> fmt::Display for KeysDisplay<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        pad(f, |f| self.write(f))
+    }
+}
+
+impl<'a> KeysDisplay<'a> {
+    fn write(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // i128 holds the total value of any currencies exactly
+        let key_price = self.key_price as i128;
+        let total = self.currencies.keys as i128 * key_price + self.currencies.metal as i128;
+        let negative = total < 0;
+        let total = total.unsigned_abs();
+        let key_price = key_price.unsigned_abs();
+        let scale = 10u128.pow(self.precision as u32);
+        let mut whole = total / key_price;
+        // the remainder is less than the key price so this cannot overflow
+        let mut fract = ((total % key_price) * scale * 2 + key_price) / (key_price * 2);
+        
+        // rounding carried into the whole number
+        if fract == scale {
+            whole += 1;
+            fract = 0;
+        }
+        
+        // values which round to zero are written without a sign
+        let sign = if negative && (whole != 0 || fract != 0) { "-" } else { "" };
+        let symbol = if whole == 1 && fract == 0 && self.precision == 0 {
+            KEY_SYMBOL
+        } else {
+            KEYS_SYMBOL
+        };
+        
+        if self.precision == 0 {
+            write!(f, "{sign}{whole} {symbol}")
+        } else {
+            let precision = self.precision as usize;
+            
+            write!(f, "{sign}{whole}.{fract:0precision$} {symbol}")
+        }
+    }
+}
+
+/// Writes a value using the given function, padded to the width of the formatter using its fill
+/// and alignment, e.g. `format!("{:>20}", currencies)`. Values are aligned left by default.
+pub(crate) fn pad<F>(f: &mut fmt::Formatter, write: F) -> fmt::Result
+where
+    F: Fn(&mut fmt::Formatter) -> fmt::Result,
+{
+    struct Unpadded<F>(F);
+    
+    impl<F> fmt::Display for Unpadded<F>
+    where
+        F: Fn(&mut fmt::Formatter) -> fmt::Result,
+    {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            (self.0)(f)
+        }
+    }
+    
+    let Some(width) = f.width() else {
+        return write(f);
+    };
+    let string = Unpadded(write).to_string();
+    let padding = width.saturating_sub(string.chars().count());
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Left) | None => (0, padding),
+    };
+    let fill = f.fill();
+    
+    for _ in 0..before {
+        write!(f, "{fill}")?;
+    }
+    
+    f.write_str(&string)?;
+    
+    for _ in 0..after {
+        write!(f, "{fill}")?;
+    }
+    
+    Ok(())
+}
+
+/// Writes an unsigned integer with its digits grouped into thousands.
+struct Grouped {
+    value: u128,
+    separator: Option<&'static str>,
+}
+
+impl Grouped {
+    fn new(value: u128, separator: Option<&'static str>) -> Self {
+        Self {
+            value,
+            separator,
+        }
+    }
+}
+
+impl fmt::Display for Grouped {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Some(separator) = self.separator else {
+            return write!(f, "{}", self.value);
+        };
+        // u128::MAX has 39 digits
+        let mut digits = [0u8; 39];
+        let mut len = 0;
+        let mut value = self.value;
+        
+        loop {
+            digits[len] = b'0' + (value % 10) as u8;
+            len += 1;
+            value /= 10;
+            
+            if value == 0 {
+                break;
+            }
+        }
+        
+        for i in (0..len).rev() {
+            write!(f, "{}", digits[i] as char)?;
+            
+            if i != 0 && i % 3 == 0 {
+                write!(f, "{separator}")?;
+            }
+        }
+        
+        Ok(())
+    }
+}
+
+/// Writes a metal value as refined. The value is truncated to 2 decimal places using integer
+/// arithmetic so that large values do not lose precision.
+fn write_metal(
+    f: &mut fmt::Formatter,
+    metal: i128,
+    decimal_places: Option<u8>,
+    thousands_separator: Option<&'static str>,
+) -> fmt::Result {
+    let hundredths = metal * 100 / ONE_REF as i128;
+    let sign = if hundredths < 0 { "-" } else { "" };
+    let hundredths = hundredths.unsigned_abs();
+    let whole = Grouped::new(hundredths / 100, thousands_separator);
+    let fract = hundredths % 100;
+    
+    match decimal_places {
+        Some(0) => write!(f, "{sign}{whole}"),
+        Some(1) => write!(f, "{sign}{whole}.{}", fract / 10),
+        Some(_) => write!(f, "{sign}{whole}.{fract:02}"),
+        None if fract == 0 => write!(f, "{sign}{whole}"),
+        None if fract.is_multiple_of(10) => write!(f, "{sign}{whole}.{}", fract / 10),
+        None => write!(f, "{sign}{whole}.{fract:02}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{refined, scrap};
+    
+    fn format(currencies: Currencies, options: FormatOptions) -> String {
+        currencies.display_with(&options).to_string()
+    }
+    
+    #[test]
+    fn formats_default() {
+        for (currencies, expected) in [
+            (Currencies { keys: 2, metal: refined!(23) + scrap!(4) }, "2 keys, 23.44 ref"),
+            (Currencies { keys: 1, metal: 0 }, "1 key"),
+            (Currencies { keys: 0, metal: refined!(3) + scrap!(1) }, "3.11 ref"),
+            (Currencies { keys: 0, metal: refined!(3) + scrap!(9) }, "4 ref"),
+            (Currencies { keys: 0, metal: refined!(3) + scrap!(4) + 1 }, "3.5 ref"),
+            (Currencies { keys: -2, metal: -(refined!(3) + scrap!(3)) }, "-2 keys, -3.33 ref"),
+            (Currencies { keys: 0, metal: -1 }, "-0.05 ref"),
+            (Currencies { keys: 0, metal: refined!(1_000_000) + scrap!(2) }, "1000000.22 ref"),
+            (Currencies::default(), "nothing"),
+        ] {
+            assert_eq!(format(currencies, FormatOptions::new()), expected);
+        }
+    }
+    
+    #[test]
+    fn formats_zero_components() {
+        let options = FormatOptions::new().show_zero(true);
+        
+        assert_eq!(format(Currencies { keys: 0, metal: refined!(3) }, options), "0 keys, 3 ref");
+        assert_eq!(format(Currencies::default(), options), "0 keys, 0 ref");
+        assert_eq!(format(Currencies::default(), FormatOptions::new()), "nothing");
+    }
+    
+    #[test]
+    fn formats_unit_labels() {
+        let currencies = Currencies { keys: 2, metal: refined!(3) + scrap!(3) };
+        
+        assert_eq!(
+            format(currencies, FormatOptions::new().unit_label(UnitLabel::Long)),
+            "2 keys, 3.33 refined",
+        );
+        assert_eq!(
+            format(currencies, FormatOptions::new().unit_label(UnitLabel::None)),
+            "2 keys, 3.33",
+        );
+    }
+    
+    #[test]
+    fn formats_without_pluralizing_keys() {
+        let options = FormatOptions::new().pluralize_keys(false);
+        
+        assert_eq!(format(Currencies { keys: 1, metal: 0 }, options), "1 keys");
+    }
+    
+    #[test]
+    fn formats_decimal_places() {
+        let currencies = Currencies { keys: 0, metal: refined!(3) + scrap!(8) };
+        let with_places = |decimal_places| {
+            format(currencies, FormatOptions::new().decimal_places(decimal_places))
+        };
+        
+        assert_eq!(with_places(None), "3.88 ref");
+        assert_eq!(with_places(Some(0)), "3 ref");
+        assert_eq!(with_places(Some(1)), "3.8 ref");
+        assert_eq!(with_places(Some(2)), "3.88 ref");
+        assert_eq!(with_places(Some(5)), "3.88 ref");
+        
+        let options = FormatOptions::new().decimal_places(Some(2));
+        
+        assert_eq!(format(Currencies { keys: 0, metal: refined!(3) }, options), "3.00 ref");
+        assert_eq!(format(Currencies { keys: 0, metal: -1 }, options), "-0.05 ref");
+    }
+    
+    #[test]
+    fn formats_compact() {
+        let options = FormatOptions::new().compact(true);
+        
+        let currencies = Currencies { keys: 2, metal: refined!(3) + scrap!(3) };
+        
+        assert_eq!(format(currencies, options), "2k, 3.33r");
+        assert_eq!(format(Currencies { keys: 1, metal: 0 }, options), "1k");
+        assert_eq!(format(Currencies { keys: 0, metal: -scrap!(1) }, options), "-0.11r");
+    }
+    
+    #[test]
+    fn formats_thousands_separator() {
+        let options = FormatOptions::new().thousands_separator(Some(","));
+        
+        assert_eq!(format(Currencies { keys: 12_500, metal: 0 }, options), "12,500 keys");
+        assert_eq!(format(Currencies { keys: 100, metal: 0 }, options), "100 keys");
+        assert_eq!(format(Currencies { keys: -1_000, metal: 0 }, options), "-1,000 keys");
+        assert_eq!(
+            format(Currencies { keys: 1_234_567, metal: refined!(1_000) + scrap!(5) }, options),
+            "1,234,567 keys, 1,000.55 ref",
+        );
+        assert_eq!(
+            format(Currencies { keys: i64::MIN, metal: 0 }, options.compact(true)),
+            "-9,223,372,036,854,775,808k",
+        );
+        assert_eq!(
+            format(Currencies { keys: 12_500, metal: 0 }, FormatOptions::new().thousands_separator(Some(" "))),
+            "12 500 keys",
+        );
+    }
+    
+    #[test]
+    fn formats_sign_styles() {
+        let leading = FormatOptions::new().sign_style(SignStyle::Leading);
+        let accounting = FormatOptions::new().sign_style(SignStyle::Accounting);
+        let currencies = Currencies { keys: -2, metal: -(refined!(3) + scrap!(3)) };
+        
+        assert_eq!(format(currencies, FormatOptions::new()), "-2 keys, -3.33 ref");
+        assert_eq!(format(currencies, leading), "-2 keys, 3.33 ref");
+        assert_eq!(format(currencies, accounting), "(2 keys, 3.33 ref)");
+        assert_eq!(format(currencies, leading.compact(true)), "-2k, 3.33r");
+        assert_eq!(format(Currencies { keys: -1, metal: 0 }, leading), "-1 key");
+        assert_eq!(format(Currencies { keys: -1, metal: -scrap!(3) }, accounting), "(1 key, 0.33 ref)");
+        assert_eq!(format(Currencies { keys: -1, metal: 0 }, FormatOptions::new()), "-1 keys");
+        assert_eq!(format(Currencies { keys: 0, metal: -scrap!(1) }, accounting), "(0.11 ref)");
+        assert_eq!(
+            format(Currencies { keys: i64::MIN, metal: i64::MIN }, leading.decimal_places(Some(0))),
+            "-9223372036854775808 keys, 512409557603043100 ref",
+        );
+    }
+    
+    #[test]
+    fn formats_mixed_signs_per_component() {
+        let currencies = Currencies { keys: -2, metal: refined!(3) };
+        
+        for sign_style in [SignStyle::PerComponent, SignStyle::Leading, SignStyle::Accounting] {
+            let options = FormatOptions::new().sign_style(sign_style);
+            
+            assert_eq!(format(currencies, options), "-2 keys, 3 ref");
+        }
+        
+        let options = FormatOptions::new().sign_style(SignStyle::Accounting);
+        
+        assert_eq!(format(Currencies { keys: 2, metal: 0 }, options), "2 keys");
+        assert_eq!(format(Currencies::default(), options), "nothing");
+    }
+    
+    #[test]
+    fn pads_to_width() {
+        let currencies = Currencies { keys: 2, metal: refined!(3) + scrap!(3) };
+        let options = FormatOptions::new().compact(true);
+        
+        assert_eq!(format!("{:>20}", currencies.display_with(&options)), "           2k, 3.33r");
+        assert_eq!(format!("{:<12}|", currencies.display_with(&options)), "2k, 3.33r   |");
+        assert_eq!(format!("{:*^13}", currencies.display_with(&options)), "**2k, 3.33r**");
+        // values longer than the width are not truncated
+        assert_eq!(format!("{:>4}", currencies.display_with(&options)), "2k, 3.33r");
+        assert_eq!(format!("{:>12}", currencies.display_as_keys(refined!(50), 1)), "    2.1 keys");
+    }
+    
+    #[test]
+    fn formats_as_keys() {
+        let key_price = refined!(50);
+        let as_keys = |currencies: Currencies, precision| {
+            currencies.display_as_keys(key_price, precision).to_string()
+        };
+        
+        assert_eq!(as_keys(Currencies { keys: 2, metal: refined!(22) + scrap!(5) }, 2), "2.45 keys");
+        assert_eq!(as_keys(Currencies { keys: 1, metal: 0 }, 0), "1 key");
+        assert_eq!(as_keys(Currencies { keys: 1, metal: 0 }, 2), "1.00 keys");
+        assert_eq!(as_keys(Currencies { keys: 0, metal: refined!(25) }, 1), "0.5 keys");
+        assert_eq!(as_keys(Currencies { keys: -2, metal: -refined!(25) }, 1), "-2.5 keys");
+        // rounds to the nearest value
+        assert_eq!(as_keys(Currencies { keys: 1, metal: refined!(49) + scrap!(8) }, 2), "2.00 keys");
+        assert_eq!(as_keys(Currencies { keys: 0, metal: refined!(24) }, 0), "0 keys");
+        assert_eq!(as_keys(Currencies { keys: 0, metal: -1 }, 0), "0 keys");
+        assert_eq!(as_keys(Currencies { keys: 0, metal: -1 }, 2), "0.00 keys");
+        assert_eq!(as_keys(Currencies { keys: 0, metal: -refined!(1) }, 2), "-0.02 keys");
+    }
+    
+    #[test]
+    fn formats_as_keys_with_large_values() {
+        let currencies = Currencies { keys: i64::MAX, metal: i64::MAX };
+        
+        assert_eq!(
+            currencies.display_as_keys(i64::MAX, 2).to_string(),
+            "9223372036854775808.00 keys",
+        );
+    }
+    
+    #[test]
+    fn formats_separator() {
+        let options = FormatOptions::new().separator(" ");
+        
+        assert_eq!(format(Currencies { keys: 2, metal: refined!(3) }, options), "2 keys 3 ref");
+    }
+}
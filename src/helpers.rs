@@ -1,14 +1,21 @@
-use crate::error::ParseError;
+use crate::error::{ParseError, ParseUSDError};
 use crate::types::Currency;
-use crate::constants::{KEYS_SYMBOL, KEY_SYMBOL, METAL_SYMBOL, ONE_REF};
-use crate::Rounding;
-use std::fmt;
-use std::str::FromStr;
+use crate::constants::{ONE_REF, ONE_SCRAP, ONE_HAT};
+use crate::parse::{self, ParseOptions, KeyCount};
+use crate::{Currencies, Rounding, RoundingDirection, RoundMetal};
+#[cfg(not(feature = "std"))]
+use crate::float::Float;
+use core::cmp::Ordering;
+use core::fmt;
 use serde::{Deserialize, Deserializer};
+use serde::de::{self, Visitor};
+use alloc::string::{String, ToString};
+use alloc::borrow::ToOwned;
+use alloc::format;
 
 /// Converts currencies to a metal value using the given key price (represented as weapons). This
 /// method is saturating.
-pub fn to_metal(
+pub const fn to_metal(
     metal: Currency,
     keys: Currency,
     key_price: Currency,
@@ -19,45 +26,214 @@ pub fn to_metal(
 /// Converts currencies to a metal value using the given key price (represented as weapons).
 /// In cases where the result overflows or underflows beyond the limit for [`i64`], `None` is 
 /// returned.
-pub fn checked_to_metal(
+pub const fn checked_to_metal(
     metal: Currency,
     keys: Currency,
     key_price: Currency,
 ) -> Option<Currency> {
-    metal.checked_add(keys.checked_mul(key_price)?)
+    let Some(keys_metal) = keys.checked_mul(key_price) else {
+        return None;
+    };
+    
+    metal.checked_add(keys_metal)
+}
+
+/// Converts currencies to a metal value using the given key price (represented as weapons). The 
+/// result is widened to [`i128`] so it can never overflow, which makes it suitable for exact 
+/// comparisons.
+pub const fn to_metal_wide(
+    metal: Currency,
+    keys: Currency,
+    key_price: Currency,
+) -> i128 {
+    keys as i128 * key_price as i128 + metal as i128
+}
+
+/// Compares the total values of two currencies using the given key price (represented as 
+/// weapons). Values are compared exactly as integers. See [`Currencies::cmp_with_key_price`].
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, compare_values, refined};
+/// use std::cmp::Ordering;
+/// 
+/// let a = Currencies { keys: 1, metal: 0 };
+/// let b = Currencies { keys: 0, metal: refined!(51) };
+/// 
+/// assert_eq!(compare_values(&a, &b, refined!(50)), Ordering::Less);
+/// ```
+pub fn compare_values(a: &Currencies, b: &Currencies, key_price: Currency) -> Ordering {
+    a.cmp_with_key_price(b, key_price)
+}
+
+/// Sorts currencies by their total values from lowest to highest using the given key price 
+/// (represented as weapons). The sort is stable, so currencies of equal value keep their order.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, sort_by_value, refined};
+/// 
+/// let mut listings = [
+///     Currencies { keys: 0, metal: refined!(55) },
+///     Currencies { keys: 1, metal: 0 },
+///     Currencies { keys: 0, metal: refined!(45) },
+/// ];
+/// 
+/// sort_by_value(&mut listings, refined!(50));
+/// 
+/// assert_eq!(listings, [
+///     Currencies { keys: 0, metal: refined!(45) },
+///     Currencies { keys: 1, metal: 0 },
+///     Currencies { keys: 0, metal: refined!(55) },
+/// ]);
+/// ```
+pub fn sort_by_value(currencies: &mut [Currencies], key_price: Currency) {
+    currencies.sort_by(|a, b| compare_values(a, b, key_price));
 }
 
-/// Deserializes float weapon values as weapons.
+/// Deserializes refined values as weapons. Integers, floats and numeric strings are accepted.
 pub fn metal_deserializer<'de, D>(deserializer: D) -> Result<Currency, D::Error>
 where
     D: Deserializer<'de>
 {
-    // get the metal value as a float e.g. 2.55 ref
-    let metal_refined_float = f32::deserialize(deserializer)?;
-    // will fit it into the nearest weapon value
-    let metal = (metal_refined_float * (ONE_REF as f32)).round() as Currency;
+    deserialize_metal(deserializer, ONE_REF)
+}
+
+/// Deserializes metal values counted in the given unit (represented as weapons) as weapons, e.g. 
+/// `ONE_REF` for refined. Integers, floats and numeric strings are accepted in human-readable 
+/// formats. Other formats, such as bincode, store metal values as integer weapons regardless of 
+/// the unit so that values are exact and the layout is fixed.
+pub(crate) fn deserialize_metal<'de, D>(deserializer: D, unit: Currency) -> Result<Currency, D::Error>
+where
+    D: Deserializer<'de>
+{
+    let visitor = MetalVisitor { unit };
+    
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(visitor)
+    } else {
+        Currency::deserialize(deserializer)
+    }
+}
+
+/// Visits metal values counted in a unit (represented as weapons).
+struct MetalVisitor {
+    unit: Currency,
+}
+
+impl<'de> Visitor<'de> for MetalVisitor {
+    type Value = Currency;
+    
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an integer, float or numeric string")
+    }
+    
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(value.saturating_mul(self.unit))
+    }
+    
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_i64(Currency::try_from(value).unwrap_or(Currency::MAX))
+    }
+    
+    fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if !value.is_finite() {
+            return Err(E::invalid_value(de::Unexpected::Float(value), &self));
+        }
+        
+        // will fit it into the nearest weapon value
+        Ok((value * self.unit as f64).round() as Currency)
+    }
     
-    Ok(metal)
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let trimmed = value.trim();
+        
+        if let Ok(value) = trimmed.parse::<i64>() {
+            return self.visit_i64(value);
+        }
+        
+        match trimmed.parse::<f64>() {
+            Ok(value) if value.is_finite() => self.visit_f64(value),
+            _ => Err(E::invalid_value(de::Unexpected::Str(value), &self)),
+        }
+    }
+}
+
+/// Serializes and deserializes metal values (represented as weapons) as refined floats.
+pub mod metal {
+    use serde::{Serializer, Deserializer};
+    use crate::types::Currency;
+    use super::{get_metal_float_f64, metal_deserializer};
+    #[cfg(not(feature = "std"))]
+    use crate::float::Float;
+    
+    pub fn serialize<S>(value: &Currency, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        if !serializer.is_human_readable() {
+            return serializer.serialize_i64(*value);
+        }
+        
+        let float = get_metal_float_f64(*value);
+        
+        // Whole values are serialized as integers, matching how Currencies are serialized.
+        if float.fract() == 0.0 {
+            serializer.serialize_i64(float as Currency)
+        } else {
+            serializer.serialize_f64(float)
+        }
+    }
+    
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Currency, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        metal_deserializer(deserializer)
+    }
 }
 
 /// Serialzies and deserializes cents.
 pub mod cents {
     use serde::{Serializer, Deserialize, Deserializer};
     use crate::types::Currency;
-    use super::cents_to_dollars;
+    #[cfg(not(feature = "std"))]
+    use crate::float::Float;
     
     pub fn serialize<S>(value: &Currency, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer
     {
-        serializer.serialize_f32(cents_to_dollars(*value))
+        // binary formats store exact cents
+        if !serializer.is_human_readable() {
+            return serializer.serialize_i64(*value);
+        }
+        
+        // f64 is used so that large values do not lose precision
+        serializer.serialize_f64(*value as f64 / 100.0)
     }
     
     pub fn deserialize<'de, D>(deserializer: D) -> Result<Currency, D::Error>
     where
         D: Deserializer<'de>
     {
-        let usd = f32::deserialize(deserializer)?;
+        if !deserializer.is_human_readable() {
+            return Currency::deserialize(deserializer);
+        }
+        
+        let usd = f64::deserialize(deserializer)?;
         let cents = (usd * 100.0).round() as Currency;
         
         Ok(cents)
@@ -69,15 +245,6 @@ pub fn cents_to_dollars(cents: Currency) -> f32 {
     (cents as f32) / 100.0
 }
 
-/// Pluralizes a value using an integer as the test.
-pub fn pluralize<'a>(amount: Currency, singular: &'a str, plural: &'a str) -> &'a str {
-    if amount == 1 {
-        singular
-    } else {
-        plural
-    }
-}
-
 /// Pluralizes a value using a float as the test.
 pub fn pluralize_float<'a>(amount: f32, singular: &'a str, plural: &'a str) -> &'a str {
     if amount == 1.0 {
@@ -140,6 +307,23 @@ pub fn get_metal_float(value: Currency) -> f32 {
     f32::trunc((value as f32 / (ONE_REF as f32)) * 100.0) / 100.0
 }
 
+/// Converts a metal value into its [`f64`] float value, truncated to 2 decimal places. The 
+/// truncation is done using integers so that large values do not lose precision. Prefer this over 
+/// [`get_metal_float`] for large values, since [`f32`] only holds about 7 significant digits.
+///
+/// # Examples
+/// ```
+/// use tf2_price::{get_metal_float, get_metal_float_f64, refined, scrap};
+/// 
+/// let metal = refined!(500_000) + scrap!(1);
+/// 
+/// assert_eq!(get_metal_float_f64(metal), 500_000.11);
+/// assert_ne!(get_metal_float(metal) as f64, 500_000.11);
+/// ```
+pub fn get_metal_float_f64(value: Currency) -> f64 {
+    (value as i128 * 100 / ONE_REF as i128) as f64 / 100.0
+}
+
 /// Converts a float value into a metal value.
 ///
 /// # Examples
@@ -150,114 +334,402 @@ pub fn get_metal_from_float(value: f32) -> Currency {
     (value * (ONE_REF as f32)).round() as Currency
 }
 
-/// Parses currencies from a string.
-pub fn parse_from_string<T>(string: &str) -> Result<(T, Currency), ParseError>
+/// Converts an [`f64`] float value into a metal value, rounding to the nearest weapon. Prefer this 
+/// over [`get_metal_from_float`] for large values, since [`f32`] only holds about 7 significant 
+/// digits.
+///
+/// # Examples
+/// ```
+/// use tf2_price::{get_metal_from_float_f64, refined, scrap};
+/// 
+/// assert_eq!(get_metal_from_float_f64(0.33), scrap!(3));
+/// assert_eq!(get_metal_from_float_f64(500_000.11), refined!(500_000) + scrap!(1));
+/// ```
+pub fn get_metal_from_float_f64(value: f64) -> Currency {
+    (value * ONE_REF as f64).round() as Currency
+}
+
+/// Converts a metal value into hundredths of refined metal, truncated towards zero. This is the 
+/// integer equivalent of [`get_metal_float`] and can be used in `const` contexts.
+///
+/// # Examples
+/// ```
+/// use tf2_price::{get_metal_hundredths, refined};
+/// 
+/// const HUNDREDTHS: i64 = get_metal_hundredths(refined!(1) + 6);
+/// 
+/// assert_eq!(HUNDREDTHS, 133);
+/// ```
+pub const fn get_metal_hundredths(value: Currency) -> Currency {
+    saturate(value as i128 * 100 / ONE_REF as i128)
+}
+
+/// Converts hundredths of refined metal into a metal value, rounding half away from zero. This is 
+/// the integer equivalent of [`get_metal_from_float`] and can be used in `const` contexts.
+///
+/// # Examples
+/// ```
+/// use tf2_price::{get_metal_from_hundredths, refined};
+/// 
+/// const METAL: i64 = get_metal_from_hundredths(133);
+/// 
+/// assert_eq!(METAL, refined!(1) + 6);
+/// ```
+pub const fn get_metal_from_hundredths(hundredths: Currency) -> Currency {
+    let value = hundredths as i128 * ONE_REF as i128;
+    
+    if value < 0 {
+        saturate((value - 50) / 100)
+    } else {
+        saturate((value + 50) / 100)
+    }
+}
+
+/// Narrows an [`i128`] into a [`Currency`], saturating at the bounds.
+pub(crate) const fn saturate(value: i128) -> Currency {
+    if value > Currency::MAX as i128 {
+        Currency::MAX
+    } else if value < Currency::MIN as i128 {
+        Currency::MIN
+    } else {
+        value as Currency
+    }
+}
+
+/// Converts a metal value into its value in craft hats.
+///
+/// # Examples
+/// ```
+/// use tf2_price::{get_hats_float, refined, scrap};
+/// 
+/// assert_eq!(get_hats_float(refined!(4)), 3.0);
+/// assert_eq!(get_hats_float(refined!(2) + scrap!(6)), 2.0);
+/// ```
+pub fn get_hats_float(value: Currency) -> f32 {
+    value as f32 / (ONE_HAT as f32)
+}
+
+/// Converts a value in craft hats into a metal value.
+///
+/// # Examples
+/// ```
+/// use tf2_price::{get_metal_from_hats_float, refined};
+/// 
+/// assert_eq!(get_metal_from_hats_float(3.0), refined!(4));
+/// ```
+pub fn get_metal_from_hats_float(value: f32) -> Currency {
+    (value * (ONE_HAT as f32)).round() as Currency
+}
+
+/// Parses currencies from a string. Metal may be given in refined ("ref", "refined"), reclaimed 
+/// ("rec", "reclaimed") or scrap ("scrap"), with each denomination summed into the metal value, 
+/// e.g. "1 key, 2 rec, 1 scrap". Each unit may only appear once.
+pub(crate) fn parse_from_string<T>(string: &str) -> Result<(T, Currency), ParseError>
 where
-    T: Default + FromStr + PartialEq,
-    <T as FromStr>::Err: fmt::Display,
+    T: KeyCount,
 {
-    let mut keys = T::default();
-    let mut metal = 0;
-    
-    for element in string.split(", ") {
-        let mut element_split = element.split(' ');
-        let (
-            count_str,
-            currency_name,
-        ) = (
-            element_split.next(),
-            element_split.next(),
-        );
-        
-        if count_str.is_none() || currency_name.is_none() || element_split.next().is_some() {
-            return Err(ParseError::Invalid);
+    parse::parse_currencies(string, &ParseOptions::default())
+}
+
+/// Symbol for cents.
+const CENTS_SYMBOL: &str = "¢";
+/// Currency code for US dollars.
+const USD_SYMBOL: &str = "USD";
+
+/// Parses a cash value from a string into cents, e.g. "$1,234.56" becomes `123456`. The value may 
+/// be given in dollars with a leading "$" and/or a trailing "USD", e.g. "$4.99" or "4.99 USD", or 
+/// in cents with a trailing "¢", e.g. "499¢". Thousands separators are optional. Values are parsed 
+/// exactly without passing through floats.
+pub fn parse_cents(string: &str) -> Result<Currency, ParseUSDError> {
+    let string = string.trim();
+    
+    if string.is_empty() {
+        return Err(ParseUSDError::Empty);
+    }
+    
+    let (is_cents, string) = match string.strip_suffix(CENTS_SYMBOL) {
+        Some(stripped) => (true, stripped.trim_end()),
+        None => (false, string),
+    };
+    let string = match string.len().checked_sub(USD_SYMBOL.len()) {
+        Some(index) if !is_cents && string.get(index..)
+            .is_some_and(|code| code.eq_ignore_ascii_case(USD_SYMBOL)) => {
+            string[..index].trim_end()
+        },
+        _ => string,
+    };
+    let (negative, string) = match string.strip_prefix('-') {
+        Some(stripped) => (true, stripped),
+        None => (false, string),
+    };
+    let string = match string.strip_prefix('$') {
+        Some(stripped) if !is_cents => stripped,
+        _ => string,
+    };
+    // Display formats negative values as "$-1.00".
+    let (negative, string) = match string.strip_prefix('-') {
+        Some(stripped) if !negative => (true, stripped),
+        _ => (negative, string),
+    };
+    let (dollars_str, cents_str) = match string.split_once('.') {
+        Some((dollars, cents)) => (dollars, cents),
+        None => (string, ""),
+    };
+    
+    if dollars_str.is_empty() || !cents_str.chars().all(|c| c.is_ascii_digit()) {
+        return Err(ParseUSDError::InvalidFormat);
+    }
+    
+    if cents_str.len() > if is_cents { 0 } else { 2 } {
+        return Err(ParseUSDError::TooManyDecimalPlaces);
+    }
+    
+    for (i, group) in dollars_str.split(',').enumerate() {
+        if !group.chars().all(|c| c.is_ascii_digit()) {
+            return Err(ParseUSDError::InvalidFormat);
         }
         
-        let (
-            count_str,
-            currency_name,
-        ) = (
-            count_str.unwrap(),
-            currency_name.unwrap(),
-        );
+        let valid = if i == 0 {
+            !group.is_empty() && (group.len() <= 3 || !dollars_str.contains(','))
+        } else {
+            group.len() == 3
+        };
         
-        match currency_name {
-            KEY_SYMBOL | KEYS_SYMBOL => {
-                keys = count_str.parse::<T>()
-                    .map_err(|e| ParseError::ParseNumeric(e.to_string()))?;
-            },
-            METAL_SYMBOL => {
-                metal = get_metal_from_float(count_str.parse::<f32>()?);
-            },
-            _ => {
-                return Err(ParseError::Invalid);
-            },
+        if !valid {
+            return Err(ParseUSDError::InvalidThousandsSeparator);
         }
     }
     
-    if keys == T::default() && metal == 0 {
-        return Err(ParseError::Invalid);
-    }
+    let whole = dollars_str.replace(',', "").parse::<Currency>()
+        .map_err(|_| ParseUSDError::Overflow)?;
+    let cents = if is_cents {
+        Some(whole)
+    } else {
+        let cents = match cents_str.len() {
+            0 => 0,
+            // cents_str is validated as 1 or 2 ASCII digits
+            1 => cents_str.parse::<Currency>().unwrap_or_default() * 10,
+            _ => cents_str.parse::<Currency>().unwrap_or_default(),
+        };
+        
+        whole.checked_mul(100).and_then(|dollars| dollars.checked_add(cents))
+    }.ok_or(ParseUSDError::Overflow)?;
     
-    Ok((keys, metal))
+    if negative {
+        Ok(-cents)
+    } else {
+        Ok(cents)
+    }
 }
 
-/// Rounds a metal value.
-pub fn round_metal(metal: Currency, rounding: &Rounding) -> Currency {
-    if metal == 0 {
+/// Rounds a metal value. This is equivalent to [`Rounding::round`].
+pub const fn round_metal(metal: Currency, rounding: &Rounding) -> Currency {
+    rounding.round(metal)
+}
+
+/// Rounds a metal value. `None` if the result overflows integer bounds. This is equivalent to 
+/// [`Rounding::checked_round`].
+pub const fn checked_round_metal(metal: Currency, rounding: &Rounding) -> Option<Currency> {
+    rounding.checked_round(metal)
+}
+
+/// Rounds a metal value to a multiple of `multiple` (represented as weapons) in the given 
+/// direction, e.g. to the nearest reclaimed using [`ONE_REC`](crate::ONE_REC). `metal` is 
+/// returned unchanged if `multiple` is not positive. Results saturate at integer bounds.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{round_metal_to_multiple, RoundingDirection, ONE_REC, refined, scrap};
+/// 
+/// let metal = refined!(1) + scrap!(4);
+/// 
+/// assert_eq!(round_metal_to_multiple(metal, ONE_REC, RoundingDirection::Nearest), refined!(1) + scrap!(3));
+/// assert_eq!(round_metal_to_multiple(metal, ONE_REC, RoundingDirection::Up), refined!(1) + scrap!(6));
+/// assert_eq!(round_metal_to_multiple(-metal, ONE_REC, RoundingDirection::TowardZero), -refined!(1) - scrap!(3));
+/// ```
+pub fn round_metal_to_multiple(
+    metal: Currency,
+    multiple: Currency,
+    direction: RoundingDirection,
+) -> Currency {
+    if multiple <= 0 {
         return metal;
     }
     
-    match *rounding {
-        // No rounding needed if the metal value is an even number.
-        Rounding::UpScrap if metal % 2 != 0 => {
-            metal + 1
-        },
-        // No rounding needed if the metal value is an even number.
-        Rounding::DownScrap if metal % 2 != 0 => {
-            metal - 1
-        },
-        Rounding::Refined => {
-            let value = metal + ONE_REF / 2;
-            
-            value - (value % ONE_REF)
-        },
-        Rounding::UpRefined => {
-            let remainder = metal % ONE_REF;
-            
-            if remainder != 0 {
-                if metal > 0 {
-                    metal - (remainder + -ONE_REF)
-                } else {
-                    metal - remainder
-                }
-            } else {
-                metal
-            }
-        },
-        Rounding::DownRefined => {
-            let remainder = metal % ONE_REF;
-            
-            if remainder != 0 {
-                if metal > 0 {
-                    metal - remainder
-                } else {
-                    metal - (remainder + ONE_REF)
-                }
-            } else {
-                metal
-            }
-        },
-        _ => {
-            metal
-        },
+    // Computed as i128 so that rounding values near the integer bounds does not overflow.
+    saturate(round_wide_to_multiple(metal as i128, multiple as i128, direction))
+}
+
+/// Rounds a value to a multiple of `multiple` in the given direction. `multiple` must be 
+/// positive and the result must not overflow [`i128`], which holds for any values widened from 
+/// [`Currency`].
+pub(crate) fn round_wide_to_multiple(
+    value: i128,
+    multiple: i128,
+    direction: RoundingDirection,
+) -> i128 {
+    let remainder = value.rem_euclid(multiple);
+    
+    if remainder == 0 {
+        return value;
+    }
+    
+    let down = value - remainder;
+    let up = down + multiple;
+    
+    match direction {
+        RoundingDirection::Nearest if remainder * 2 >= multiple => up,
+        RoundingDirection::Nearest => down,
+        RoundingDirection::Up => up,
+        RoundingDirection::Down => down,
+        RoundingDirection::TowardZero if value < 0 => up,
+        RoundingDirection::TowardZero => down,
+        RoundingDirection::AwayFromZero if value < 0 => down,
+        RoundingDirection::AwayFromZero => up,
+    }
+}
+
+/// Rounds a metal value in the given direction to the nearest value whose fractional refined part 
+/// is one of `endings` (represented as weapons), e.g. `scrap!(3)` for prices ending in `.33` ref. 
+/// This nudges computed prices to quotes which look natural to traders. Endings are taken modulo 
+/// one refined.
+/// 
+/// Negative values use the same endings for their magnitude, e.g. `-5.55` ref. If there is no 
+/// such value between zero and `metal`, the nearest one away from zero is used. `metal` is 
+/// returned unchanged if `endings` is empty. Results saturate at integer bounds.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{round_metal_to_endings, RoundingDirection, refined, scrap};
+/// 
+/// let endings = [scrap!(3), scrap!(5), scrap!(8)];
+/// // 5.66 ref
+/// let metal = refined!(5) + scrap!(6);
+/// 
+/// assert_eq!(
+///     round_metal_to_endings(metal, &endings, RoundingDirection::Nearest),
+///     refined!(5) + scrap!(5),
+/// );
+/// assert_eq!(
+///     round_metal_to_endings(metal, &endings, RoundingDirection::Up),
+///     refined!(5) + scrap!(8),
+/// );
+/// ```
+pub fn round_metal_to_endings(
+    metal: Currency,
+    endings: &[Currency],
+    direction: RoundingDirection,
+) -> Currency {
+    let negative = metal < 0;
+    // Computed as i128 so that the magnitude of Currency::MIN and values near the integer bounds 
+    // do not overflow.
+    let magnitude = (metal as i128).abs();
+    let refined = ONE_REF as i128;
+    let base = magnitude - magnitude % refined;
+    let candidates = endings
+        .iter()
+        .map(|ending| base + (*ending as i128).rem_euclid(refined))
+        .flat_map(|value| [value - refined, value, value + refined])
+        .filter(|value| *value >= 0);
+    let below = candidates.clone().filter(|value| *value <= magnitude).max();
+    let above = candidates.filter(|value| *value >= magnitude).min();
+    let (below, above) = match (below, above) {
+        (Some(below), Some(above)) => (below, above),
+        (None, Some(above)) => (above, above),
+        _ => return metal,
+    };
+    let toward_zero = match direction {
+        // Half-way values are rounded up, which is towards zero for negative values.
+        RoundingDirection::Nearest if above - magnitude == magnitude - below => negative,
+        RoundingDirection::Nearest => magnitude - below < above - magnitude,
+        RoundingDirection::Up => negative,
+        RoundingDirection::Down => !negative,
+        RoundingDirection::TowardZero => true,
+        RoundingDirection::AwayFromZero => false,
+    };
+    let rounded = if toward_zero { below } else { above };
+    
+    if negative {
+        saturate(-rounded)
+    } else {
+        saturate(rounded)
     }
 }
 
+/// Rounds a fractional metal value (represented as weapons) into a whole metal value. Unlike 
+/// [`round_metal`] no precision is lost before rounding, e.g. `4.5` weapons rounded up to the 
+/// nearest scrap will result in `6` rather than `4`. Results saturate at integer bounds.
+pub fn round_metal_float(metal: f64, rounding: &Rounding) -> Currency {
+    let scrap = ONE_SCRAP as f64;
+    let refined = ONE_REF as f64;
+    let rounded = match *rounding {
+        Rounding::UpScrap => (metal / scrap).ceil() * scrap,
+        Rounding::DownScrap => (metal / scrap).floor() * scrap,
+        // Halves are rounded up, including for negative values, which matches the behavior of 
+        // round_metal.
+        Rounding::Refined => (metal / refined + 0.5).floor() * refined,
+        Rounding::UpRefined => (metal / refined).ceil() * refined,
+        Rounding::DownRefined => (metal / refined).floor() * refined,
+        Rounding::ToEvenScrap => (metal / scrap).round_ties_even() * scrap,
+        Rounding::TowardZeroScrap => (metal / scrap).trunc() * scrap,
+        Rounding::AwayFromZeroScrap => round_away_from_zero(metal / scrap) * scrap,
+        Rounding::TowardZeroRefined => (metal / refined).trunc() * refined,
+        Rounding::AwayFromZeroRefined => round_away_from_zero(metal / refined) * refined,
+        Rounding::None => metal.round(),
+    };
+    
+    rounded as Currency
+}
+
+/// Rounds a float to the nearest integer away from zero.
+fn round_away_from_zero(value: f64) -> f64 {
+    if value < 0.0 {
+        value.floor()
+    } else {
+        value.ceil()
+    }
+}
+
+/// Splits a float into its floor, saturating at the bounds of [`i128`], and its fraction in 
+/// `0.0..1.0`.
+pub(crate) fn split_fraction(value: f64) -> (i128, f64) {
+    let floor = value.floor();
+    
+    (floor as i128, value - floor)
+}
+
+/// Rounds the metal value `whole + fraction` (represented as weapons), where `fraction` is in 
+/// `0.0..1.0`, using the given rounding method. Unlike converting the value to a float first, 
+/// whole values beyond the precision of [`f64`] are kept exactly; only their fraction is rounded 
+/// to the nearest weapon before rounding. Results saturate at integer bounds.
+pub(crate) fn round_metal_parts<R: RoundMetal + ?Sized>(
+    whole: i128,
+    fraction: f64,
+    rounding: &R,
+) -> Currency {
+    // Whole values below this are represented exactly by f64 along with their fraction.
+    const MAX_EXACT: u128 = 1 << 52;
+    
+    if fraction == 0.0 {
+        return rounding.round_metal(saturate(whole));
+    }
+    
+    if whole.unsigned_abs() < MAX_EXACT {
+        return rounding.round_metal_float(whole as f64 + fraction);
+    }
+    
+    // Halves are rounded away from zero, matching f64::round.
+    let round_up = fraction > 0.5 || (fraction == 0.5 && whole >= 0);
+    let whole = if round_up { whole.saturating_add(1) } else { whole };
+    
+    rounding.round_metal(saturate(whole))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::scrap;
+    use crate::constants::{KEYS_SYMBOL, METAL_SYMBOL, REC_SYMBOL, ONE_REC};
     
     #[test]
     fn prints_float_rounded_whole_number() {
@@ -278,4 +750,424 @@ mod tests {
     fn converts_to_metal_float() {
         assert_eq!(0.33, get_metal_float(6));
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn converts_to_metal_float_f64() {
+        assert_eq!(get_metal_float_f64(6), 0.33);
+        assert_eq!(get_metal_float_f64(-6), -0.33);
+        assert_eq!(get_metal_float_f64(ONE_REF * 1_000_000 + 4), 1_000_000.22);
+    }
+    
+    #[test]
+    fn converts_large_metal_floats_f64() {
+        let metal = ONE_REF * 5_000_000 + scrap!(1);
+        
+        assert_eq!(get_metal_from_float_f64(get_metal_float_f64(metal)), metal);
+        assert_eq!(get_metal_from_float_f64(5_000_000.11), metal);
+        assert_ne!(get_metal_from_float(5_000_000.11), metal);
+        assert_eq!(get_metal_from_float_f64(-0.33), -scrap!(3));
+        assert_eq!(get_metal_from_float_f64(f64::NAN), 0);
+    }
+    
+    #[test]
+    fn converts_metal_hundredths() {
+        assert_eq!(get_metal_hundredths(6), 33);
+        assert_eq!(get_metal_hundredths(-6), -33);
+        assert_eq!(get_metal_from_hundredths(33), scrap!(3));
+        assert_eq!(get_metal_from_hundredths(-33), -scrap!(3));
+        assert_eq!(get_metal_hundredths(i64::MAX), i64::MAX);
+        
+        for value in [0.0, 1.0, 0.33, 1.33, -2.55, 70.22] {
+            let hundredths = (value * 100.0_f32).round() as Currency;
+            
+            assert_eq!(get_metal_from_hundredths(hundredths), get_metal_from_float(value));
+        }
+    }
+    
+    #[test]
+    fn converts_hats_to_metal_and_back() {
+        assert_eq!(get_metal_from_hats_float(1.0), ONE_HAT);
+        assert_eq!(get_metal_from_hats_float(0.5), scrap!(6));
+        assert_eq!(get_hats_float(get_metal_from_hats_float(7.0)), 7.0);
+    }
+    
+    #[test]
+    fn parses_from_string_errors() {
+        assert_eq!(parse_from_string::<Currency>(""), Err(ParseError::Empty));
+        assert_eq!(parse_from_string::<Currency>("0 keys"), Err(ParseError::Empty));
+        assert_eq!(parse_from_string::<Currency>("1"), Err(ParseError::InvalidFormat {
+            token: "1".into(),
+            span: 0..1,
+        }));
+        assert_eq!(parse_from_string::<Currency>("1 key 2"), Err(ParseError::InvalidFormat {
+            token: "1 key 2".into(),
+            span: 0..7,
+        }));
+        assert_eq!(parse_from_string::<Currency>("1 hat"), Err(ParseError::InvalidFormat {
+            token: "hat".into(),
+            span: 2..5,
+        }));
+        assert_eq!(
+            parse_from_string::<Currency>("1.5 keys"),
+            Err(ParseError::InvalidKeyCount {
+                token: "1.5".into(),
+                span: 0..3,
+            }),
+        );
+        assert_eq!(
+            parse_from_string::<Currency>("one ref"),
+            Err(ParseError::InvalidMetalValue {
+                token: "one".into(),
+                span: 0..3,
+            }),
+        );
+        assert_eq!(
+            parse_from_string::<Currency>("inf ref"),
+            Err(ParseError::InvalidMetalValue {
+                token: "inf".into(),
+                span: 0..3,
+            }),
+        );
+    }
+    
+    #[test]
+    fn parses_from_string_rejects_duplicates() {
+        assert_eq!(
+            parse_from_string::<Currency>("1 key, 2 keys"),
+            Err(ParseError::DuplicateCurrency {
+                currency: KEYS_SYMBOL.into(),
+                token: "2 keys".into(),
+                span: 7..13,
+            }),
+        );
+        assert_eq!(
+            parse_from_string::<Currency>("1 ref, 2 ref"),
+            Err(ParseError::DuplicateCurrency {
+                currency: METAL_SYMBOL.into(),
+                token: "2 ref".into(),
+                span: 7..12,
+            }),
+        );
+        // Duplicates are rejected even when the value is 0.
+        assert!(parse_from_string::<Currency>("0 keys, 1 ref, 0 keys").is_err());
+    }
+    
+    #[test]
+    fn parses_long_form_units_from_string() {
+        assert_eq!(
+            parse_from_string::<Currency>("1 key, 2 rec, 1 scrap"),
+            Ok((1, ONE_REC * 2 + ONE_SCRAP)),
+        );
+        assert_eq!(
+            parse_from_string::<Currency>("1 refined, 1 reclaimed, 3 scrap"),
+            Ok((0, ONE_REF + ONE_REC + ONE_SCRAP * 3)),
+        );
+        assert_eq!(parse_from_string::<Currency>("2 keys, 1.5 rec"), Ok((2, ONE_REC + ONE_SCRAP + 1)));
+        assert_eq!(
+            parse_from_string::<Currency>("1 ref, 2 refined"),
+            Err(ParseError::DuplicateCurrency {
+                currency: METAL_SYMBOL.into(),
+                token: "2 refined".into(),
+                span: 7..16,
+            }),
+        );
+        assert_eq!(
+            parse_from_string::<Currency>("1 rec, 1 reclaimed"),
+            Err(ParseError::DuplicateCurrency {
+                currency: REC_SYMBOL.into(),
+                token: "1 reclaimed".into(),
+                span: 7..18,
+            }),
+        );
+    }
+    
+    #[test]
+    fn parses_large_metal_from_string() {
+        assert_eq!(parse_from_string::<Currency>("1000000.22 ref"), Ok((0, ONE_REF * 1_000_000 + 4)));
+    }
+    
+    #[test]
+    fn parses_cents() {
+        assert_eq!(parse_cents("$4.99").unwrap(), 499);
+        assert_eq!(parse_cents("$1,234.56").unwrap(), 123456);
+        assert_eq!(parse_cents("12").unwrap(), 1200);
+        assert_eq!(parse_cents("$0.5").unwrap(), 50);
+        assert_eq!(parse_cents("-$4.99").unwrap(), -499);
+        assert_eq!(parse_cents("$-4.99").unwrap(), -499);
+    }
+    
+    #[test]
+    fn parses_cents_with_usd_code() {
+        assert_eq!(parse_cents("4.99 USD").unwrap(), 499);
+        assert_eq!(parse_cents("$1,234.56 usd").unwrap(), 123456);
+        assert_eq!(parse_cents("-4.99USD").unwrap(), -499);
+    }
+    
+    #[test]
+    fn parses_cents_with_cents_symbol() {
+        assert_eq!(parse_cents("499¢").unwrap(), 499);
+        assert_eq!(parse_cents("1,499 ¢").unwrap(), 1499);
+        assert_eq!(parse_cents("-5¢").unwrap(), -5);
+        assert_eq!(parse_cents("4.5¢"), Err(ParseUSDError::TooManyDecimalPlaces));
+        assert_eq!(parse_cents("$5¢"), Err(ParseUSDError::InvalidFormat));
+    }
+    
+    #[test]
+    fn parses_cents_invalid() {
+        assert!(parse_cents("").is_err());
+        assert!(parse_cents("$").is_err());
+        assert!(parse_cents("$.99").is_err());
+        assert!(parse_cents("$4.999").is_err());
+        assert!(parse_cents("$1,23.00").is_err());
+        assert!(parse_cents("$4.-9").is_err());
+        assert!(parse_cents("--$4").is_err());
+        assert!(parse_cents("$99999999999999999999").is_err());
+    }
+    
+    #[test]
+    fn parses_cents_errors() {
+        assert_eq!(parse_cents(" "), Err(ParseUSDError::Empty));
+        assert_eq!(parse_cents("$4.999"), Err(ParseUSDError::TooManyDecimalPlaces));
+        assert_eq!(parse_cents("$1,23.00"), Err(ParseUSDError::InvalidThousandsSeparator));
+        assert_eq!(parse_cents("4.99 EUR"), Err(ParseUSDError::InvalidFormat));
+        assert_eq!(parse_cents("$99999999999999999"), Err(ParseUSDError::Overflow));
+    }
+    
+    #[test]
+    fn compares_values_near_ties() {
+        // These values are equal when converted to f32.
+        let a = Currencies { keys: 10_000_000, metal: 0 };
+        let b = Currencies { keys: 10_000_000, metal: 1 };
+        
+        assert_eq!(compare_values(&a, &b, ONE_REF * 50), Ordering::Less);
+    }
+    
+    #[test]
+    fn sorts_by_value_stable() {
+        let mut currencies = [
+            Currencies { keys: 1, metal: 0 },
+            Currencies { keys: 0, metal: ONE_REF * 50 },
+            Currencies { keys: 0, metal: ONE_REF },
+        ];
+        
+        sort_by_value(&mut currencies, ONE_REF * 50);
+        
+        assert_eq!(currencies, [
+            Currencies { keys: 0, metal: ONE_REF },
+            Currencies { keys: 1, metal: 0 },
+            Currencies { keys: 0, metal: ONE_REF * 50 },
+        ]);
+    }
+    
+    #[test]
+    fn rounds_metal_float_up_scrap() {
+        assert_eq!(round_metal_float(4.5, &Rounding::UpScrap), 6);
+        assert_eq!(round_metal_float(-3.0, &Rounding::UpScrap), -2);
+    }
+    
+    #[test]
+    fn rounds_metal_float_down_scrap() {
+        assert_eq!(round_metal_float(5.9, &Rounding::DownScrap), 4);
+    }
+    
+    #[test]
+    fn rounds_metal_float_refined() {
+        assert_eq!(round_metal_float(26.5, &Rounding::Refined), 18);
+        assert_eq!(round_metal_float(27.0, &Rounding::Refined), 36);
+        assert_eq!(round_metal_float(-10.0, &Rounding::Refined), -18);
+        assert_eq!(round_metal_float(-9.0, &Rounding::Refined), 0);
+    }
+    
+    #[test]
+    fn rounds_metal_parts() {
+        let large = 1_i128 << 60;
+        
+        assert_eq!(round_metal_parts(4, 0.5, &Rounding::UpScrap), 6);
+        assert_eq!(round_metal_parts(-3, 0.5, &Rounding::Refined), 0);
+        assert_eq!(round_metal_parts(large + 1, 0.0, &Rounding::None), (large + 1) as Currency);
+        assert_eq!(round_metal_parts(large + 1, 0.5, &Rounding::None), (large + 2) as Currency);
+        assert_eq!(round_metal_parts(-large - 2, 0.5, &Rounding::None), (-large - 2) as Currency);
+        assert_eq!(round_metal_parts(i128::MAX, 0.5, &Rounding::None), Currency::MAX);
+    }
+    
+    #[test]
+    fn rounds_metal_float_matches_round_metal_for_whole_values() {
+        let roundings = [
+            Rounding::UpScrap,
+            Rounding::DownScrap,
+            Rounding::Refined,
+            Rounding::UpRefined,
+            Rounding::DownRefined,
+            Rounding::ToEvenScrap,
+            Rounding::TowardZeroScrap,
+            Rounding::AwayFromZeroScrap,
+            Rounding::TowardZeroRefined,
+            Rounding::AwayFromZeroRefined,
+            Rounding::None,
+        ];
+        
+        for rounding in roundings {
+            for metal in -100..100 {
+                assert_eq!(round_metal_float(metal as f64, &rounding), round_metal(metal, &rounding));
+            }
+        }
+    }
+    
+    #[test]
+    fn rounds_metal_to_even_scrap() {
+        assert_eq!(round_metal(1, &Rounding::ToEvenScrap), 0);
+        assert_eq!(round_metal(3, &Rounding::ToEvenScrap), 4);
+        assert_eq!(round_metal(5, &Rounding::ToEvenScrap), 4);
+        assert_eq!(round_metal(6, &Rounding::ToEvenScrap), 6);
+        assert_eq!(round_metal(7, &Rounding::ToEvenScrap), 8);
+        assert_eq!(round_metal(-3, &Rounding::ToEvenScrap), -4);
+        assert_eq!(round_metal(-5, &Rounding::ToEvenScrap), -4);
+    }
+    
+    #[test]
+    fn rounds_metal_to_even_scrap_without_bias() {
+        let total = (1..=100).map(|metal| round_metal(metal, &Rounding::ToEvenScrap)).sum::<Currency>();
+        
+        assert_eq!(total, (1..=100).sum::<Currency>());
+    }
+    
+    #[test]
+    fn rounds_metal_float_to_even_scrap() {
+        assert_eq!(round_metal_float(5.0, &Rounding::ToEvenScrap), 4);
+        assert_eq!(round_metal_float(5.1, &Rounding::ToEvenScrap), 6);
+        assert_eq!(round_metal_float(7.0, &Rounding::ToEvenScrap), 8);
+        assert_eq!(round_metal_float(-5.0, &Rounding::ToEvenScrap), -4);
+    }
+    
+    #[test]
+    fn rounds_metal_toward_zero() {
+        assert_eq!(round_metal(5, &Rounding::TowardZeroScrap), 4);
+        assert_eq!(round_metal(-5, &Rounding::TowardZeroScrap), -4);
+        assert_eq!(round_metal(ONE_REF + 5, &Rounding::TowardZeroRefined), ONE_REF);
+        assert_eq!(round_metal(-ONE_REF - 5, &Rounding::TowardZeroRefined), -ONE_REF);
+        assert_eq!(round_metal(-ONE_REF, &Rounding::TowardZeroRefined), -ONE_REF);
+    }
+    
+    #[test]
+    fn rounds_metal_away_from_zero() {
+        assert_eq!(round_metal(5, &Rounding::AwayFromZeroScrap), 6);
+        assert_eq!(round_metal(-5, &Rounding::AwayFromZeroScrap), -6);
+        assert_eq!(round_metal(ONE_REF + 5, &Rounding::AwayFromZeroRefined), ONE_REF * 2);
+        assert_eq!(round_metal(-ONE_REF - 5, &Rounding::AwayFromZeroRefined), -ONE_REF * 2);
+        assert_eq!(round_metal(-ONE_REF, &Rounding::AwayFromZeroRefined), -ONE_REF);
+    }
+    
+    #[test]
+    fn rounds_metal_float_toward_and_away_from_zero() {
+        assert_eq!(round_metal_float(-4.5, &Rounding::TowardZeroScrap), -4);
+        assert_eq!(round_metal_float(-4.5, &Rounding::AwayFromZeroScrap), -6);
+        assert_eq!(round_metal_float(-19.5, &Rounding::TowardZeroRefined), -ONE_REF);
+        assert_eq!(round_metal_float(-19.5, &Rounding::AwayFromZeroRefined), -ONE_REF * 2);
+        assert_eq!(round_metal_float(19.5, &Rounding::AwayFromZeroRefined), ONE_REF * 2);
+    }
+    
+    #[test]
+    fn rounds_metal_symmetrically_around_zero() {
+        let roundings = [
+            Rounding::TowardZeroScrap,
+            Rounding::AwayFromZeroScrap,
+            Rounding::TowardZeroRefined,
+            Rounding::AwayFromZeroRefined,
+        ];
+        
+        for rounding in roundings {
+            for metal in 0..100 {
+                assert_eq!(round_metal(-metal, &rounding), -round_metal(metal, &rounding));
+            }
+        }
+    }
+    
+    #[test]
+    fn rounds_negative_metal_refined() {
+        assert_eq!(round_metal(-10, &Rounding::Refined), -ONE_REF);
+        assert_eq!(round_metal(-9, &Rounding::Refined), 0);
+        assert_eq!(round_metal(-100, &Rounding::Refined), -ONE_REF * 6);
+        assert_eq!(round_metal(-ONE_REF - 10, &Rounding::Refined), -ONE_REF * 2);
+        
+        for metal in 1..100 {
+            let rounded = round_metal(-metal, &Rounding::Refined);
+            
+            assert_eq!(rounded % ONE_REF, 0);
+            assert!((rounded + metal).abs() <= ONE_REF / 2);
+        }
+    }
+    
+    #[test]
+    fn rounds_metal_to_multiple() {
+        use crate::constants::ONE_REC;
+        
+        assert_eq!(round_metal_to_multiple(8, ONE_REC, RoundingDirection::Nearest), 6);
+        assert_eq!(round_metal_to_multiple(9, ONE_REC, RoundingDirection::Nearest), 12);
+        assert_eq!(round_metal_to_multiple(-9, ONE_REC, RoundingDirection::Nearest), -6);
+        assert_eq!(round_metal_to_multiple(7, ONE_REC, RoundingDirection::Up), 12);
+        assert_eq!(round_metal_to_multiple(-7, ONE_REC, RoundingDirection::Up), -6);
+        assert_eq!(round_metal_to_multiple(11, ONE_REC, RoundingDirection::Down), 6);
+        assert_eq!(round_metal_to_multiple(-7, ONE_REC, RoundingDirection::Down), -12);
+        assert_eq!(round_metal_to_multiple(-11, ONE_REC, RoundingDirection::TowardZero), -6);
+        assert_eq!(round_metal_to_multiple(-7, ONE_REC, RoundingDirection::AwayFromZero), -12);
+        assert_eq!(round_metal_to_multiple(12, ONE_REC, RoundingDirection::Up), 12);
+    }
+    
+    #[test]
+    fn rounds_metal_to_multiple_edge_cases() {
+        assert_eq!(round_metal_to_multiple(7, 0, RoundingDirection::Up), 7);
+        assert_eq!(round_metal_to_multiple(7, -6, RoundingDirection::Up), 7);
+        assert_eq!(round_metal_to_multiple(Currency::MAX, ONE_REF, RoundingDirection::Up), Currency::MAX);
+        assert_eq!(round_metal_to_multiple(Currency::MIN, ONE_REF, RoundingDirection::Down), Currency::MIN);
+    }
+    
+    #[test]
+    fn rounds_metal_to_scrap_multiple_matches_rounding() {
+        for metal in -100..100 {
+            assert_eq!(
+                round_metal_to_multiple(metal, ONE_SCRAP, RoundingDirection::Up),
+                round_metal(metal, &Rounding::UpScrap),
+            );
+            assert_eq!(
+                round_metal_to_multiple(metal, ONE_REF, RoundingDirection::AwayFromZero),
+                round_metal(metal, &Rounding::AwayFromZeroRefined),
+            );
+        }
+    }
+    
+    #[test]
+    fn rounds_metal_to_endings() {
+        let endings = [scrap!(3), scrap!(5), scrap!(8)];
+        
+        assert_eq!(round_metal_to_endings(ONE_REF * 5, &endings, RoundingDirection::Down), ONE_REF * 4 + scrap!(8));
+        assert_eq!(round_metal_to_endings(ONE_REF * 5, &endings, RoundingDirection::Up), ONE_REF * 5 + scrap!(3));
+        assert_eq!(round_metal_to_endings(ONE_REF * 5, &endings, RoundingDirection::Nearest), ONE_REF * 4 + scrap!(8));
+        assert_eq!(round_metal_to_endings(ONE_REF * 5 + scrap!(5), &endings, RoundingDirection::Up), ONE_REF * 5 + scrap!(5));
+        assert_eq!(round_metal_to_endings(ONE_REF * 5 + scrap!(4), &endings, RoundingDirection::Nearest), ONE_REF * 5 + scrap!(5));
+        assert_eq!(round_metal_to_endings(ONE_REF * 5 + scrap!(4), &endings, RoundingDirection::Down), ONE_REF * 5 + scrap!(3));
+    }
+    
+    #[test]
+    fn rounds_negative_metal_to_endings() {
+        let endings = [scrap!(3), scrap!(5)];
+        // -5.44 ref, half-way between -5.33 and -5.55 ref
+        let metal = -(ONE_REF * 5 + scrap!(4));
+        
+        assert_eq!(round_metal_to_endings(metal, &endings, RoundingDirection::Nearest), -(ONE_REF * 5 + scrap!(3)));
+        assert_eq!(round_metal_to_endings(metal, &endings, RoundingDirection::Up), -(ONE_REF * 5 + scrap!(3)));
+        assert_eq!(round_metal_to_endings(metal, &endings, RoundingDirection::Down), -(ONE_REF * 5 + scrap!(5)));
+        assert_eq!(round_metal_to_endings(metal, &endings, RoundingDirection::AwayFromZero), -(ONE_REF * 5 + scrap!(5)));
+        assert_eq!(round_metal_to_endings(-metal, &endings, RoundingDirection::Nearest), ONE_REF * 5 + scrap!(5));
+    }
+    
+    #[test]
+    fn rounds_metal_to_endings_edge_cases() {
+        assert_eq!(round_metal_to_endings(7, &[], RoundingDirection::Up), 7);
+        // endings are taken modulo one refined
+        assert_eq!(round_metal_to_endings(7, &[ONE_REF + scrap!(5)], RoundingDirection::Up), scrap!(5));
+        // no ending between zero and 0.11 ref
+        assert_eq!(round_metal_to_endings(scrap!(1), &[scrap!(3)], RoundingDirection::Down), scrap!(3));
+        assert_eq!(round_metal_to_endings(Currency::MAX, &[scrap!(3)], RoundingDirection::Up), Currency::MAX);
+        assert_eq!(round_metal_to_endings(Currency::MIN, &[scrap!(3)], RoundingDirection::Down), Currency::MIN);
+    }
+}
@@ -0,0 +1,453 @@
+use crate::helpers;
+use crate::types::Currency;
+use crate::{Currencies, RoundMetal};
+use core::ops::{Bound, RangeBounds};
+use core::time::Duration;
+use serde::{Serialize, Deserialize};
+use alloc::vec::Vec;
+
+/// A price at a point in time. Timestamps are Unix timestamps in seconds. With the `chrono` or 
+/// `time` features enabled, snapshots can be created from and converted to their date types.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, PriceSnapshot};
+/// 
+/// let snapshot = PriceSnapshot::new(Currencies { keys: 1, metal: 0 }, 1672531200);
+/// 
+/// assert_eq!(snapshot.timestamp, 1672531200);
+/// ```
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct PriceSnapshot {
+    /// The price.
+    pub price: Currencies,
+    /// The time of the price as a Unix timestamp in seconds.
+    #[cfg_attr(feature = "ts-rs", ts(type = "number"))]
+    pub timestamp: i64,
+}
+
+impl PriceSnapshot {
+    /// Creates a new [`PriceSnapshot`].
+    pub fn new(price: Currencies, timestamp: i64) -> Self {
+        Self {
+            price,
+            timestamp,
+        }
+    }
+    
+    /// Creates a new [`PriceSnapshot`] from a [`chrono::DateTime`].
+    #[cfg(feature = "chrono")]
+    pub fn from_datetime<Tz: chrono::TimeZone>(
+        price: Currencies,
+        datetime: &chrono::DateTime<Tz>,
+    ) -> Self {
+        Self::new(price, datetime.timestamp())
+    }
+    
+    /// The time of the price as a [`chrono::DateTime`]. `None` if the timestamp is out of range.
+    #[cfg(feature = "chrono")]
+    pub fn datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        chrono::DateTime::from_timestamp(self.timestamp, 0)
+    }
+    
+    /// Creates a new [`PriceSnapshot`] from a [`time::OffsetDateTime`].
+    #[cfg(feature = "time")]
+    pub fn from_offset_datetime(price: Currencies, datetime: &time::OffsetDateTime) -> Self {
+        Self::new(price, datetime.unix_timestamp())
+    }
+    
+    /// The time of the price as a [`time::OffsetDateTime`]. `None` if the timestamp is out of 
+    /// range.
+    #[cfg(feature = "time")]
+    pub fn offset_datetime(&self) -> Option<time::OffsetDateTime> {
+        time::OffsetDateTime::from_unix_timestamp(self.timestamp).ok()
+    }
+}
+
+/// A series of [`PriceSnapshot`] ordered by timestamp. Serializes as an array of snapshots.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, PriceHistory, PriceSnapshot};
+/// 
+/// let mut history = PriceHistory::new();
+/// 
+/// history.push(PriceSnapshot::new(Currencies { keys: 2, metal: 0 }, 200));
+/// history.push(PriceSnapshot::new(Currencies { keys: 1, metal: 0 }, 100));
+/// history.push(PriceSnapshot::new(Currencies { keys: 3, metal: 0 }, 300));
+/// 
+/// assert_eq!(history.len(), 3);
+/// assert_eq!(history.snapshots()[0].timestamp, 100);
+/// assert_eq!(history.range(150..).len(), 2);
+/// ```
+#[derive(Debug, Serialize, Deserialize, Default, Eq, PartialEq, Hash, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[serde(from = "Vec<PriceSnapshot>", into = "Vec<PriceSnapshot>")]
+#[cfg_attr(feature = "ts-rs", ts(type = "Array<PriceSnapshot>"))]
+pub struct PriceHistory {
+    snapshots: Vec<PriceSnapshot>,
+}
+
+impl PriceHistory {
+    /// Creates a new empty [`PriceHistory`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Adds a snapshot, keeping the history ordered by timestamp. Snapshots with the same 
+    /// timestamp are kept in the order they were added.
+    pub fn push(&mut self, snapshot: PriceSnapshot) {
+        let index = self.snapshots
+            .partition_point(|other| other.timestamp <= snapshot.timestamp);
+        
+        self.snapshots.insert(index, snapshot);
+    }
+    
+    /// The snapshots ordered by timestamp.
+    pub fn snapshots(&self) -> &[PriceSnapshot] {
+        &self.snapshots
+    }
+    
+    /// The snapshots with timestamps within `range`.
+    pub fn range<R>(&self, range: R) -> &[PriceSnapshot]
+    where
+        R: RangeBounds<i64>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(start) => self.snapshots.partition_point(|s| s.timestamp < *start),
+            Bound::Excluded(start) => self.snapshots.partition_point(|s| s.timestamp <= *start),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => self.snapshots.partition_point(|s| s.timestamp <= *end),
+            Bound::Excluded(end) => self.snapshots.partition_point(|s| s.timestamp < *end),
+            Bound::Unbounded => self.snapshots.len(),
+        };
+        
+        self.snapshots.get(start..end).unwrap_or_default()
+    }
+    
+    /// The most recent snapshot.
+    pub fn latest(&self) -> Option<&PriceSnapshot> {
+        self.snapshots.last()
+    }
+    
+    /// The price at `timestamp`. Prices between two snapshots are interpolated linearly by value 
+    /// using the given key price (represented as weapons) and rounded using the given rounding 
+    /// method. Prices after the latest snapshot are the latest price. `None` if `timestamp` is 
+    /// before the first snapshot.
+    /// 
+    /// When multiple snapshots share `timestamp`, the most recently added is used.
+    /// 
+    /// # Panics
+    /// Panics if `key_price` is `0` and a price is interpolated, as with 
+    /// [`Currencies::from_metal`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, PriceHistory, PriceSnapshot, Rounding, refined};
+    /// 
+    /// let key_price = refined!(50);
+    /// let history = PriceHistory::from(vec![
+    ///     PriceSnapshot::new(Currencies { keys: 0, metal: refined!(40) }, 100),
+    ///     PriceSnapshot::new(Currencies { keys: 1, metal: 0 }, 200),
+    /// ]);
+    /// 
+    /// assert_eq!(
+    ///     history.at(150, key_price, &Rounding::Refined),
+    ///     Some(Currencies { keys: 0, metal: refined!(45) }),
+    /// );
+    /// assert_eq!(history.at(50, key_price, &Rounding::Refined), None);
+    /// ```
+    pub fn at<R: RoundMetal + ?Sized>(
+        &self,
+        timestamp: i64,
+        key_price: Currency,
+        rounding: &R,
+    ) -> Option<Currencies> {
+        let index = self.snapshots.partition_point(|s| s.timestamp <= timestamp);
+        let before = self.snapshots.get(index.checked_sub(1)?)?;
+        
+        if before.timestamp == timestamp {
+            return Some(before.price);
+        }
+        
+        let Some(after) = self.snapshots.get(index) else {
+            return Some(before.price);
+        };
+        let elapsed = timestamp as i128 - before.timestamp as i128;
+        let span = after.timestamp as i128 - before.timestamp as i128;
+        let t = elapsed as f64 / span as f64;
+        
+        Some(before.price.lerp_f64(&after.price, t, key_price, rounding))
+    }
+    
+    /// The change in value from `duration` before the latest snapshot to the latest snapshot 
+    /// using the given key price (represented as weapons). See [`PriceHistory::at`]. This method 
+    /// is saturating.
+    /// 
+    /// # Panics
+    /// Panics if `key_price` is `0`, as with [`Currencies::from_metal`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, PriceHistory, PriceSnapshot, Rounding, refined};
+    /// use std::time::Duration;
+    /// 
+    /// let key_price = refined!(50);
+    /// let week = Duration::from_secs(60 * 60 * 24 * 7);
+    /// let history = PriceHistory::from(vec![
+    ///     PriceSnapshot::new(Currencies { keys: 0, metal: refined!(40) }, 0),
+    ///     PriceSnapshot::new(Currencies { keys: 1, metal: 0 }, week.as_secs() as i64),
+    /// ]);
+    /// 
+    /// assert_eq!(
+    ///     history.change_since(week, key_price, &Rounding::Refined),
+    ///     Some(Currencies { keys: 0, metal: refined!(10) }),
+    /// );
+    /// assert_eq!(history.percent_change(week, key_price, &Rounding::Refined), Some(25.0));
+    /// ```
+    pub fn change_since<R: RoundMetal + ?Sized>(
+        &self,
+        duration: Duration,
+        key_price: Currency,
+        rounding: &R,
+    ) -> Option<Currencies> {
+        let (previous, latest) = self.prices_since(duration, key_price, rounding)?;
+        let change = helpers::to_metal_wide(latest.metal, latest.keys, key_price) -
+            helpers::to_metal_wide(previous.metal, previous.keys, key_price);
+        let metal = change.clamp(Currency::MIN as i128, Currency::MAX as i128) as Currency;
+        
+        Some(Currencies::from_metal(metal, key_price))
+    }
+    
+    /// The percentage change in value from `duration` before the latest snapshot to the latest 
+    /// snapshot using the given key price (represented as weapons). See 
+    /// [`Currencies::percent_difference`].
+    /// 
+    /// # Panics
+    /// Panics if `key_price` is `0` and a price is interpolated, as with 
+    /// [`Currencies::from_metal`].
+    pub fn percent_change<R: RoundMetal + ?Sized>(
+        &self,
+        duration: Duration,
+        key_price: Currency,
+        rounding: &R,
+    ) -> Option<f32> {
+        let (previous, latest) = self.prices_since(duration, key_price, rounding)?;
+        
+        Some(previous.percent_difference(&latest, key_price))
+    }
+    
+    /// The price `duration` before the latest snapshot along with the latest price.
+    fn prices_since<R: RoundMetal + ?Sized>(
+        &self,
+        duration: Duration,
+        key_price: Currency,
+        rounding: &R,
+    ) -> Option<(Currencies, Currencies)> {
+        let latest = self.latest()?;
+        let seconds = i64::try_from(duration.as_secs()).unwrap_or(i64::MAX);
+        let previous = self.at(latest.timestamp.saturating_sub(seconds), key_price, rounding)?;
+        
+        Some((previous, latest.price))
+    }
+    
+    /// The number of snapshots.
+    pub fn len(&self) -> usize {
+        self.snapshots.len()
+    }
+    
+    /// Checks if there are no snapshots.
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+    
+    /// Iterates over the snapshots ordered by timestamp.
+    pub fn iter(&self) -> core::slice::Iter<'_, PriceSnapshot> {
+        self.snapshots.iter()
+    }
+}
+
+impl From<Vec<PriceSnapshot>> for PriceHistory {
+    fn from(mut snapshots: Vec<PriceSnapshot>) -> Self {
+        snapshots.sort_by_key(|snapshot| snapshot.timestamp);
+        
+        Self {
+            snapshots,
+        }
+    }
+}
+
+impl From<PriceHistory> for Vec<PriceSnapshot> {
+    fn from(history: PriceHistory) -> Self {
+        history.snapshots
+    }
+}
+
+impl FromIterator<PriceSnapshot> for PriceHistory {
+    fn from_iter<I: IntoIterator<Item = PriceSnapshot>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+impl Extend<PriceSnapshot> for PriceHistory {
+    fn extend<I: IntoIterator<Item = PriceSnapshot>>(&mut self, iter: I) {
+        for snapshot in iter {
+            self.push(snapshot);
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a PriceHistory {
+    type Item = &'a PriceSnapshot;
+    type IntoIter = core::slice::Iter<'a, PriceSnapshot>;
+    
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rounding;
+    
+    fn snapshot(keys: i64, timestamp: i64) -> PriceSnapshot {
+        PriceSnapshot::new(Currencies { keys, metal: 0 }, timestamp)
+    }
+    
+    #[test]
+    fn keeps_snapshots_ordered() {
+        let history = [snapshot(3, 30), snapshot(1, 10), snapshot(2, 20), snapshot(4, 20)]
+            .into_iter()
+            .collect::<PriceHistory>();
+        let keys = history.iter().map(|s| s.price.keys).collect::<Vec<_>>();
+        
+        assert_eq!(keys, [1, 2, 4, 3]);
+    }
+    
+    #[test]
+    fn queries_range() {
+        let history = PriceHistory::from(vec![snapshot(1, 10), snapshot(2, 20), snapshot(3, 30)]);
+        
+        assert_eq!(history.range(10..30), &[snapshot(1, 10), snapshot(2, 20)]);
+        assert_eq!(history.range(..=20).len(), 2);
+        assert_eq!(history.range((Bound::Excluded(10), Bound::Unbounded)).len(), 2);
+        assert!(history.range(40..).is_empty());
+        assert!(history.range((Bound::Included(30), Bound::Excluded(10))).is_empty());
+    }
+    
+    #[test]
+    fn gets_latest() {
+        let history = PriceHistory::from(vec![snapshot(2, 20), snapshot(1, 10)]);
+        
+        assert_eq!(history.latest(), Some(&snapshot(2, 20)));
+        assert_eq!(PriceHistory::new().latest(), None);
+    }
+    
+    #[test]
+    fn gets_price_at_timestamp() {
+        let key_price = 100;
+        let history = PriceHistory::from(vec![snapshot(1, 10), snapshot(2, 20)]);
+        
+        assert_eq!(history.at(10, key_price, &Rounding::None), Some(Currencies { keys: 1, metal: 0 }));
+        assert_eq!(history.at(20, key_price, &Rounding::None), Some(Currencies { keys: 2, metal: 0 }));
+        assert_eq!(history.at(25, key_price, &Rounding::None), Some(Currencies { keys: 2, metal: 0 }));
+        assert_eq!(history.at(12, key_price, &Rounding::None), Some(Currencies { keys: 1, metal: 20 }));
+        assert_eq!(history.at(9, key_price, &Rounding::None), None);
+    }
+    
+    #[test]
+    fn gets_price_at_extreme_timestamps() {
+        let key_price = 100;
+        let history = PriceHistory::from(vec![snapshot(1, i64::MIN), snapshot(3, i64::MAX)]);
+        
+        assert_eq!(history.at(0, key_price, &Rounding::None), Some(Currencies { keys: 2, metal: 0 }));
+        assert_eq!(history.at(i64::MAX - 1, key_price, &Rounding::None), Some(Currencies { keys: 3, metal: 0 }));
+    }
+    
+    #[test]
+    fn gets_price_at_timestamp_over_long_span() {
+        let span = 100_000_000;
+        let history = PriceHistory::from(vec![
+            PriceSnapshot::new(Currencies { keys: 0, metal: 0 }, 0),
+            PriceSnapshot::new(Currencies { keys: 0, metal: span }, span),
+        ]);
+        
+        assert_eq!(
+            history.at(span - 1, Currency::MAX, &Rounding::None),
+            Some(Currencies { keys: 0, metal: span - 1 }),
+        );
+    }
+    
+    #[test]
+    fn change_since_before_history() {
+        let history = PriceHistory::from(vec![snapshot(1, 10), snapshot(2, 20)]);
+        
+        assert_eq!(history.change_since(Duration::from_secs(20), 100, &Rounding::None), None);
+        assert_eq!(history.change_since(Duration::MAX, 100, &Rounding::None), None);
+    }
+    
+    #[test]
+    fn change_since_decrease() {
+        let history = PriceHistory::from(vec![snapshot(5, 10), snapshot(4, 20)]);
+        
+        assert_eq!(history.change_since(Duration::from_secs(10), 100, &Rounding::None), Some(Currencies {
+            keys: -1,
+            metal: 0,
+        }));
+        assert_eq!(history.percent_change(Duration::from_secs(10), 100, &Rounding::None), Some(-20.0));
+    }
+    
+    #[test]
+    fn serializes_as_array() {
+        let history = PriceHistory::from(vec![snapshot(1, 10)]);
+        
+        assert_eq!(
+            serde_json::to_string(&history).unwrap(),
+            r#"[{"price":{"keys":1},"timestamp":10}]"#,
+        );
+    }
+    
+    #[test]
+    fn deserializes_unordered() {
+        let history: PriceHistory = serde_json::from_str(
+            r#"[{"price":{"keys":2},"timestamp":20},{"price":{"keys":1},"timestamp":10}]"#,
+        ).unwrap();
+        
+        assert_eq!(history.snapshots(), &[snapshot(1, 10), snapshot(2, 20)]);
+    }
+    
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn converts_chrono_datetime() {
+        let datetime = chrono::DateTime::from_timestamp(1672531200, 0).unwrap();
+        let snapshot = PriceSnapshot::from_datetime(Currencies { keys: 1, metal: 0 }, &datetime);
+        
+        assert_eq!(snapshot.timestamp, 1672531200);
+        assert_eq!(snapshot.datetime(), Some(datetime));
+    }
+    
+    #[cfg(feature = "time")]
+    #[test]
+    fn converts_time_offset_datetime() {
+        let datetime = time::OffsetDateTime::from_unix_timestamp(1672531200).unwrap();
+        let snapshot = PriceSnapshot::from_offset_datetime(Currencies { keys: 1, metal: 0 }, &datetime);
+        
+        assert_eq!(snapshot.timestamp, 1672531200);
+        assert_eq!(snapshot.offset_datetime(), Some(datetime));
+    }
+    
+    #[cfg(feature = "ts-rs")]
+    #[test]
+    fn generates_typescript_declaration() {
+        use ts_rs::TS;
+        
+        assert_eq!(PriceHistory::decl(), "type PriceHistory = Array<PriceSnapshot>;");
+        assert!(PriceSnapshot::decl().contains("timestamp: number"));
+    }
+}
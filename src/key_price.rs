@@ -0,0 +1,222 @@
+use crate::helpers;
+use crate::types::Currency;
+use crate::error::InvalidKeyPriceError;
+use serde::{Serialize, Deserialize, Serializer, Deserializer};
+use serde::de::Error;
+
+/// The buy and sell price of a key (represented as weapons). Both prices are positive and the 
+/// sell price is always greater than or equal to the buy price.
+/// 
+/// Serializes with both prices as refined floats, e.g. `{"buy":50.11,"sell":50.22}`.
+/// 
+/// [`Converter::from_key_price`](crate::Converter::from_key_price) and 
+/// [`Pricing::with_key_price`](crate::Pricing::with_key_price) choose the side to use. Other 
+/// functions which take a key price (represented as weapons) can be given either side using 
+/// [`KeyPrice::price`].
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, KeyPrice, Side, refined, scrap};
+/// 
+/// let key_price = KeyPrice::new(refined!(50), refined!(50) + scrap!(2)).unwrap();
+/// let currencies = Currencies { keys: 1, metal: 0 };
+/// 
+/// assert_eq!(key_price.mid(), refined!(50) + scrap!(1));
+/// assert_eq!(currencies.to_metal(key_price.price(Side::Sell)), refined!(50) + scrap!(2));
+/// assert!(KeyPrice::new(refined!(51), refined!(50)).is_err());
+/// assert!(KeyPrice::new(0, refined!(50)).is_err());
+/// ```
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[serde(remote = "Self")]
+pub struct KeyPrice {
+    /// The price keys are bought at.
+    #[serde(with = "helpers::metal")]
+    #[cfg_attr(feature = "schemars", schemars(with = "f64"))]
+    #[cfg_attr(feature = "ts-rs", ts(type = "number"))]
+    pub buy: Currency,
+    /// The price keys are sold at.
+    #[serde(with = "helpers::metal")]
+    #[cfg_attr(feature = "schemars", schemars(with = "f64"))]
+    #[cfg_attr(feature = "ts-rs", ts(type = "number"))]
+    pub sell: Currency,
+}
+
+/// A side of a trade, used to choose between the buy and sell price of a [`KeyPrice`].
+/// 
+/// Sides are written in lowercase for serde, e.g. "buy".
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[serde(rename_all = "lowercase")]
+#[cfg_attr(feature = "ts-rs", ts(rename_all = "lowercase"))]
+pub enum Side {
+    /// Buying, which uses the buy price.
+    Buy,
+    /// Selling, which uses the sell price.
+    Sell,
+}
+
+impl KeyPrice {
+    /// Creates a new [`KeyPrice`]. Errors if either price is not positive or `sell` is less than 
+    /// `buy`.
+    pub fn new(buy: Currency, sell: Currency) -> Result<Self, InvalidKeyPriceError> {
+        if buy <= 0 || sell <= 0 {
+            return Err(InvalidKeyPriceError::NotPositive {
+                buy,
+                sell,
+            });
+        }
+        
+        if sell < buy {
+            return Err(InvalidKeyPriceError::SellLessThanBuy {
+                buy,
+                sell,
+            });
+        }
+        
+        Ok(Self {
+            buy,
+            sell,
+        })
+    }
+    
+    /// The midpoint between the buy and sell price, rounded down to the nearest weapon.
+    pub fn mid(&self) -> Currency {
+        // The fields are public so the prices may be anything; widening cannot overflow and the 
+        // result is always between the two prices.
+        (self.buy as i128 + self.sell as i128).div_euclid(2) as Currency
+    }
+    
+    /// The price for the given side.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{KeyPrice, Side, refined};
+    /// 
+    /// let key_price = KeyPrice::new(refined!(50), refined!(51)).unwrap();
+    /// 
+    /// assert_eq!(key_price.price(Side::Buy), refined!(50));
+    /// assert_eq!(key_price.price(Side::Sell), refined!(51));
+    /// ```
+    pub fn price(&self, side: Side) -> Currency {
+        match side {
+            Side::Buy => self.buy,
+            Side::Sell => self.sell,
+        }
+    }
+}
+
+impl From<Currency> for KeyPrice {
+    /// Creates a [`KeyPrice`] where the buy and sell price are the same.
+    fn from(price: Currency) -> Self {
+        Self {
+            buy: price,
+            sell: price,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for KeyPrice {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let key_price = Self::deserialize(deserializer)?;
+        
+        Self::new(key_price.buy, key_price.sell).map_err(D::Error::custom)
+    }
+}
+
+impl Serialize for KeyPrice {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Self::serialize(self, serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{refined, scrap};
+    
+    #[test]
+    fn creates_key_price() {
+        assert_eq!(KeyPrice::new(refined!(50), refined!(51)), Ok(KeyPrice {
+            buy: refined!(50),
+            sell: refined!(51),
+        }));
+    }
+    
+    #[test]
+    fn rejects_sell_less_than_buy() {
+        assert_eq!(KeyPrice::new(refined!(51), refined!(50)), Err(InvalidKeyPriceError::SellLessThanBuy {
+            buy: refined!(51),
+            sell: refined!(50),
+        }));
+    }
+    
+    #[test]
+    fn rejects_non_positive_prices() {
+        assert_eq!(KeyPrice::new(0, refined!(50)), Err(InvalidKeyPriceError::NotPositive {
+            buy: 0,
+            sell: refined!(50),
+        }));
+        assert!(KeyPrice::new(Currency::MIN, Currency::MAX).is_err());
+        assert!(serde_json::from_str::<KeyPrice>(r#"{"buy":0,"sell":0}"#).is_err());
+    }
+    
+    #[test]
+    fn mid_rounds_down() {
+        assert_eq!(KeyPrice::new(refined!(50), refined!(50) + 1).unwrap().mid(), refined!(50));
+    }
+    
+    #[test]
+    fn mid_does_not_overflow() {
+        assert_eq!(KeyPrice::new(Currency::MAX - 2, Currency::MAX).unwrap().mid(), Currency::MAX - 1);
+        assert_eq!(KeyPrice { buy: Currency::MIN, sell: Currency::MAX }.mid(), -1);
+    }
+    
+    #[test]
+    fn serializes_key_price() {
+        let key_price = KeyPrice::new(refined!(50) + scrap!(1), refined!(50) + scrap!(2)).unwrap();
+        
+        assert_eq!(serde_json::to_string(&key_price).unwrap(), r#"{"buy":50.11,"sell":50.22}"#);
+    }
+    
+    #[test]
+    fn deserializes_key_price() {
+        let key_price: KeyPrice = serde_json::from_str(r#"{"buy":50.11,"sell":50.22}"#).unwrap();
+        
+        assert_eq!(key_price, KeyPrice {
+            buy: refined!(50) + scrap!(1),
+            sell: refined!(50) + scrap!(2),
+        });
+    }
+    
+    #[test]
+    fn deserialize_rejects_invalid_key_price() {
+        assert!(serde_json::from_str::<KeyPrice>(r#"{"buy":51,"sell":50}"#).is_err());
+    }
+    
+    #[test]
+    fn serializes_bincode() {
+        let key_price = KeyPrice::new(refined!(50), refined!(50) + scrap!(2) + 1).unwrap();
+        let bytes = bincode::serialize(&key_price).unwrap();
+        
+        assert_eq!(bincode::deserialize::<KeyPrice>(&bytes).unwrap(), key_price);
+        
+        let bytes = bincode::serialize(&(refined!(51), refined!(50))).unwrap();
+        
+        assert!(bincode::deserialize::<KeyPrice>(&bytes).is_err());
+    }
+    
+    #[test]
+    fn serializes_side_lowercase() {
+        assert_eq!(serde_json::to_string(&Side::Sell).unwrap(), r#""sell""#);
+        assert_eq!(serde_json::from_str::<Side>(r#""buy""#).unwrap(), Side::Buy);
+    }
+}
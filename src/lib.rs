@@ -27,7 +27,7 @@
 //! All `metal` values are represented as the number of weapons. For 1 refined, this would be 18. 
 //! The macros and constant values should be used to avoid any errors in accounting. For example: 
 //! if adding one scrap, add `ONE_SCRAP` to the `metal` field. `scrap!(1)` will also create the 
-//! same value.
+//! same value. Prices quoted in craft weapons can be created using `weapon!`.
 //! 
 //! In addition, all key values in methods are represented as values in weapons. If you need to 
 //! use a floating point key price e.g. 70.22, you may use [`helpers::get_metal_from_float`] which 
@@ -37,25 +37,94 @@
 //! [`i64::MAX`] will result in [`i64::MAX`] rather than rolling over. While values are stored as 
 //! 64-bit integers and usually won't overflow if you're using reasonable numbers, if you need to 
 //! check for overflows some checked methods are included.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("either the `std` or `libm` feature must be enabled");
+
+extern crate alloc;
+
 #[macro_use] extern crate impl_ops;
 
+#[cfg(not(feature = "std"))]
+mod float;
 mod helpers;
 mod currencies;
 mod listing_currencies;
 mod rounding;
 mod constants;
 mod usd_currencies;
+mod by_value;
+mod denomination;
+mod key_price;
+mod converter;
+mod fiat;
+mod price_range;
+mod pricing;
+mod history;
+mod parse;
+mod format;
+#[cfg(feature = "sqlx")]
+mod sqlx;
+#[cfg(feature = "diesel")]
+mod diesel;
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+mod arbitrary;
+#[cfg(feature = "approx")]
+mod approx;
 
 pub mod traits;
 pub mod error;
 pub mod types;
+pub mod steam_market;
+pub mod aggregate;
+pub mod backpack_tf;
+pub mod prices_tf;
+pub mod tf2autobot;
+pub mod marketplace_tf;
+pub mod serde;
+#[cfg(feature = "decimal")]
+pub mod decimal;
+#[cfg(feature = "bson")]
+pub mod bson;
+#[cfg(feature = "redis")]
+pub mod redis;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "pyo3")]
+pub mod python;
 
 pub use usd_currencies::USDCurrencies;
 pub use currencies::Currencies;
 pub use listing_currencies::ListingCurrencies;
-pub use rounding::Rounding;
-pub use helpers::{get_metal_from_float, get_metal_float};
-pub use constants::{ONE_REF, ONE_REC, ONE_SCRAP, ONE_WEAPON};
+pub use rounding::{Rounding, RoundingDirection, RoundMetal};
+pub use by_value::ByValue;
+pub use denomination::{Denomination, Stock, Payment};
+pub use key_price::{KeyPrice, Side};
+pub use converter::Converter;
+pub use fiat::{Fiat, FiatCurrency};
+pub use price_range::PriceRange;
+pub use pricing::{Pricing, Profit};
+pub use history::{PriceSnapshot, PriceHistory};
+pub use parse::{ParseOptions, ParseMode, Unit};
+pub use format::{FormatOptions, UnitLabel, SignStyle, CurrenciesDisplay, KeysDisplay};
+pub use helpers::{
+    get_metal_from_float,
+    get_metal_float,
+    get_metal_from_float_f64,
+    get_metal_float_f64,
+    get_metal_hundredths,
+    get_metal_from_hundredths,
+    get_hats_float,
+    get_metal_from_hats_float,
+    compare_values,
+    sort_by_value,
+    round_metal_to_multiple,
+    round_metal_to_endings,
+};
+pub use constants::{ONE_REF, ONE_REC, ONE_SCRAP, ONE_WEAPON, ONE_HAT};
 
 /// Generates value for refined metal.
 #[macro_export]
@@ -85,4 +154,42 @@ macro_rules! scrap {
             $a * 2 as i64
         }
     }
+}
+
+/// Generates value for craft weapons.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, Rounding, weapon, scrap};
+/// 
+/// let currencies = Currencies { keys: 0, metal: weapon!(3) };
+/// 
+/// assert_eq!(currencies.metal, scrap!(1) + weapon!(1));
+/// assert_eq!(currencies.round(&Rounding::UpScrap).metal, scrap!(2));
+/// assert_eq!(currencies.round(&Rounding::DownScrap).metal, scrap!(1));
+/// ```
+#[macro_export]
+macro_rules! weapon {
+    ( $a:expr ) => {
+        {
+            $a * 1 as i64
+        }
+    }
+}
+
+/// Generates value for craft hats. By convention a craft hat is worth `1.33 ref`.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{hat, refined};
+/// 
+/// assert_eq!(hat!(3), refined!(4));
+/// ```
+#[macro_export]
+macro_rules! hat {
+    ( $a:expr ) => {
+        {
+            $a * 24 as i64
+        }
+    }
 }
\ No newline at end of file
@@ -3,10 +3,15 @@ use crate::types::Currency;
 use crate::traits::SerializeCurrencies;
 use crate::error::ParseError;
 use crate::constants::{KEYS_SYMBOL, KEY_SYMBOL, METAL_SYMBOL, EMPTY_SYMBOL};
-use crate::{Currencies, Rounding};
-use std::fmt;
-use std::cmp::{Ord, Ordering};
-use std::ops::{self, AddAssign, SubAssign, MulAssign, DivAssign};
+use crate::parse::{self, ParseOptions};
+use crate::format;
+use crate::{Currencies, RoundMetal};
+#[cfg(not(feature = "std"))]
+use crate::float::Float;
+use core::fmt;
+use core::str::FromStr;
+use core::cmp::{Ord, Ordering};
+use core::ops::{self, AddAssign, SubAssign, MulAssign, DivAssign};
 use serde::{Serialize, Deserialize, Serializer, Deserializer};
 use serde::de::Error;
 use serde::ser::SerializeStruct;
@@ -14,15 +19,20 @@ use serde::ser::SerializeStruct;
 /// The `keys` field for [`ListingCurrencies`] is defined as an [`f32`]. Use this anywhere you may
 /// need key values which include decimal places.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[serde(remote = "Self")]
 pub struct ListingCurrencies {
     /// Amount of keys.
     #[serde(default)]
+    #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>", optional))]
     pub keys: f32,
     /// Amount of metal expressed as weapons. A metal value of 6 would be equivalent to 3 scrap. 
     /// It's recommended to use the `ONE_REF`, `ONE_REC`, `ONE_SCRAP`, and `ONE_WEAPON` constants 
     /// to perform arithmatic.
     #[serde(deserialize_with = "helpers::metal_deserializer", default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "f64"))]
+    #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>", optional))]
     pub metal: Currency,
 }
 
@@ -32,19 +42,20 @@ impl PartialOrd for ListingCurrencies {
     }
 }
 
+/// Currencies are ordered lexicographically by `keys` and then by `metal`. This does not take a 
+/// key price into account. Non-finite key values are ordered using [`f32::total_cmp`] so that 
+/// sorting stays consistent, with `NaN` placed after positive infinity and negative `NaN` before 
+/// negative infinity.
 impl Ord for ListingCurrencies {
     fn cmp(&self, other:&Self) -> Ordering {
-        if self.keys > other.keys {
-            Ordering::Greater
-        } else if self.keys < other.keys {
-            Ordering::Less
-        } else if self.metal > other.metal {
-            Ordering::Greater
-        } else if self.metal < other.metal {
-            Ordering::Less
-        } else {
+        let keys = if self.keys == other.keys {
+            // Keeps `-0.0` and `0.0` equal, matching `PartialEq`.
             Ordering::Equal
-        }
+        } else {
+            self.keys.total_cmp(&other.keys)
+        };
+        
+        keys.then_with(|| self.metal.cmp(&other.metal))
     }
 }
 
@@ -59,12 +70,35 @@ impl Default for ListingCurrencies {
 }
 
 impl ListingCurrencies {
+    /// [`ListingCurrencies`] with `0` keys and `0` metal.
+    pub const ZERO: Self = Self {
+        keys: 0.0,
+        metal: 0,
+    };
+    
     /// Creates a new [`ListingCurrencies`] with `0` keys and `0` metal.
-    pub fn new() -> Self {
-        Self {
-            keys: 0.0,
-            metal: 0,
-        }
+    pub const fn new() -> Self {
+        Self::ZERO
+    }
+    
+    /// Parses currencies from a string using the given options. [`FromStr`] uses the default 
+    /// options.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{ListingCurrencies, ParseOptions};
+    /// 
+    /// let currencies = ListingCurrencies::from_str_with("1.5k", &ParseOptions::lenient()).unwrap();
+    /// 
+    /// assert_eq!(currencies, ListingCurrencies { keys: 1.5, metal: 0 });
+    /// ```
+    pub fn from_str_with(string: &str, options: &ParseOptions) -> Result<Self, ParseError> {
+        let (keys, metal) = parse::parse_currencies::<f32>(string, options)?;
+        
+        Ok(Self {
+            keys,
+            metal,
+        })
     }
     
     /// Checks if the `keys` value is a fractional value.
@@ -72,35 +106,100 @@ impl ListingCurrencies {
         self.keys.fract() != 0.0
     }
     
+    /// Checks if the `keys` value is finite, i.e. neither infinite nor `NaN`. Parsing and 
+    /// deserializing reject non-finite values, but they can still result from arithmetic such as 
+    /// dividing by `0.0`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::ListingCurrencies;
+    /// 
+    /// assert!(ListingCurrencies { keys: 1.5, metal: 0 }.is_finite());
+    /// assert!(!ListingCurrencies { keys: f32::NAN, metal: 0 }.is_finite());
+    /// assert!(!(ListingCurrencies { keys: 1.5, metal: 0 } / 0.0).is_finite());
+    /// ```
+    pub fn is_finite(&self) -> bool {
+        self.keys.is_finite()
+    }
+    
+    /// Checks if both currencies hold the same `metal` value and `keys` values within `epsilon` 
+    /// of each other. Useful for comparing key values computed by different code paths which may 
+    /// differ by float rounding errors.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::ListingCurrencies;
+    /// 
+    /// let currencies = ListingCurrencies { keys: 1.3 - 1.0, metal: 4 };
+    /// 
+    /// assert_ne!(currencies, ListingCurrencies { keys: 0.3, metal: 4 });
+    /// assert!(currencies.eq_approx(&ListingCurrencies { keys: 0.3, metal: 4 }, 1e-6));
+    /// assert!(!currencies.eq_approx(&ListingCurrencies { keys: 0.3, metal: 5 }, 1e-6));
+    /// ```
+    pub fn eq_approx(&self, other: &Self, epsilon: f32) -> bool {
+        self.metal == other.metal && (self.keys - other.keys).abs() <= epsilon
+    }
+    
     /// Converts currencies to a metal value using the given key price (represented as weapons).
     /// Rounds float conversions and saturates at integer bounds.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{ListingCurrencies, refined};
+    /// 
+    /// let key_price = refined!(50);
+    /// let currencies = ListingCurrencies { keys: 1.5, metal: refined!(10) };
+    /// 
+    /// assert_eq!(currencies.to_metal(key_price), refined!(85));
+    /// ```
     pub fn to_metal(&self, key_price: Currency) -> Currency {
-        self.metal.saturating_add((self.keys * key_price as f32).round() as Currency)
+        self.metal.saturating_add((self.keys as f64 * key_price as f64).round() as Currency)
     }
     
     /// Converts currencies to a metal value using the given key price (represented as weapons).
     /// In cases where the result overflows or underflows beyond the limit for [`i64`], `None` is 
-    /// returned.
+    /// returned. `None` is also returned if `keys` is not a finite number.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{ListingCurrencies, refined};
+    /// 
+    /// let key_price = refined!(50);
+    /// 
+    /// assert_eq!(
+    ///     ListingCurrencies { keys: 1.5, metal: refined!(10) }.checked_to_metal(key_price),
+    ///     Some(refined!(85)),
+    /// );
+    /// assert!(ListingCurrencies { keys: 1e20, metal: 0 }.checked_to_metal(key_price).is_none());
+    /// ```
     pub fn checked_to_metal(&self, key_price: Currency) -> Option<Currency> {
-        let result = (self.keys * key_price as f32).round();
-        let result_metal = result as Currency;
+        let result = (self.keys as f64 * key_price as f64).round();
         
-        // Check for overflow by seeing if conversions produce unequal results
-        if result != result_metal as f32 {
+        // Casting to an integer saturates and NaN becomes 0 so the bounds must be checked first.
+        // i64::MAX as f64 rounds up to 2^63 which is already out of bounds.
+        if !(result >= Currency::MIN as f64 && result < Currency::MAX as f64) {
             return None;
         }
         
-        self.metal.checked_add(result_metal)
+        self.metal.checked_add(result as Currency)
     }
     
     /// Checks if the currencies do contain any value.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::ListingCurrencies;
+    /// 
+    /// assert!(ListingCurrencies { keys: 0.0, metal: 0 }.is_empty());
+    /// assert!(ListingCurrencies::ZERO.is_empty());
+    /// ```
     pub fn is_empty(&self) -> bool {
         self.keys == 0.0 && self.metal == 0
     }
     
     /// Rounds the metal value using the given rounding method.
-    pub fn round(mut self, rounding: &Rounding) -> Self {
-        self.metal = helpers::round_metal(self.metal, rounding);
+    pub fn round<R: RoundMetal + ?Sized>(mut self, rounding: &R) -> Self {
+        self.metal = rounding.round_metal(self.metal);
         self
     }
     
@@ -121,6 +220,143 @@ impl ListingCurrencies {
     pub fn can_afford(&self, other: &Self) -> bool {
         self.keys >= other.keys && self.metal >= other.metal
     }
+    
+    /// Saturating addition. Adds currencies, saturating the `metal` value at the numeric bounds 
+    /// instead of overflowing. `keys` are added as floats. This is equivalent to using the `+` 
+    /// operator.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::ListingCurrencies;
+    /// 
+    /// let currencies = ListingCurrencies { keys: 1.5, metal: i64::MAX };
+    /// 
+    /// assert_eq!(
+    ///     currencies.saturating_add(&ListingCurrencies { keys: 1.0, metal: 5 }),
+    ///     ListingCurrencies { keys: 2.5, metal: i64::MAX },
+    /// );
+    /// ```
+    pub fn saturating_add(&self, other: &Self) -> Self {
+        Self {
+            keys: self.keys + other.keys,
+            metal: self.metal.saturating_add(other.metal),
+        }
+    }
+    
+    /// Saturating subtraction. Subtracts currencies, saturating the `metal` value at the numeric 
+    /// bounds instead of overflowing. `keys` are subtracted as floats. This is equivalent to using 
+    /// the `-` operator.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::ListingCurrencies;
+    /// 
+    /// let currencies = ListingCurrencies { keys: 1.5, metal: i64::MIN };
+    /// 
+    /// assert_eq!(
+    ///     currencies.saturating_sub(&ListingCurrencies { keys: 1.0, metal: 5 }),
+    ///     ListingCurrencies { keys: 0.5, metal: i64::MIN },
+    /// );
+    /// ```
+    pub fn saturating_sub(&self, other: &Self) -> Self {
+        Self {
+            keys: self.keys - other.keys,
+            metal: self.metal.saturating_sub(other.metal),
+        }
+    }
+    
+    /// Saturating integer multiplication. Computes `self * rhs` for each field, saturating the 
+    /// `metal` value at the numeric bounds instead of overflowing. This is equivalent to using the 
+    /// `*` operator.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::ListingCurrencies;
+    /// 
+    /// let currencies = ListingCurrencies { keys: 1.5, metal: 10 };
+    /// 
+    /// assert_eq!(
+    ///     currencies.saturating_mul(i64::MAX),
+    ///     ListingCurrencies { keys: 1.5 * i64::MAX as f32, metal: i64::MAX },
+    /// );
+    /// ```
+    pub fn saturating_mul(&self, rhs: Currency) -> Self {
+        Self {
+            keys: self.keys * rhs as f32,
+            metal: self.metal.saturating_mul(rhs),
+        }
+    }
+    
+    /// Adds currencies. `None` if the `metal` value overflows integer bounds or the `keys` value 
+    /// is not finite.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::ListingCurrencies;
+    /// 
+    /// let currencies = ListingCurrencies { keys: 1.5, metal: 10 };
+    /// 
+    /// assert_eq!(
+    ///     currencies.checked_add(&ListingCurrencies { keys: 1.0, metal: 5 }),
+    ///     Some(ListingCurrencies { keys: 2.5, metal: 15 }),
+    /// );
+    /// assert_eq!(currencies.checked_add(&ListingCurrencies { keys: f32::NAN, metal: 0 }), None);
+    /// ```
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        Self::finite(self.keys + other.keys, self.metal.checked_add(other.metal)?)
+    }
+    
+    /// Subtracts currencies. `None` if the `metal` value overflows integer bounds or the `keys` 
+    /// value is not finite.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::ListingCurrencies;
+    /// 
+    /// let currencies = ListingCurrencies { keys: 1.5, metal: i64::MIN };
+    /// 
+    /// assert_eq!(currencies.checked_sub(&ListingCurrencies { keys: 1.0, metal: 5 }), None);
+    /// ```
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Self::finite(self.keys - other.keys, self.metal.checked_sub(other.metal)?)
+    }
+    
+    /// Checked integer multiplication. `None` if the `metal` value overflows integer bounds or the 
+    /// `keys` value is not finite.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::ListingCurrencies;
+    /// 
+    /// let currencies = ListingCurrencies { keys: 1.5, metal: 10 };
+    /// 
+    /// assert_eq!(currencies.checked_mul(2), Some(ListingCurrencies { keys: 3.0, metal: 20 }));
+    /// assert_eq!(currencies.checked_mul(i64::MAX), None);
+    /// ```
+    pub fn checked_mul(&self, rhs: Currency) -> Option<Self> {
+        Self::finite(self.keys * rhs as f32, self.metal.checked_mul(rhs)?)
+    }
+    
+    /// Checked integer division. `None` if `rhs == 0`, the division results in overflow or the 
+    /// `keys` value is not finite.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::ListingCurrencies;
+    /// 
+    /// let currencies = ListingCurrencies { keys: 1.5, metal: 10 };
+    /// 
+    /// assert_eq!(currencies.checked_div(2), Some(ListingCurrencies { keys: 0.75, metal: 5 }));
+    /// assert_eq!(currencies.checked_div(0), None);
+    /// ```
+    pub fn checked_div(&self, rhs: Currency) -> Option<Self> {
+        Self::finite(self.keys / rhs as f32, self.metal.checked_div(rhs)?)
+    }
+    
+    /// Creates currencies if `keys` is finite.
+    fn finite(keys: f32, metal: Currency) -> Option<Self> {
+        keys.is_finite().then_some(Self { keys, metal })
+    }
 }
 
 impl PartialEq<Currencies> for ListingCurrencies {
@@ -131,6 +367,13 @@ impl PartialEq<Currencies> for ListingCurrencies {
     }
 }
 
+impl_op_ex!(- |a: &ListingCurrencies| -> ListingCurrencies { 
+    ListingCurrencies {
+        keys: -a.keys,
+        metal: a.metal.saturating_neg(),
+    }
+});
+
 impl_op_ex!(+ |a: &ListingCurrencies, b: &ListingCurrencies| -> ListingCurrencies { 
     ListingCurrencies {
         keys: a.keys + b.keys,
@@ -190,14 +433,14 @@ impl_op_ex!(/ |currencies: &ListingCurrencies, num: Currency| -> ListingCurrenci
 impl_op_ex!(* |currencies: &ListingCurrencies, num: f32| -> ListingCurrencies {
     ListingCurrencies { 
         keys: currencies.keys * num,
-        metal: (currencies.metal as f32 * num).round() as Currency,
+        metal: (currencies.metal as f64 * num as f64).round() as Currency,
     }
 });
 
 impl_op_ex!(/ |currencies: &ListingCurrencies, num: f32| -> ListingCurrencies {
     ListingCurrencies {
         keys: currencies.keys / num,
-        metal: (currencies.metal as f32 / num).round() as Currency,
+        metal: (currencies.metal as f64 / num as f64).round() as Currency,
     }
 });
 
@@ -269,7 +512,7 @@ impl MulAssign<Currency> for ListingCurrencies {
 impl MulAssign<f32> for ListingCurrencies {
     fn mul_assign(&mut self, other: f32) {
         self.keys *= other;
-        self.metal = (self.metal as f32 * other).round() as Currency;
+        self.metal = (self.metal as f64 * other as f64).round() as Currency;
     }
 }
 
@@ -283,20 +526,29 @@ impl DivAssign<Currency> for ListingCurrencies {
 impl DivAssign<f32> for ListingCurrencies {
     fn div_assign(&mut self, other: f32) {
         self.keys /= other;
-        self.metal = (self.metal as f32 / other).round() as Currency;
+        self.metal = (self.metal as f64 / other as f64).round() as Currency;
     }
 }
 
 impl<'a> TryFrom<&'a str> for ListingCurrencies {
     type Error = ParseError;
     
+    /// Parses currencies from a string, e.g. "2.5 keys, 3.33 ref" or "0.4 key". Keys may be 
+    /// fractional and are parsed as an [`f32`], which is exact for halves, quarters and so on but 
+    /// otherwise holds the nearest representable value, e.g. "0.4 keys" holds `0.4f32`. Only around 
+    /// 7 significant digits are kept, so very large fractional key counts lose precision. Metal is 
+    /// rounded to the nearest weapon. Infinite and NaN key counts are rejected, as are units 
+    /// appearing more than once, e.g. "2 keys, 3 keys".
     fn try_from(string: &'a str) -> Result<Self, Self::Error>  {
-        let (keys, metal) = helpers::parse_from_string::<f32>(string)?;
-        
-        Ok(ListingCurrencies {
-            keys,
-            metal,
-        })
+        Self::from_str_with(string, &ParseOptions::default())
+    }
+}
+
+impl FromStr for ListingCurrencies {
+    type Err = ParseError;
+    
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Self::try_from(string)
     }
 }
 
@@ -320,32 +572,34 @@ impl From<&Currencies> for ListingCurrencies {
 
 impl fmt::Display for ListingCurrencies {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if self.keys != 0.0 && self.metal != 0 {
-            write!(
-                f,
-                "{} {}, {} {}",
-                helpers::print_float(self.keys),
-                helpers::pluralize_float(self.keys, KEY_SYMBOL, KEYS_SYMBOL),
-                helpers::get_metal_float(self.metal),
-                METAL_SYMBOL,
-            )
-        } else if self.keys != 0.0 {
-            write!(
-                f,
-                "{} {}",
-                helpers::print_float(self.keys),
-                helpers::pluralize_float(self.keys, KEY_SYMBOL, KEYS_SYMBOL),
-            )
-        } else if self.metal != 0 {
-            write!(
-                f,
-                "{} {}",
-                helpers::get_metal_float(self.metal),
-                METAL_SYMBOL,
-            )
-        } else {
-            write!(f, "{}", EMPTY_SYMBOL)
-        }
+        format::pad(f, |f| {
+            if self.keys != 0.0 && self.metal != 0 {
+                write!(
+                    f,
+                    "{} {}, {} {}",
+                    helpers::print_float(self.keys),
+                    helpers::pluralize_float(self.keys, KEY_SYMBOL, KEYS_SYMBOL),
+                    helpers::get_metal_float_f64(self.metal),
+                    METAL_SYMBOL,
+                )
+            } else if self.keys != 0.0 {
+                write!(
+                    f,
+                    "{} {}",
+                    helpers::print_float(self.keys),
+                    helpers::pluralize_float(self.keys, KEY_SYMBOL, KEYS_SYMBOL),
+                )
+            } else if self.metal != 0 {
+                write!(
+                    f,
+                    "{} {}",
+                    helpers::get_metal_float_f64(self.metal),
+                    METAL_SYMBOL,
+                )
+            } else {
+                write!(f, "{}", EMPTY_SYMBOL)
+            }
+        })
     }
 }
 
@@ -356,6 +610,10 @@ impl<'de> Deserialize<'de> for ListingCurrencies {
     {
         let currencies = Self::deserialize(deserializer)?;
         
+        if !currencies.is_finite() {
+            return Err(D::Error::custom("Key value is not finite"));
+        }
+        
         if currencies.keys == 0.0 && currencies.metal == 0 {
             return Err(D::Error::custom("Does not contain values for keys or metal"));
         }
@@ -369,8 +627,18 @@ impl Serialize for ListingCurrencies {
     where
         S: Serializer,
     {
+        let is_human_readable = serializer.is_human_readable();
         let mut currencies = serializer.serialize_struct("ListingCurrencies", 2)?;
         
+        // Formats which are not self-describing need every field in a fixed layout. Metal is 
+        // written as weapons so the value is exact.
+        if !is_human_readable {
+            currencies.serialize_field("keys", &self.keys)?;
+            currencies.serialize_field("metal", &self.metal)?;
+            
+            return currencies.end();
+        }
+        
         if self.keys == 0.0 {
             currencies.skip_field("keys")?;
         } else if self.keys.fract() == 0.0 {
@@ -382,7 +650,7 @@ impl Serialize for ListingCurrencies {
         if self.metal == 0 {
             currencies.skip_field("metal")?;
         } else {
-            let float = helpers::get_metal_float(self.metal);
+            let float = helpers::get_metal_float_f64(self.metal);
             
             if float.fract() == 0.0 {
                 currencies.serialize_field("metal", &(float as Currency))?;
@@ -462,6 +730,17 @@ mod tests {
         });
     }
     
+    #[test]
+    fn currencies_negated() {
+        assert_eq!(-ListingCurrencies {
+            keys: 1.5,
+            metal: refined!(-10),
+        }, ListingCurrencies {
+            keys: -1.5,
+            metal: refined!(10),
+        });
+    }
+    
     #[test]
     fn currencies_multiplied_by_metal() {
         assert_eq!(ListingCurrencies {
@@ -484,6 +763,16 @@ mod tests {
         });
     }
     
+    #[test]
+    fn borrowed_currencies_scalar_arithmetic() {
+        let currencies = &ListingCurrencies { keys: 10.0, metal: refined!(10) };
+        
+        assert_eq!(currencies * 2, ListingCurrencies { keys: 20.0, metal: refined!(20) });
+        assert_eq!(currencies / 2, ListingCurrencies { keys: 5.0, metal: refined!(5) });
+        assert_eq!(currencies * 2.5, ListingCurrencies { keys: 25.0, metal: refined!(25) });
+        assert_eq!(currencies / 2.5, ListingCurrencies { keys: 4.0, metal: refined!(4) });
+    }
+    
     #[test]
     fn currencies_multiplied_by_f32() {
         assert_eq!(ListingCurrencies {
@@ -571,6 +860,75 @@ mod tests {
         assert!(currencies.is_err());
     }
     
+    #[test]
+    fn parses_fractional_keys() {
+        assert_eq!(ListingCurrencies::try_from("2.5 keys").unwrap(), ListingCurrencies {
+            keys: 2.5,
+            metal: 0,
+        });
+        assert_eq!(ListingCurrencies::try_from("0.4 key").unwrap(), ListingCurrencies {
+            keys: 0.4,
+            metal: 0,
+        });
+        assert_eq!(ListingCurrencies::try_from("1.25 keys, 3.33 ref").unwrap(), ListingCurrencies {
+            keys: 1.25,
+            metal: refined!(3) + scrap!(3),
+        });
+    }
+    
+    #[test]
+    fn parses_fractional_keys_display_round_trip() {
+        let currencies = ListingCurrencies {
+            keys: 2.5,
+            metal: refined!(1),
+        };
+        
+        assert_eq!(ListingCurrencies::try_from(currencies.to_string().as_str()).unwrap(), currencies);
+    }
+    
+    #[test]
+    fn pads_to_width() {
+        let currencies = ListingCurrencies {
+            keys: 2.5,
+            metal: refined!(1),
+        };
+        
+        assert_eq!(format!("{:>20}|", currencies), "    2.50 keys, 1 ref|");
+        assert_eq!(format!("{:^10}|", ListingCurrencies::default()), " nothing  |");
+    }
+    
+    #[test]
+    fn serializes_bincode() {
+        for currencies in [
+            ListingCurrencies { keys: 2.5, metal: refined!(3) + 1 },
+            ListingCurrencies { keys: 0.0, metal: 1 },
+            ListingCurrencies { keys: 1.0, metal: 0 },
+        ] {
+            let bytes = bincode::serialize(&currencies).unwrap();
+            
+            assert_eq!(bincode::deserialize::<ListingCurrencies>(&bytes).unwrap(), currencies);
+        }
+    }
+    
+    #[test]
+    fn parses_duplicate_currencies() {
+        let options = ParseOptions::new().allow_duplicates(true);
+        
+        assert!(ListingCurrencies::try_from("2 keys, 3 keys").is_err());
+        let currencies = ListingCurrencies::from_str_with("2.5 keys, 0.5 keys", &options).unwrap();
+        
+        assert_eq!(currencies, ListingCurrencies {
+            keys: 3.0,
+            metal: 0,
+        });
+    }
+    
+    #[test]
+    fn parses_non_finite_keys_as_error() {
+        assert!(ListingCurrencies::try_from("inf keys").is_err());
+        assert!(ListingCurrencies::try_from("NaN keys").is_err());
+    }
+    
     #[test]
     fn formats_currencies() {
         assert_eq!(&format!("{}", ListingCurrencies {
@@ -657,7 +1015,7 @@ mod tests {
     
     #[test]
     fn sorts() {
-        let mut currencies = vec![
+        let mut currencies = [
             ListingCurrencies { keys: 2.0, metal: 4 },
             ListingCurrencies { keys: 0.0, metal: 2 },
             ListingCurrencies { keys: 10.0, metal: 4 },
@@ -666,7 +1024,23 @@ mod tests {
         // lowest to highest
         currencies.sort();
         
-        assert_eq!(*currencies.iter().rev().next().unwrap(), ListingCurrencies { keys: 10.0, metal: 4});
+        assert_eq!(*currencies.iter().next_back().unwrap(), ListingCurrencies { keys: 10.0, metal: 4});
+    }
+    
+    #[test]
+    fn saturating_add() {
+        assert_eq!(
+            ListingCurrencies { keys: 1.5, metal: Currency::MAX }.saturating_add(&ListingCurrencies { keys: 1.0, metal: 1 }),
+            ListingCurrencies { keys: 2.5, metal: Currency::MAX },
+        );
+    }
+    
+    #[test]
+    fn saturating_sub() {
+        assert_eq!(
+            ListingCurrencies { keys: 1.5, metal: Currency::MIN }.saturating_sub(&ListingCurrencies { keys: 1.0, metal: 1 }),
+            ListingCurrencies { keys: 0.5, metal: Currency::MIN },
+        );
     }
     
     #[test]
@@ -677,6 +1051,30 @@ mod tests {
         );
     }
     
+    #[test]
+    fn checked_to_metal_negative_overflow() {
+        assert_eq!(
+            ListingCurrencies { keys: -1e20, metal: 0 }.checked_to_metal(10),
+            None,
+        );
+    }
+    
+    #[test]
+    fn checked_to_metal_metal_overflow() {
+        assert_eq!(
+            ListingCurrencies { keys: 1.0, metal: Currency::MAX }.checked_to_metal(10),
+            None,
+        );
+    }
+    
+    #[test]
+    fn checked_to_metal_nan() {
+        assert_eq!(
+            ListingCurrencies { keys: f32::NAN, metal: 0 }.checked_to_metal(10),
+            None,
+        );
+    }
+    
     #[test]
     fn checked_to_metal_correct_value() {
         assert_eq!(
@@ -684,4 +1082,69 @@ mod tests {
             Some(105),
         );
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn checks_finite() {
+        assert!(ListingCurrencies { keys: 1.5, metal: 4 }.is_finite());
+        assert!(!ListingCurrencies { keys: f32::INFINITY, metal: 4 }.is_finite());
+        assert!(!ListingCurrencies { keys: f32::NAN, metal: 4 }.is_finite());
+    }
+    
+    #[test]
+    fn checked_arithmetic_rejects_non_finite() {
+        let currencies = ListingCurrencies { keys: 1.5, metal: 4 };
+        
+        assert_eq!(
+            currencies.checked_sub(&ListingCurrencies { keys: 1.0, metal: 2 }),
+            Some(ListingCurrencies { keys: 0.5, metal: 2 }),
+        );
+        assert_eq!(currencies.checked_add(&ListingCurrencies { keys: f32::MAX, metal: 0 }.saturating_mul(2)), None);
+        assert_eq!(currencies.checked_sub(&ListingCurrencies { keys: f32::NAN, metal: 0 }), None);
+        assert_eq!(ListingCurrencies { keys: f32::MAX, metal: 4 }.checked_mul(2), None);
+        assert_eq!(currencies.checked_div(0), None);
+    }
+    
+    #[test]
+    fn sorts_non_finite() {
+        let mut currencies = [
+            ListingCurrencies { keys: f32::NAN, metal: 0 },
+            ListingCurrencies { keys: 2.0, metal: 4 },
+            ListingCurrencies { keys: f32::INFINITY, metal: 0 },
+            ListingCurrencies { keys: f32::NEG_INFINITY, metal: 0 },
+        ];
+        
+        currencies.sort();
+        
+        assert_eq!(currencies[0], ListingCurrencies { keys: f32::NEG_INFINITY, metal: 0 });
+        assert_eq!(currencies[1], ListingCurrencies { keys: 2.0, metal: 4 });
+        assert_eq!(currencies[2], ListingCurrencies { keys: f32::INFINITY, metal: 0 });
+        assert!(currencies[3].keys.is_nan());
+        assert_eq!(
+            ListingCurrencies { keys: f32::NAN, metal: 0 }.cmp(&ListingCurrencies { keys: f32::NAN, metal: 0 }),
+            Ordering::Equal,
+        );
+        assert_eq!(
+            ListingCurrencies { keys: f32::NAN, metal: 1 }.cmp(&ListingCurrencies { keys: f32::NAN, metal: 2 }),
+            Ordering::Less,
+        );
+        assert_eq!(
+            ListingCurrencies { keys: -0.0, metal: 1 }.cmp(&ListingCurrencies { keys: 0.0, metal: 1 }),
+            Ordering::Equal,
+        );
+    }
+    
+    #[test]
+    fn deserializes_currencies_rejects_non_finite_keys() {
+        // serde_json parses numbers beyond the range of f32 as infinity
+        assert!(serde_json::from_str::<ListingCurrencies>(r#"{"keys":1e39,"metal":2}"#).is_err());
+    }
+    
+    #[test]
+    fn equals_approximately() {
+        let currencies = ListingCurrencies { keys: 1.0, metal: 4 };
+        
+        assert!(currencies.eq_approx(&ListingCurrencies { keys: 1.0 + 1e-7, metal: 4 }, 1e-6));
+        assert!(!currencies.eq_approx(&ListingCurrencies { keys: 1.1, metal: 4 }, 1e-6));
+        assert!(!currencies.eq_approx(&ListingCurrencies { keys: f32::NAN, metal: 4 }, f32::MAX));
+    }
+}
@@ -0,0 +1,137 @@
+//! Helpers for Marketplace.tf cash prices. Marketplace.tf takes a cut of 10% from each sale, 
+//! rounded to the nearest cent.
+//! 
+//! # Examples
+//! ```
+//! use tf2_price::{Converter, Currencies, USDCurrencies, refined};
+//! use tf2_price::marketplace_tf;
+//! 
+//! // Keys are 50 ref or $1.80 each.
+//! let converter = Converter::new(refined!(50), Some(180));
+//! let price = USDCurrencies { usd: 400 };
+//! 
+//! // Listing an item for $4.00 leaves $3.60 after the seller cut, or 2 keys.
+//! assert_eq!(marketplace_tf::seller_receives(&price), USDCurrencies { usd: 360 });
+//! assert_eq!(
+//!     marketplace_tf::received_to_currencies(&price, &converter),
+//!     Some(Currencies { keys: 2, metal: 0 }),
+//! );
+//! ```
+
+use crate::types::Currency;
+use crate::{Converter, Currencies, USDCurrencies};
+
+/// The percentage of each sale taken by Marketplace.tf.
+pub const SELLER_CUT_PERCENT: Currency = 10;
+
+/// The cut taken by Marketplace.tf from a sale at `price`.
+pub fn seller_cut(price: &USDCurrencies) -> USDCurrencies {
+    // Widened so that the multiplication cannot overflow, rounded half away from zero.
+    let cut = price.usd as i128 * SELLER_CUT_PERCENT as i128;
+    let cut = (cut + cut.signum() * 50) / 100;
+    
+    USDCurrencies {
+        usd: cut as Currency,
+    }
+}
+
+/// The amount the seller receives from a sale at `price` after the seller cut.
+pub fn seller_receives(price: &USDCurrencies) -> USDCurrencies {
+    *price - seller_cut(price)
+}
+
+/// The lowest price to list at for the seller to receive at least `received` after the seller 
+/// cut. `None` if the price would be beyond the bounds of [`i64`].
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::USDCurrencies;
+/// use tf2_price::marketplace_tf;
+/// 
+/// let price = marketplace_tf::price_for_received(&USDCurrencies { usd: 360 });
+/// 
+/// assert_eq!(price, Some(USDCurrencies { usd: 400 }));
+/// assert_eq!(marketplace_tf::price_for_received(&USDCurrencies { usd: i64::MAX }), None);
+/// ```
+pub fn price_for_received(received: &USDCurrencies) -> Option<USDCurrencies> {
+    let percent_received = 100 - SELLER_CUT_PERCENT;
+    // An estimate which is close to the result, only small adjustments are needed from here.
+    let estimate = received.usd as i128 * 100 / percent_received as i128;
+    let mut usd = Currency::try_from(estimate).ok()?;
+    
+    while seller_receives(&USDCurrencies { usd }) < *received {
+        usd = usd.checked_add(1)?;
+    }
+    
+    while usd > 0 && seller_receives(&USDCurrencies { usd: usd - 1 }) >= *received {
+        usd -= 1;
+    }
+    
+    Some(USDCurrencies {
+        usd,
+    })
+}
+
+/// Converts a Marketplace.tf price to currencies using the given converter. `None` if the 
+/// converter does not have a cash value for keys.
+/// 
+/// # Panics
+/// Panics if the converter's key price is `0`.
+pub fn price_to_currencies(price: &USDCurrencies, converter: &Converter) -> Option<Currencies> {
+    converter.from_usd(price)
+}
+
+/// Converts the amount the seller receives from a sale at `price` to currencies using the given 
+/// converter. `None` if the converter does not have a cash value for keys.
+/// 
+/// # Panics
+/// Panics if the converter's key price is `0`.
+pub fn received_to_currencies(price: &USDCurrencies, converter: &Converter) -> Option<Currencies> {
+    converter.from_usd(&seller_receives(price))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::refined;
+    
+    #[test]
+    fn rounds_seller_cut() {
+        assert_eq!(seller_cut(&USDCurrencies { usd: 15 }), USDCurrencies { usd: 2 });
+        assert_eq!(seller_cut(&USDCurrencies { usd: 14 }), USDCurrencies { usd: 1 });
+        assert_eq!(seller_cut(&USDCurrencies { usd: 0 }), USDCurrencies { usd: 0 });
+    }
+    
+    #[test]
+    fn price_for_received_is_lowest_price() {
+        for usd in 0..2000 {
+            let received = USDCurrencies { usd };
+            let price = price_for_received(&received).unwrap();
+            
+            assert!(seller_receives(&price) >= received);
+            
+            if price.usd > 0 {
+                assert!(seller_receives(&USDCurrencies { usd: price.usd - 1 }) < received);
+            }
+        }
+    }
+    
+    #[test]
+    fn price_for_received_near_integer_bounds() {
+        let usd = Currency::MAX / 10 * 9;
+        let price = price_for_received(&USDCurrencies { usd }).unwrap();
+        
+        assert!(seller_receives(&price) >= USDCurrencies { usd });
+        assert_eq!(price_for_received(&USDCurrencies { usd: Currency::MAX - 1 }), None);
+    }
+    
+    #[test]
+    fn converts_price_to_currencies() {
+        let converter = Converter::new(refined!(50), Some(200));
+        
+        assert_eq!(price_to_currencies(&USDCurrencies { usd: 300 }, &converter), Some(Currencies {
+            keys: 1,
+            metal: refined!(25),
+        }));
+    }
+}
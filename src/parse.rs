@@ -0,0 +1,1000 @@
+use crate::error::ParseError;
+use crate::types::Currency;
+use crate::constants::{
+    KEYS_SYMBOL,
+    KEY_SYMBOL,
+    METAL_SYMBOL,
+    REFINED_SYMBOL,
+    REC_SYMBOL,
+    RECLAIMED_SYMBOL,
+    SCRAP_SYMBOL,
+    EMPTY_SYMBOL,
+    ONE_REF,
+    ONE_REC,
+    ONE_SCRAP,
+};
+use crate::format::{KEY_COMPACT_SYMBOL, METAL_COMPACT_SYMBOL};
+#[cfg(not(feature = "std"))]
+use crate::float::Float;
+use core::ops::Range;
+use core::str::FromStr;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::format;
+
+/// Symbol for keys accepted in lenient parsing, e.g. "2k".
+const KEY_SHORT_SYMBOL: &str = KEY_COMPACT_SYMBOL;
+/// Symbol for refined metal accepted in lenient parsing, e.g. "3.33r".
+const METAL_SHORT_SYMBOL: &str = METAL_COMPACT_SYMBOL;
+/// Thousands separators accepted alongside "." when parsing with a decimal comma.
+const THIN_SPACES: [char; 2] = ['\u{2009}', '\u{202F}'];
+
+/// How strictly strings are matched when parsing currencies.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum ParseMode {
+    /// Values must be written exactly as "N unit" and separated by ", ", e.g. "2 keys, 3.33 ref". 
+    /// Units are case-sensitive.
+    Strict,
+    /// Values must be written as "N unit" and separated by commas, e.g. "2 keys, 3.33 ref". This 
+    /// is the format created by [`Display`](std::fmt::Display). Units are matched ignoring case 
+    /// and any amount of whitespace is allowed around values, e.g. "2 KEYS,  3.33 Ref".
+    #[default]
+    Standard,
+    /// Values may be written without separators or spaces, e.g. "2k 3.33ref" or "2keys". Keys may
+    /// be abbreviated as "k" and refined as "r", matching the compact format created by 
+    /// `format!("{:#}", currencies)`, and a number without a unit is read as refined metal, e.g. 
+    /// "1.33".
+    Lenient,
+}
+
+/// A unit a value can be written in.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Unit {
+    /// Keys, written as "key" or "keys".
+    Keys,
+    /// Refined metal, written as "ref" or "refined".
+    Refined,
+    /// Reclaimed metal, written as "rec" or "reclaimed".
+    Reclaimed,
+    /// Scrap metal, written as "scrap".
+    Scrap,
+}
+
+impl Unit {
+    /// All units.
+    pub const ALL: [Self; 4] = [
+        Self::Keys,
+        Self::Refined,
+        Self::Reclaimed,
+        Self::Scrap,
+    ];
+    
+    /// Matches a unit symbol. Case is ignored unless parsing in [`ParseMode::Strict`].
+    fn from_symbol(symbol: &str, options: &ParseOptions) -> Option<Self> {
+        let is = |other: &str| if options.mode == ParseMode::Strict {
+            symbol == other
+        } else {
+            symbol.eq_ignore_ascii_case(other)
+        };
+        let is_lenient = options.mode == ParseMode::Lenient;
+        
+        if is(KEY_SYMBOL) || is(KEYS_SYMBOL) || (is_lenient && is(KEY_SHORT_SYMBOL)) {
+            Some(Self::Keys)
+        } else if is(METAL_SYMBOL) || is(REFINED_SYMBOL) || (is_lenient && is(METAL_SHORT_SYMBOL)) {
+            Some(Self::Refined)
+        } else if is(REC_SYMBOL) || is(RECLAIMED_SYMBOL) {
+            Some(Self::Reclaimed)
+        } else if is(SCRAP_SYMBOL) {
+            Some(Self::Scrap)
+        } else {
+            None
+        }
+    }
+    
+    /// The symbol used for this unit in errors.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Self::Keys => KEYS_SYMBOL,
+            Self::Refined => METAL_SYMBOL,
+            Self::Reclaimed => REC_SYMBOL,
+            Self::Scrap => SCRAP_SYMBOL,
+        }
+    }
+    
+    fn bit(&self) -> u8 {
+        match self {
+            Self::Keys => 1,
+            Self::Refined => 1 << 1,
+            Self::Reclaimed => 1 << 2,
+            Self::Scrap => 1 << 3,
+        }
+    }
+}
+
+/// Options for parsing currencies from strings. By default strings are parsed using 
+/// [`ParseMode::Standard`], all units are allowed, and duplicate units and empty strings are 
+/// errors.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, ParseOptions, ParseMode, Unit, refined, scrap};
+/// 
+/// let options = ParseOptions::new().mode(ParseMode::Lenient);
+/// let currencies = Currencies::from_str_with("2k 3.33ref", &options).unwrap();
+/// 
+/// assert_eq!(currencies, Currencies { keys: 2, metal: refined!(3) + scrap!(3) });
+/// 
+/// let options = ParseOptions::new()
+///     .units(&[Unit::Keys, Unit::Refined])
+///     .allow_duplicates(true)
+///     .allow_empty(true);
+/// 
+/// let currencies = Currencies::from_str_with("1 key, 1 key", &options).unwrap();
+/// 
+/// assert_eq!(currencies, Currencies { keys: 2, metal: 0 });
+/// assert_eq!(Currencies::from_str_with("", &options).unwrap(), Currencies::default());
+/// assert!(Currencies::from_str_with("1 scrap", &options).is_err());
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct ParseOptions {
+    mode: ParseMode,
+    decimal_comma: bool,
+    // bits of the allowed units
+    units: u8,
+    allow_duplicates: bool,
+    allow_empty: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            mode: ParseMode::default(),
+            decimal_comma: false,
+            units: Unit::ALL.iter().fold(0, |units, unit| units | unit.bit()),
+            allow_duplicates: false,
+            allow_empty: false,
+        }
+    }
+}
+
+impl ParseOptions {
+    /// Creates the default options. These match the format created by
+    /// [`Display`](std::fmt::Display).
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Creates options for strict parsing. Equivalent to
+    /// `ParseOptions::new().mode(ParseMode::Strict)`.
+    pub fn strict() -> Self {
+        Self::new().mode(ParseMode::Strict)
+    }
+    
+    /// Creates options for lenient parsing. Equivalent to
+    /// `ParseOptions::new().mode(ParseMode::Lenient)`.
+    pub fn lenient() -> Self {
+        Self::new().mode(ParseMode::Lenient)
+    }
+    
+    /// Sets the parsing mode.
+    pub fn mode(mut self, mode: ParseMode) -> Self {
+        self.mode = mode;
+        self
+    }
+    
+    /// Sets whether numbers are written with a decimal comma, e.g. "3,33 ref". When enabled, "." 
+    /// and thin spaces are read as thousands separators, e.g. "1.234,56 ref", and a comma is only 
+    /// read as a decimal separator when it is between two digits. Thousands separators must be 
+    /// followed by groups of three digits, so "3.33 ref" is an error rather than `333 ref`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, ParseOptions, refined, scrap};
+    /// 
+    /// let options = ParseOptions::new().decimal_comma(true);
+    /// let currencies = Currencies::from_str_with("2 keys, 3,33 ref", &options).unwrap();
+    /// 
+    /// assert_eq!(currencies, Currencies { keys: 2, metal: refined!(3) + scrap!(3) });
+    /// ```
+    pub fn decimal_comma(mut self, decimal_comma: bool) -> Self {
+        self.decimal_comma = decimal_comma;
+        self
+    }
+    
+    /// Sets the units which are allowed. Values in any other unit result in 
+    /// [`ParseError::UnitNotAllowed`].
+    pub fn units(mut self, units: &[Unit]) -> Self {
+        self.units = units.iter().fold(0, |units, unit| units | unit.bit());
+        self
+    }
+    
+    /// Sets whether a unit may appear more than once, e.g. "2 keys, 3 keys". When allowed the 
+    /// values are summed, otherwise they result in [`ParseError::DuplicateCurrency`].
+    pub fn allow_duplicates(mut self, allow_duplicates: bool) -> Self {
+        self.allow_duplicates = allow_duplicates;
+        self
+    }
+    
+    /// Sets whether strings containing no value, e.g. "", "nothing" or "0 keys", are parsed as 
+    /// zero rather than resulting in [`ParseError::Empty`].
+    pub fn allow_empty(mut self, allow_empty: bool) -> Self {
+        self.allow_empty = allow_empty;
+        self
+    }
+    
+    /// Gets the parsing mode.
+    pub fn get_mode(&self) -> ParseMode {
+        self.mode
+    }
+    
+    /// Gets whether numbers are written with a decimal comma.
+    pub fn get_decimal_comma(&self) -> bool {
+        self.decimal_comma
+    }
+    
+    /// Checks whether values may be written in the given unit.
+    pub fn is_unit_allowed(&self, unit: Unit) -> bool {
+        self.units & unit.bit() != 0
+    }
+    
+    /// Gets whether a unit may appear more than once.
+    pub fn get_allow_duplicates(&self) -> bool {
+        self.allow_duplicates
+    }
+    
+    /// Gets whether strings containing no value are parsed as zero.
+    pub fn get_allow_empty(&self) -> bool {
+        self.allow_empty
+    }
+}
+
+/// A key count which can be parsed from a string.
+pub(crate) trait KeyCount: Default + FromStr + PartialEq + Copy {
+    /// Adds two key counts, used when summing duplicates.
+    fn add_keys(self, other: Self) -> Self;
+    
+    /// Checks whether a parsed key count is valid.
+    fn is_valid(&self) -> bool {
+        true
+    }
+}
+
+impl KeyCount for Currency {
+    fn add_keys(self, other: Self) -> Self {
+        self.saturating_add(other)
+    }
+}
+
+impl KeyCount for f32 {
+    fn add_keys(self, other: Self) -> Self {
+        self + other
+    }
+    
+    // f32 parsing accepts values such as "inf" and "NaN"
+    fn is_valid(&self) -> bool {
+        self.is_finite()
+    }
+}
+
+/// The string being parsed, used to locate tokens in the original input for errors.
+struct Source<'a> {
+    original: &'a str,
+    // the string tokens are sliced from, which differs from the original when normalized
+    parsed: &'a str,
+    // the byte offset in the original input of each byte in the parsed string, followed by the 
+    // length of the original input
+    offsets: Option<Vec<usize>>,
+}
+
+impl<'a> Source<'a> {
+    /// Gets the span of a token sliced from the parsed string within the original input.
+    fn span(&self, token: &str) -> Range<usize> {
+        let start = token.as_ptr() as usize - self.parsed.as_ptr() as usize;
+        let end = start + token.len();
+        
+        match &self.offsets {
+            Some(offsets) => offsets[start]..offsets[end],
+            None => start..end,
+        }
+    }
+    
+    /// Gets the text and span of a token within the original input.
+    fn locate(&self, token: &str) -> (String, Range<usize>) {
+        let span = self.span(token);
+        
+        (self.original[span.clone()].to_string(), span)
+    }
+    
+    /// Gets the slice of the parsed string from the start of `first` to the end of `last`.
+    fn join(&self, first: &'a str, last: &'a str) -> &'a str {
+        let start = first.as_ptr() as usize - self.parsed.as_ptr() as usize;
+        let end = last.as_ptr() as usize - self.parsed.as_ptr() as usize + last.len();
+        
+        &self.parsed[start..end]
+    }
+    
+    fn invalid_format(&self, token: &str) -> ParseError {
+        let (token, span) = self.locate(token);
+        
+        ParseError::InvalidFormat {
+            token,
+            span,
+        }
+    }
+}
+
+/// Collects the values of each unit as they are parsed.
+struct Parsed<'a, T> {
+    options: &'a ParseOptions,
+    source: &'a Source<'a>,
+    keys: Option<T>,
+    // refined, reclaimed and scrap
+    metal: [Option<Currency>; 3],
+    // whether the values are wrapped in "-(…)"
+    negate: bool,
+}
+
+impl<'a, T> Parsed<'a, T>
+where
+    T: KeyCount,
+{
+    fn new(options: &'a ParseOptions, source: &'a Source<'a>, negate: bool) -> Self {
+        Self {
+            options,
+            source,
+            keys: None,
+            metal: [None; 3],
+            negate,
+        }
+    }
+    
+    /// Adds a value, where `element` is the whole value including its unit, e.g. "2 keys".
+    fn add(&mut self, count_str: &str, unit: Unit, element: &str) -> Result<(), ParseError> {
+        if !self.options.is_unit_allowed(unit) {
+            let (token, span) = self.source.locate(element);
+            
+            return Err(ParseError::UnitNotAllowed {
+                unit: unit.symbol().to_string(),
+                token,
+                span,
+            });
+        }
+        
+        let negated;
+        let count_value = if self.negate {
+            if count_str.starts_with(['-', '+']) {
+                // signs inside "-(…)" are not allowed
+                return Err(self.source.invalid_format(count_str));
+            }
+            
+            negated = format!("-{count_str}");
+            negated.as_str()
+        } else {
+            count_str
+        };
+        let duplicate_error = || {
+            let (token, span) = self.source.locate(element);
+            
+            ParseError::DuplicateCurrency {
+                currency: unit.symbol().to_string(),
+                token,
+                span,
+            }
+        };
+        let (index, value) = match unit {
+            Unit::Keys => {
+                if self.keys.is_some() && !self.options.allow_duplicates {
+                    return Err(duplicate_error());
+                }
+                
+                let count = count_value.parse::<T>()
+                    .ok()
+                    .filter(KeyCount::is_valid)
+                    .ok_or_else(|| {
+                        let (token, span) = self.source.locate(count_str);
+                        
+                        ParseError::InvalidKeyCount {
+                            token,
+                            span,
+                        }
+                    })?;
+                
+                self.keys = Some(self.keys.unwrap_or_default().add_keys(count));
+                return Ok(());
+            },
+            Unit::Refined => (0, ONE_REF),
+            Unit::Reclaimed => (1, ONE_REC),
+            Unit::Scrap => (2, ONE_SCRAP),
+        };
+        
+        if self.metal[index].is_some() && !self.options.allow_duplicates {
+            return Err(duplicate_error());
+        }
+        
+        // f64 is used so that large values do not lose precision
+        let count = count_value.parse::<f64>()
+            .ok()
+            .filter(|count| count.is_finite())
+            .ok_or_else(|| {
+                let (token, span) = self.source.locate(count_str);
+                
+                ParseError::InvalidMetalValue {
+                    token,
+                    span,
+                }
+            })?;
+        let metal = (count * value as f64).round() as Currency;
+        
+        self.metal[index] = Some(self.metal[index].unwrap_or_default().saturating_add(metal));
+        Ok(())
+    }
+    
+    fn finish(self) -> Result<(T, Currency), ParseError> {
+        let keys = self.keys.unwrap_or_default();
+        let metal = self.metal
+            .into_iter()
+            .flatten()
+            .fold(0, Currency::saturating_add);
+        
+        if keys == T::default() && metal == 0 && !self.options.allow_empty {
+            return Err(ParseError::Empty);
+        }
+        
+        Ok((keys, metal))
+    }
+}
+
+/// Parses keys and metal from a string using the given options. Values may be negated 
+/// individually, e.g. "-2 keys, -3.33 ref", or together, e.g. "-(2 keys, 3.33 ref)".
+pub(crate) fn parse_currencies<T>(
+    string: &str,
+    options: &ParseOptions,
+) -> Result<(T, Currency), ParseError>
+where
+    T: KeyCount,
+{
+    let is_empty = if options.mode == ParseMode::Strict {
+        string.is_empty() || string == EMPTY_SYMBOL
+    } else {
+        string.trim().is_empty() || string.trim().eq_ignore_ascii_case(EMPTY_SYMBOL)
+    };
+    
+    if is_empty {
+        if options.allow_empty {
+            return Ok((T::default(), 0));
+        }
+        
+        return Err(ParseError::Empty);
+    }
+    
+    let normalized;
+    let source = if options.decimal_comma {
+        let offsets;
+        
+        (normalized, offsets) = normalize_decimal_comma(string)?;
+        
+        Source {
+            original: string,
+            parsed: normalized.as_str(),
+            offsets: Some(offsets),
+        }
+    } else {
+        Source {
+            original: string,
+            parsed: string,
+            offsets: None,
+        }
+    };
+    let string = source.parsed;
+    let trimmed = if options.mode == ParseMode::Strict {
+        string
+    } else {
+        string.trim()
+    };
+    let (string, negate) = match trimmed.strip_prefix("-(") {
+        Some(inner) => {
+            let inner = inner.strip_suffix(')')
+                .ok_or_else(|| source.invalid_format(trimmed))?;
+            
+            (inner, true)
+        },
+        None => (string, false),
+    };
+    let parsed = Parsed::new(options, &source, negate);
+    
+    match options.mode {
+        ParseMode::Strict => parse_strict(string, options, parsed),
+        ParseMode::Standard => parse_standard(string, options, parsed),
+        ParseMode::Lenient => parse_lenient(string, options, parsed),
+    }
+}
+
+fn parse_strict<T>(
+    string: &str,
+    options: &ParseOptions,
+    mut parsed: Parsed<T>,
+) -> Result<(T, Currency), ParseError>
+where
+    T: KeyCount,
+{
+    for element in string.split(", ") {
+        let mut element_split = element.split(' ');
+        let (
+            Some(count_str),
+            Some(currency_name),
+            None,
+        ) = (
+            element_split.next(),
+            element_split.next(),
+            element_split.next(),
+        ) else {
+            return Err(parsed.source.invalid_format(element));
+        };
+        let unit = Unit::from_symbol(currency_name, options)
+            .ok_or_else(|| parsed.source.invalid_format(currency_name))?;
+        
+        parsed.add(count_str, unit, element)?;
+    }
+    
+    parsed.finish()
+}
+
+fn parse_standard<T>(
+    string: &str,
+    options: &ParseOptions,
+    mut parsed: Parsed<T>,
+) -> Result<(T, Currency), ParseError>
+where
+    T: KeyCount,
+{
+    for element in string.split(',') {
+        let element = element.trim();
+        let mut element_split = element.split_whitespace();
+        let (
+            Some(count_str),
+            Some(currency_name),
+            None,
+        ) = (
+            element_split.next(),
+            element_split.next(),
+            element_split.next(),
+        ) else {
+            return Err(parsed.source.invalid_format(element));
+        };
+        let unit = Unit::from_symbol(currency_name, options)
+            .ok_or_else(|| parsed.source.invalid_format(currency_name))?;
+        
+        parsed.add(count_str, unit, element)?;
+    }
+    
+    parsed.finish()
+}
+
+fn parse_lenient<T>(
+    string: &str,
+    options: &ParseOptions,
+    mut parsed: Parsed<T>,
+) -> Result<(T, Currency), ParseError>
+where
+    T: KeyCount,
+{
+    let source = parsed.source;
+    let mut tokens = string
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|token| !token.is_empty())
+        .peekable();
+    
+    while let Some(token) = tokens.next() {
+        let (count_str, symbol) = split_number(token);
+        
+        if count_str.is_empty() {
+            // a unit without a number
+            return Err(source.invalid_format(token));
+        }
+        
+        let (unit, element) = if !symbol.is_empty() {
+            let unit = Unit::from_symbol(symbol, options)
+                .ok_or_else(|| source.invalid_format(symbol))?;
+            
+            (unit, token)
+        } else if let Some((unit, next)) = tokens
+            .peek()
+            .and_then(|next| Some((Unit::from_symbol(next, options)?, *next)))
+        {
+            // the unit is the next token e.g. "2 keys"
+            tokens.next();
+            (unit, source.join(token, next))
+        } else {
+            // a bare number is refined metal
+            (Unit::Refined, token)
+        };
+        
+        parsed.add(count_str, unit, element)?;
+    }
+    
+    parsed.finish()
+}
+
+/// Rewrites numbers written with a decimal comma and thousands separators into the format read by 
+/// [`str::parse`], e.g. "1.234,56 ref" into "1234.56 ref". Commas which are not between two digits 
+/// are left as-is. Along with the rewritten string, the byte offset in `string` of each byte in the 
+/// rewritten string is returned, followed by the length of `string`.
+fn normalize_decimal_comma(string: &str) -> Result<(String, Vec<usize>), ParseError> {
+    let chars = string.char_indices().collect::<Vec<_>>();
+    let mut normalized = String::with_capacity(string.len());
+    let mut offsets = Vec::with_capacity(string.len() + 1);
+    let mut push = |c: char, offset: usize| {
+        normalized.push(c);
+        offsets.extend(core::iter::repeat_n(offset, c.len_utf8()));
+    };
+    let is_digit = |index: usize| chars.get(index).is_some_and(|(_, c)| c.is_ascii_digit());
+    let mut i = 0;
+    
+    while i < chars.len() {
+        if !is_digit(i) {
+            push(chars[i].1, chars[i].0);
+            i += 1;
+            continue;
+        }
+        
+        let run_start = chars[i].0;
+        let invalid_format = |mut end: usize| {
+            // include the rest of the number
+            while end < chars.len() && (is_digit(end) || (is_digit(end - 1) && is_digit(end + 1))) {
+                end += 1;
+            }
+            
+            let end = chars.get(end).map_or(string.len(), |(offset, _)| *offset);
+            
+            ParseError::InvalidFormat {
+                token: string[run_start..end].to_string(),
+                span: run_start..end,
+            }
+        };
+        // digits in the current group, whether a thousands separator has been read, and whether 
+        // the decimal separator has been read
+        let mut group_len = 0;
+        let mut has_thousands = false;
+        let mut has_decimal = false;
+        
+        while i < chars.len() {
+            let (offset, c) = chars[i];
+            let is_separator = i > 0 && is_digit(i - 1) && is_digit(i + 1);
+            
+            if c.is_ascii_digit() {
+                push(c, offset);
+                group_len += 1;
+            } else if is_separator && (c == '.' || THIN_SPACES.contains(&c)) {
+                if has_decimal || (has_thousands && group_len != 3) {
+                    return Err(invalid_format(i));
+                }
+                
+                has_thousands = true;
+                group_len = 0;
+            } else if is_separator && c == ',' && !has_decimal {
+                if has_thousands && group_len != 3 {
+                    return Err(invalid_format(i));
+                }
+                
+                push('.', offset);
+                has_decimal = true;
+                group_len = 0;
+            } else {
+                break;
+            }
+            
+            i += 1;
+        }
+        
+        if has_thousands && !has_decimal && group_len != 3 {
+            return Err(invalid_format(i));
+        }
+    }
+    
+    offsets.push(string.len());
+    Ok((normalized, offsets))
+}
+
+/// Splits a token into its leading number and trailing unit, e.g. "3.33ref" into "3.33" and "ref".
+fn split_number(token: &str) -> (&str, &str) {
+    let index = token
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+'))
+        .unwrap_or(token.len());
+    
+    token.split_at(index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn lenient(string: &str) -> Result<(Currency, Currency), ParseError> {
+        parse_currencies(string, &ParseOptions::lenient())
+    }
+    
+    fn invalid_format(token: &str, start: usize) -> ParseError {
+        ParseError::InvalidFormat {
+            token: token.into(),
+            span: start..start + token.len(),
+        }
+    }
+    
+    fn duplicate(currency: &str, token: &str, start: usize) -> ParseError {
+        ParseError::DuplicateCurrency {
+            currency: currency.into(),
+            token: token.into(),
+            span: start..start + token.len(),
+        }
+    }
+    
+    fn unit_not_allowed(unit: &str, token: &str, start: usize) -> ParseError {
+        ParseError::UnitNotAllowed {
+            unit: unit.into(),
+            token: token.into(),
+            span: start..start + token.len(),
+        }
+    }
+    
+    #[test]
+    fn parses_standard() {
+        assert_eq!(
+            parse_currencies::<Currency>("2 keys, 3 ref", &ParseOptions::new()),
+            Ok((2, ONE_REF * 3)),
+        );
+        assert_eq!(
+            parse_currencies::<Currency>("2k 3ref", &ParseOptions::new()),
+            Err(invalid_format("3ref", 3)),
+        );
+    }
+    
+    #[test]
+    fn parses_standard_ignoring_case_and_whitespace() {
+        let options = ParseOptions::new();
+        
+        assert_eq!(
+            parse_currencies::<Currency>("2 KEYS,  3.33 Ref", &options),
+            Ok((2, ONE_REF * 3 + ONE_SCRAP * 3)),
+        );
+        assert_eq!(
+            parse_currencies::<Currency>("  1 Key ,2\tref ", &options),
+            Ok((1, ONE_REF * 2)),
+        );
+        assert_eq!(
+            parse_currencies::<Currency>("1 key,, 2 ref", &options),
+            Err(invalid_format("", 6)),
+        );
+        assert_eq!(
+            parse_currencies::<Currency>("1 key 2 ref", &options),
+            Err(invalid_format("1 key 2 ref", 0)),
+        );
+    }
+    
+    #[test]
+    fn parses_negative() {
+        let options = ParseOptions::new();
+        
+        assert_eq!(
+            parse_currencies::<Currency>("-2 keys, -3.33 ref", &options),
+            Ok((-2, -(ONE_REF * 3 + ONE_SCRAP * 3))),
+        );
+        assert_eq!(
+            parse_currencies::<Currency>("-(2 keys, 3.33 ref)", &options),
+            Ok((-2, -(ONE_REF * 3 + ONE_SCRAP * 3))),
+        );
+        assert_eq!(
+            parse_currencies::<f32>(" -(0.5 keys) ", &options),
+            Ok((-0.5, 0)),
+        );
+        assert_eq!(
+            parse_currencies::<Currency>("-(2k 1.33)", &ParseOptions::lenient()),
+            Ok((-2, -(ONE_REF + ONE_SCRAP * 3))),
+        );
+    }
+    
+    #[test]
+    fn parses_negative_invalid() {
+        let options = ParseOptions::new();
+        
+        assert_eq!(
+            parse_currencies::<Currency>("-(2 keys", &options),
+            Err(invalid_format("-(2 keys", 0)),
+        );
+        assert_eq!(
+            parse_currencies::<Currency>("-(-2 keys)", &options),
+            Err(invalid_format("-2", 2)),
+        );
+    }
+    
+    #[test]
+    fn parses_decimal_comma() {
+        let options = ParseOptions::new().decimal_comma(true);
+        
+        assert_eq!(
+            parse_currencies::<Currency>("2 keys, 3,33 ref", &options),
+            Ok((2, ONE_REF * 3 + ONE_SCRAP * 3)),
+        );
+        assert_eq!(
+            parse_currencies::<Currency>("2 keys,3,33 ref", &options),
+            Ok((2, ONE_REF * 3 + ONE_SCRAP * 3)),
+        );
+        assert_eq!(
+            parse_currencies::<Currency>("1.000 keys, 1.234,5 ref", &options),
+            Ok((1000, ONE_REF * 1234 + ONE_REC + 3)),
+        );
+        assert_eq!(
+            parse_currencies::<Currency>("1\u{2009}234 ref", &options),
+            Ok((0, ONE_REF * 1234)),
+        );
+        assert_eq!(
+            parse_currencies::<f32>("2,5 keys", &options),
+            Ok((2.5, 0)),
+        );
+        assert_eq!(
+            parse_currencies::<Currency>("2k 3,33", &options.mode(ParseMode::Lenient)),
+            Ok((2, ONE_REF * 3 + ONE_SCRAP * 3)),
+        );
+    }
+    
+    #[test]
+    fn parses_decimal_comma_invalid_groups() {
+        let options = ParseOptions::new().decimal_comma(true);
+        
+        assert_eq!(
+            parse_currencies::<Currency>("3.33 ref", &options),
+            Err(invalid_format("3.33", 0)),
+        );
+        assert_eq!(
+            parse_currencies::<Currency>("1.23,5 ref", &options),
+            Err(invalid_format("1.23,5", 0)),
+        );
+        assert_eq!(
+            parse_currencies::<Currency>("1,5.000 ref", &options),
+            Err(invalid_format("1,5.000", 0)),
+        );
+    }
+    
+    #[test]
+    fn parses_error_spans() {
+        let input = "2 keys, 3.x3 ref";
+        let error = parse_currencies::<Currency>(input, &ParseOptions::new()).unwrap_err();
+        
+        assert_eq!(error, ParseError::InvalidMetalValue {
+            token: "3.x3".into(),
+            span: 8..12,
+        });
+        assert_eq!(&input[error.span().unwrap()], "3.x3");
+        assert_eq!(error.to_string(), r#"Invalid metal value "3.x3" at 8..12"#);
+        assert_eq!(
+            parse_currencies::<Currency>("2.5 keys", &ParseOptions::new()),
+            Err(ParseError::InvalidKeyCount {
+                token: "2.5".into(),
+                span: 0..3,
+            }),
+        );
+        assert_eq!(
+            parse_currencies::<Currency>("-(2 keys, 3.x3 ref)", &ParseOptions::new()),
+            Err(ParseError::InvalidMetalValue {
+                token: "3.x3".into(),
+                span: 10..14,
+            }),
+        );
+    }
+    
+    #[test]
+    fn parses_error_spans_with_decimal_comma() {
+        let options = ParseOptions::new().decimal_comma(true);
+        let input = "1.000 keys, 2 hats";
+        let error = parse_currencies::<Currency>(input, &options).unwrap_err();
+        
+        assert_eq!(error, invalid_format("hats", 14));
+        assert_eq!(&input[error.span().unwrap()], "hats");
+        assert_eq!(
+            parse_currencies::<Currency>("1\u{2009}000 keys, 1.x ref", &options),
+            Err(ParseError::InvalidMetalValue {
+                token: "1.x".into(),
+                span: 14..17,
+            }),
+        );
+    }
+    
+    #[test]
+    fn parses_strict() {
+        let options = ParseOptions::strict();
+        
+        assert_eq!(parse_currencies::<Currency>("2 keys, 3 ref", &options), Ok((2, ONE_REF * 3)));
+        assert_eq!(parse_currencies::<Currency>("-(2 keys)", &options), Ok((-2, 0)));
+        assert_eq!(
+            parse_currencies::<Currency>("2 KEYS, 3 ref", &options),
+            Err(invalid_format("KEYS", 2)),
+        );
+        assert_eq!(
+            parse_currencies::<Currency>("2 keys,  3 ref", &options),
+            Err(invalid_format(" 3 ref", 8)),
+        );
+        assert_eq!(
+            parse_currencies::<Currency>(" 2 keys", &options),
+            Err(invalid_format(" 2 keys", 0)),
+        );
+    }
+    
+    #[test]
+    fn parses_allowed_units() {
+        let options = ParseOptions::new().units(&[Unit::Keys, Unit::Refined]);
+        
+        assert_eq!(parse_currencies::<Currency>("2 keys, 3 ref", &options), Ok((2, ONE_REF * 3)));
+        assert_eq!(
+            parse_currencies::<Currency>("2 keys, 3 scrap", &options),
+            Err(unit_not_allowed(SCRAP_SYMBOL, "3 scrap", 8)),
+        );
+        assert_eq!(
+            parse_currencies::<Currency>("1.33", &ParseOptions::lenient().units(&[Unit::Keys])),
+            Err(unit_not_allowed(METAL_SYMBOL, "1.33", 0)),
+        );
+    }
+    
+    #[test]
+    fn parses_duplicates_summed() {
+        let options = ParseOptions::new().allow_duplicates(true);
+        
+        assert_eq!(
+            parse_currencies::<Currency>("2 keys, 3 keys, 1 ref, 1 refined", &options),
+            Ok((5, ONE_REF * 2)),
+        );
+        assert_eq!(parse_currencies::<f32>("0.5 keys, 0.25 keys", &options), Ok((0.75, 0)));
+        assert_eq!(
+            parse_currencies::<Currency>("-(1 key, 1 key)", &options),
+            Ok((-2, 0)),
+        );
+    }
+    
+    #[test]
+    fn parses_empty() {
+        let options = ParseOptions::new().allow_empty(true);
+        
+        assert_eq!(parse_currencies::<Currency>("", &options), Ok((0, 0)));
+        assert_eq!(parse_currencies::<Currency>("  ", &options), Ok((0, 0)));
+        assert_eq!(parse_currencies::<Currency>("nothing", &options), Ok((0, 0)));
+        assert_eq!(parse_currencies::<Currency>("0 keys", &options), Ok((0, 0)));
+        assert_eq!(parse_currencies::<Currency>("nothing", &ParseOptions::new()), Err(ParseError::Empty));
+        assert_eq!(parse_currencies::<Currency>("0 keys", &ParseOptions::new()), Err(ParseError::Empty));
+    }
+    
+    #[test]
+    fn parses_lenient_ignoring_case() {
+        assert_eq!(lenient("2K 3REF"), Ok((2, ONE_REF * 3)));
+    }
+    
+    #[test]
+    fn parses_lenient_shorthand() {
+        assert_eq!(lenient("2k 3.33ref"), Ok((2, ONE_REF * 3 + ONE_SCRAP * 3)));
+        assert_eq!(lenient("2keys"), Ok((2, 0)));
+        assert_eq!(lenient("2k, 3.33r"), Ok((2, ONE_REF * 3 + ONE_SCRAP * 3)));
+        assert_eq!(lenient("1key,1ref"), Ok((1, ONE_REF)));
+        assert_eq!(lenient("1 key 2 rec"), Ok((1, ONE_REC * 2)));
+    }
+    
+    #[test]
+    fn parses_lenient_bare_metal() {
+        assert_eq!(lenient("1.33"), Ok((0, ONE_REF + ONE_SCRAP * 3)));
+        assert_eq!(lenient("2k 1.33"), Ok((2, ONE_REF + ONE_SCRAP * 3)));
+        assert_eq!(lenient("1.33 2k"), Ok((2, ONE_REF + ONE_SCRAP * 3)));
+    }
+    
+    #[test]
+    fn parses_lenient_standard_format() {
+        assert_eq!(lenient("2 keys, 3.33 ref"), Ok((2, ONE_REF * 3 + ONE_SCRAP * 3)));
+    }
+    
+    #[test]
+    fn parses_lenient_errors() {
+        assert_eq!(lenient("keys"), Err(invalid_format("keys", 0)));
+        assert_eq!(lenient("2hats"), Err(invalid_format("hats", 1)));
+        assert_eq!(lenient("1 2"), Err(duplicate(METAL_SYMBOL, "2", 2)));
+        assert_eq!(lenient("2k 3 keys"), Err(duplicate(KEYS_SYMBOL, "3 keys", 3)));
+        assert_eq!(lenient(" , "), Err(ParseError::Empty));
+        assert_eq!(lenient("1.2.3ref"), Err(ParseError::InvalidMetalValue {
+            token: "1.2.3".into(),
+            span: 0..5,
+        }));
+    }
+}
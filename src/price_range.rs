@@ -0,0 +1,417 @@
+use crate::types::Currency;
+use crate::error::{InvalidPriceRangeError, ParseError};
+use crate::{Currencies, RoundMetal};
+use core::fmt;
+use core::cmp::Ordering;
+use core::str::FromStr;
+use serde::{Serialize, Deserialize, Serializer, Deserializer};
+use serde::de::Error;
+use alloc::string::ToString;
+use alloc::format;
+
+/// A range of prices, such as a price suggestion of "2–3 keys". Prices are compared by their 
+/// total value using a key price, so whether the low price is worth more than the high price 
+/// depends on the key price, e.g. "1 key – 100 ref" is only valid while keys are worth at most 
+/// 100 ref.
+/// 
+/// [`PriceRange::new`] checks the range using the given key price. Parsing and deserializing have 
+/// no key price, so they only reject ranges where the low price is worth more than the high price 
+/// at every key price; use [`PriceRange::is_valid`] to check these against a key price.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, PriceRange, Rounding, refined};
+/// 
+/// let key_price = refined!(50);
+/// let range = PriceRange::try_from("2–3 keys").unwrap();
+/// 
+/// assert_eq!(range.low, Currencies { keys: 2, metal: 0 });
+/// assert_eq!(range.high, Currencies { keys: 3, metal: 0 });
+/// assert!(range.contains(&Currencies { keys: 2, metal: refined!(25) }, key_price));
+/// assert_eq!(range.mid(key_price, &Rounding::Refined), Currencies { keys: 2, metal: refined!(25) });
+/// assert_eq!(range.width(key_price), refined!(50));
+/// assert_eq!(range.to_string(), "2 keys – 3 keys");
+/// ```
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[serde(remote = "Self")]
+pub struct PriceRange {
+    /// The low price.
+    pub low: Currencies,
+    /// The high price.
+    pub high: Currencies,
+}
+
+impl PriceRange {
+    /// Creates a new [`PriceRange`]. Errors if `low` is worth more than `high` using the given key 
+    /// price (represented as weapons).
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, PriceRange, refined};
+    /// 
+    /// let low = Currencies { keys: 1, metal: 0 };
+    /// let high = Currencies { keys: 0, metal: refined!(100) };
+    /// 
+    /// assert!(PriceRange::new(low, high, refined!(60)).is_ok());
+    /// assert!(PriceRange::new(low, high, refined!(120)).is_err());
+    /// ```
+    pub fn new(
+        low: Currencies,
+        high: Currencies,
+        key_price: Currency,
+    ) -> Result<Self, InvalidPriceRangeError> {
+        let range = Self {
+            low,
+            high,
+        };
+        
+        if !range.is_valid(key_price) {
+            return Err(InvalidPriceRangeError {
+                low,
+                high,
+            });
+        }
+        
+        Ok(range)
+    }
+    
+    /// Checks that `low` is worth at most as much as `high` using the given key price 
+    /// (represented as weapons).
+    pub fn is_valid(&self, key_price: Currency) -> bool {
+        self.low.cmp_with_key_price(&self.high, key_price) != Ordering::Greater
+    }
+    
+    /// Checks if the value of the currencies is within the range, inclusive, using the given key 
+    /// price (represented as weapons).
+    pub fn contains(&self, currencies: &Currencies, key_price: Currency) -> bool {
+        self.low.cmp_with_key_price(currencies, key_price) != Ordering::Greater &&
+            currencies.cmp_with_key_price(&self.high, key_price) != Ordering::Greater
+    }
+    
+    /// The average of the low and high price using the given key price (represented as weapons).
+    /// See [`Currencies::midpoint`].
+    pub fn mid<R: RoundMetal + ?Sized>(&self, key_price: Currency, rounding: &R) -> Currencies {
+        self.low.midpoint(&self.high, key_price, rounding)
+    }
+    
+    /// The difference in value between the high and low price as weapons using the given key 
+    /// price (represented as weapons). This method is saturating.
+    pub fn width(&self, key_price: Currency) -> Currency {
+        self.high.to_metal(key_price).saturating_sub(self.low.to_metal(key_price))
+    }
+    
+    /// Creates a range without a key price. Errors only if `low` is worth more than `high` at 
+    /// every key price, i.e. neither `keys` nor `metal` is lower than in `high`.
+    fn without_key_price(
+        low: Currencies,
+        high: Currencies,
+    ) -> Result<Self, InvalidPriceRangeError> {
+        if low != high && low.keys >= high.keys && low.metal >= high.metal {
+            return Err(InvalidPriceRangeError {
+                low,
+                high,
+            });
+        }
+        
+        Ok(Self {
+            low,
+            high,
+        })
+    }
+}
+
+impl From<Currencies> for PriceRange {
+    /// Creates a [`PriceRange`] where the low and high price are the same.
+    fn from(currencies: Currencies) -> Self {
+        Self {
+            low: currencies,
+            high: currencies,
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for PriceRange {
+    type Error = ParseError;
+    
+    /// Parses a range from a string. The prices may be separated by an en dash ("–") or a hyphen 
+    /// and the low price may omit its unit, e.g. "2–3 keys", "1 key - 1 key, 20 ref". A single 
+    /// price is parsed as a range containing only that price.
+    /// 
+    /// A hyphen with whitespace on both sides is preferred as the separator so that prices with 
+    /// negative components can be separated, e.g. "1 key, -3 ref - 2 keys". Otherwise the first 
+    /// hyphen following a value is used, e.g. "2-3 ref".
+    fn try_from(string: &'a str) -> Result<Self, Self::Error>  {
+        let index = string.find('–').map(|i| (i, '–'.len_utf8()))
+            .or_else(|| find_hyphen_separator(string).map(|i| (i, 1)));
+        let Some((index, separator_len)) = index else {
+            return Ok(Currencies::try_from(string)?.into());
+        };
+        let low_part = &string[..index];
+        let low_str = low_part.trim();
+        let low_offset = low_part.len() - low_part.trim_start().len();
+        let high_part = &string[index + separator_len..];
+        let high_str = high_part.trim();
+        let high_offset = index + separator_len + high_part.len() - high_part.trim_start().len();
+        let high = Currencies::try_from(high_str)
+            .map_err(|error| error.offset(high_offset))?;
+        let low = if low_str.contains(' ') {
+            Currencies::try_from(low_str)
+        } else {
+            // The unit is shared with the high price e.g. "2–3 keys".
+            let unit = high_str.rsplit(' ').next().unwrap_or(high_str);
+            
+            Currencies::try_from(format!("{low_str} {unit}").as_str())
+        }.map_err(|error| error.offset(low_offset))?;
+        
+        Self::without_key_price(low, high).map_err(|_| ParseError::InvalidFormat {
+            token: string.to_string(),
+            span: 0..string.len(),
+        })
+    }
+}
+
+/// Finds the index of the hyphen separating two prices in a range.
+fn find_hyphen_separator(string: &str) -> Option<usize> {
+    let hyphens = || string
+        .match_indices('-')
+        .map(|(index, _)| index)
+        // a leading hyphen is not a separator
+        .filter(|index| *index > 0);
+    let before = |index: usize| string[..index].chars().next_back().unwrap_or(' ');
+    let after = |index: usize| string[index + 1..].chars().next().unwrap_or(' ');
+    
+    hyphens()
+        .find(|index| before(*index).is_whitespace() && after(*index).is_whitespace())
+        // a hyphen following whitespace or a comma is the sign of a negative value
+        .or_else(|| hyphens().find(|index| {
+            let before = before(*index);
+            
+            !before.is_whitespace() && before != ','
+        }))
+}
+
+impl FromStr for PriceRange {
+    type Err = ParseError;
+    
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Self::try_from(string)
+    }
+}
+
+impl fmt::Display for PriceRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.low == self.high {
+            write!(f, "{}", self.low)
+        } else {
+            write!(f, "{} – {}", self.low, self.high)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PriceRange {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let range = Self::deserialize(deserializer)?;
+        
+        Self::without_key_price(range.low, range.high).map_err(D::Error::custom)
+    }
+}
+
+impl Serialize for PriceRange {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Self::serialize(self, serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{refined, scrap};
+    
+    #[test]
+    fn rejects_low_greater_than_high() {
+        assert!(PriceRange::new(Currencies {
+            keys: 2,
+            metal: 0,
+        }, Currencies {
+            keys: 1,
+            metal: refined!(10),
+        }, refined!(50)).is_err());
+    }
+    
+    #[test]
+    fn validates_by_value() {
+        let key_price = refined!(50);
+        let range = PriceRange::new(Currencies {
+            keys: 1,
+            metal: 0,
+        }, Currencies {
+            keys: 0,
+            metal: refined!(100),
+        }, key_price).unwrap();
+        
+        assert!(range.contains(&Currencies { keys: 1, metal: refined!(25) }, key_price));
+        assert!(range.contains(&Currencies { keys: 0, metal: refined!(100) }, key_price));
+        assert!(!range.contains(&Currencies { keys: 2, metal: 1 }, key_price));
+        assert!(!range.is_valid(refined!(101)));
+    }
+    
+    #[test]
+    fn contains_compares_by_value() {
+        let key_price = refined!(50);
+        let range = PriceRange::new(Currencies::default(), Currencies {
+            keys: 1,
+            metal: 0,
+        }, key_price).unwrap();
+        
+        assert!(range.contains(&Currencies { keys: 0, metal: refined!(50) }, key_price));
+        assert!(!range.contains(&Currencies { keys: 0, metal: refined!(1000) }, key_price));
+        assert!(!range.contains(&Currencies { keys: 0, metal: -1 }, key_price));
+    }
+    
+    #[test]
+    fn parses_range_with_hyphen() {
+        assert_eq!(PriceRange::try_from("1 key, 5 ref - 1 key, 10.11 ref").unwrap(), PriceRange {
+            low: Currencies {
+                keys: 1,
+                metal: refined!(5),
+            },
+            high: Currencies {
+                keys: 1,
+                metal: refined!(10) + scrap!(1),
+            },
+        });
+    }
+    
+    #[test]
+    fn parses_range_with_negative_component() {
+        assert_eq!(PriceRange::try_from("1 key, -3 ref - 2 keys").unwrap(), PriceRange {
+            low: Currencies {
+                keys: 1,
+                metal: -refined!(3),
+            },
+            high: Currencies {
+                keys: 2,
+                metal: 0,
+            },
+        });
+        assert_eq!(PriceRange::try_from("-2 - -1 keys").unwrap(), PriceRange {
+            low: Currencies {
+                keys: -2,
+                metal: 0,
+            },
+            high: Currencies {
+                keys: -1,
+                metal: 0,
+            },
+        });
+        assert_eq!(PriceRange::try_from("-3--2 ref").unwrap(), PriceRange {
+            low: Currencies {
+                keys: 0,
+                metal: -refined!(3),
+            },
+            high: Currencies {
+                keys: 0,
+                metal: -refined!(2),
+            },
+        });
+    }
+    
+    #[test]
+    fn parses_range_with_shared_unit() {
+        assert_eq!(PriceRange::try_from("2-3 ref").unwrap(), PriceRange {
+            low: Currencies {
+                keys: 0,
+                metal: refined!(2),
+            },
+            high: Currencies {
+                keys: 0,
+                metal: refined!(3),
+            },
+        });
+    }
+    
+    #[test]
+    fn parses_single_price() {
+        let range = PriceRange::try_from("5 keys").unwrap();
+        
+        assert_eq!(range.low, range.high);
+        assert_eq!(range.to_string(), "5 keys");
+    }
+    
+    #[test]
+    fn parses_inverted_range_as_error() {
+        assert!(PriceRange::try_from("3–2 keys").is_err());
+        assert!(PriceRange::try_from("1 key, 5 ref – 1 key, 2 ref").is_err());
+    }
+    
+    #[test]
+    fn parses_range_valid_at_some_key_price() {
+        let range = PriceRange::try_from("1 key – 100 ref").unwrap();
+        
+        assert!(range.is_valid(refined!(60)));
+        assert!(!range.is_valid(refined!(120)));
+    }
+    
+    #[test]
+    fn parses_error_spans_within_range() {
+        let input = "2 – 3.x3 ref";
+        let error = PriceRange::try_from(input).unwrap_err();
+        
+        assert_eq!(&input[error.span().unwrap()], "3.x3");
+        
+        let input = "1.x - 3 ref";
+        let error = PriceRange::try_from(input).unwrap_err();
+        
+        assert_eq!(&input[error.span().unwrap()], "1.x");
+    }
+    
+    #[test]
+    fn parses_display_round_trip() {
+        let range = PriceRange::new(Currencies {
+            keys: 0,
+            metal: refined!(40),
+        }, Currencies {
+            keys: 1,
+            metal: scrap!(3),
+        }, refined!(50)).unwrap();
+        
+        assert_eq!(PriceRange::try_from(range.to_string().as_str()).unwrap(), range);
+    }
+    
+    #[test]
+    fn deserializes_range() {
+        let range: PriceRange = serde_json::from_str(r#"{"low":{"keys":2},"high":{"keys":3,"metal":1.11}}"#).unwrap();
+        
+        assert_eq!(range.high, Currencies {
+            keys: 3,
+            metal: refined!(1) + scrap!(1),
+        });
+    }
+    
+    #[test]
+    fn deserialize_rejects_invalid_range() {
+        assert!(serde_json::from_str::<PriceRange>(r#"{"low":{"keys":3},"high":{"keys":2}}"#).is_err());
+    }
+    
+    #[test]
+    fn serializes_bincode() {
+        let range = PriceRange::new(Currencies {
+            keys: 1,
+            metal: 1,
+        }, Currencies {
+            keys: 2,
+            metal: refined!(5),
+        }, refined!(50)).unwrap();
+        let bytes = bincode::serialize(&range).unwrap();
+        
+        assert_eq!(bincode::deserialize::<PriceRange>(&bytes).unwrap(), range);
+    }
+}
@@ -0,0 +1,125 @@
+//! Types for the prices.tf v2 API.
+//! 
+//! prices.tf gives metal values in half scrap, which are the same as weapons. No conversion is 
+//! needed to use them as metal values.
+//! 
+//! # Examples
+//! ```
+//! use tf2_price::{Currencies, refined, scrap};
+//! use tf2_price::prices_tf::Price;
+//! 
+//! let json = r#"{
+//!     "sku": "5021;6",
+//!     "buyHalfScrap": 1212,
+//!     "buyKeys": 0,
+//!     "buyKeyHalfScrap": null,
+//!     "sellHalfScrap": 1224,
+//!     "sellKeys": 0,
+//!     "sellKeyHalfScrap": null,
+//!     "createdAt": "2023-01-01T00:00:00.000Z",
+//!     "updatedAt": "2023-01-01T00:00:00.000Z"
+//! }"#;
+//! let price: Price = serde_json::from_str(json).unwrap();
+//! let pricing = price.to_pricing();
+//! 
+//! assert_eq!(pricing.buy, Currencies { keys: 0, metal: refined!(67) + scrap!(3) });
+//! assert_eq!(pricing.sell, Currencies { keys: 0, metal: refined!(68) });
+//! ```
+
+use crate::types::Currency;
+use crate::{Currencies, Pricing};
+use serde::{Serialize, Deserialize};
+use alloc::string::String;
+
+/// A price from the prices.tf v2 API.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Price {
+    /// The SKU of the item.
+    pub sku: String,
+    /// The buy price metal value in half scrap (weapons).
+    pub buy_half_scrap: Currency,
+    /// The buy price key value.
+    pub buy_keys: Currency,
+    /// The key price in half scrap (weapons) used for the buy price, if different from the 
+    /// current key price.
+    #[serde(default)]
+    pub buy_key_half_scrap: Option<Currency>,
+    /// The sell price metal value in half scrap (weapons).
+    pub sell_half_scrap: Currency,
+    /// The sell price key value.
+    pub sell_keys: Currency,
+    /// The key price in half scrap (weapons) used for the sell price, if different from the 
+    /// current key price.
+    #[serde(default)]
+    pub sell_key_half_scrap: Option<Currency>,
+    /// When the price was created.
+    #[serde(default)]
+    pub created_at: Option<String>,
+    /// When the price was last updated.
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+impl Price {
+    /// The buy price.
+    pub fn buy(&self) -> Currencies {
+        Currencies {
+            keys: self.buy_keys,
+            metal: self.buy_half_scrap,
+        }
+    }
+    
+    /// The sell price.
+    pub fn sell(&self) -> Currencies {
+        Currencies {
+            keys: self.sell_keys,
+            metal: self.sell_half_scrap,
+        }
+    }
+    
+    /// The buy and sell price. This is not validated, see [`Pricing::is_valid`].
+    pub fn to_pricing(&self) -> Pricing {
+        Pricing {
+            buy: self.buy(),
+            sell: self.sell(),
+        }
+    }
+}
+
+impl From<&Price> for Pricing {
+    fn from(price: &Price) -> Self {
+        price.to_pricing()
+    }
+}
+
+impl From<Price> for Pricing {
+    fn from(price: Price) -> Self {
+        price.to_pricing()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::refined;
+    
+    #[test]
+    fn converts_key_prices() {
+        let json = r#"{
+            "sku": "30911;5;u13",
+            "buyHalfScrap": 36,
+            "buyKeys": 100,
+            "buyKeyHalfScrap": 1224,
+            "sellHalfScrap": 0,
+            "sellKeys": 110,
+            "sellKeyHalfScrap": 1224
+        }"#;
+        let price: Price = serde_json::from_str(json).unwrap();
+        
+        assert_eq!(price.buy(), Currencies { keys: 100, metal: refined!(2) });
+        assert_eq!(price.sell(), Currencies { keys: 110, metal: 0 });
+        assert_eq!(price.buy_key_half_scrap, Some(refined!(68)));
+        assert_eq!(price.created_at, None);
+    }
+}
@@ -0,0 +1,355 @@
+use crate::helpers;
+use crate::types::Currency;
+use crate::error::InvalidPricingError;
+use crate::{Currencies, KeyPrice, RoundMetal};
+use core::cmp::Ordering;
+use serde::{Serialize, Deserialize};
+
+/// The prices an item is bought and sold at. Serializes as `{"buy":{...},"sell":{...}}`, the 
+/// format used by most pricelists.
+/// 
+/// Since validating requires a key price, deserialized values are not validated. Use 
+/// [`Pricing::is_valid`] to check them.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, Pricing, refined};
+/// 
+/// let key_price = refined!(50);
+/// let pricing = Pricing::new(
+///     Currencies { keys: 0, metal: refined!(45) },
+///     Currencies { keys: 1, metal: 0 },
+///     key_price,
+/// ).unwrap();
+/// 
+/// assert_eq!(pricing.spread(), Currencies { keys: 1, metal: -refined!(45) });
+/// assert_eq!(pricing.margin(key_price), 10.0);
+/// ```
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct Pricing {
+    /// The price the item is bought at.
+    pub buy: Currencies,
+    /// The price the item is sold at.
+    pub sell: Currencies,
+}
+
+impl Pricing {
+    /// Creates a new [`Pricing`]. Errors if `sell` is worth less than `buy` using the given key 
+    /// price (represented as weapons).
+    pub fn new(
+        buy: Currencies,
+        sell: Currencies,
+        key_price: Currency,
+    ) -> Result<Self, InvalidPricingError> {
+        let pricing = Self {
+            buy,
+            sell,
+        };
+        
+        if !pricing.is_valid(key_price) {
+            return Err(InvalidPricingError {
+                buy,
+                sell,
+            });
+        }
+        
+        Ok(pricing)
+    }
+    
+    /// Creates a new [`Pricing`] using both sides of a [`KeyPrice`]. Errors if `sell` valued at 
+    /// the key sell price is worth less than `buy` valued at the key buy price.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, KeyPrice, Pricing, refined};
+    /// 
+    /// let key_price = KeyPrice::new(refined!(50), refined!(52)).unwrap();
+    /// let buy = Currencies { keys: 0, metal: refined!(51) };
+    /// let sell = Currencies { keys: 1, metal: 0 };
+    /// 
+    /// assert!(Pricing::with_key_price(buy, sell, &key_price).is_ok());
+    /// assert!(Pricing::new(buy, sell, refined!(50)).is_err());
+    /// ```
+    pub fn with_key_price(
+        buy: Currencies,
+        sell: Currencies,
+        key_price: &KeyPrice,
+    ) -> Result<Self, InvalidPricingError> {
+        let pricing = Self {
+            buy,
+            sell,
+        };
+        
+        if !pricing.is_valid_with_key_price(key_price) {
+            return Err(InvalidPricingError {
+                buy,
+                sell,
+            });
+        }
+        
+        Ok(pricing)
+    }
+    
+    /// Checks that `sell` is worth at least as much as `buy` using the given key price 
+    /// (represented as weapons).
+    pub fn is_valid(&self, key_price: Currency) -> bool {
+        self.sell.cmp_with_key_price(&self.buy, key_price) != Ordering::Less
+    }
+    
+    /// Checks that `sell` valued at the key sell price is worth at least as much as `buy` valued 
+    /// at the key buy price.
+    pub fn is_valid_with_key_price(&self, key_price: &KeyPrice) -> bool {
+        let buy = helpers::to_metal_wide(self.buy.metal, self.buy.keys, key_price.buy);
+        let sell = helpers::to_metal_wide(self.sell.metal, self.sell.keys, key_price.sell);
+        
+        sell >= buy
+    }
+    
+    /// The difference between the sell and buy price for each field. This is not neatened; use 
+    /// [`Currencies::neaten`] to carry metal into keys.
+    pub fn spread(&self) -> Currencies {
+        self.sell - self.buy
+    }
+    
+    /// The difference in value between the sell and buy price as a percentage of the buy price 
+    /// using the given key price (represented as weapons). See 
+    /// [`Currencies::percent_difference`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, Pricing, refined};
+    /// 
+    /// let pricing = Pricing {
+    ///     buy: Currencies { keys: 0, metal: refined!(40) },
+    ///     sell: Currencies { keys: 0, metal: refined!(50) },
+    /// };
+    /// 
+    /// assert_eq!(pricing.spread_percent(refined!(50)), 25.0);
+    /// ```
+    pub fn spread_percent(&self, key_price: Currency) -> f32 {
+        self.buy.percent_difference(&self.sell, key_price)
+    }
+    
+    /// The difference in value between the sell and buy price as a percentage of the sell price 
+    /// using the given key price (represented as weapons). This is the percentage of the sale 
+    /// kept as profit. See [`Profit::margin`].
+    /// 
+    /// # Panics
+    /// Panics if `key_price` is `0`, as with [`Currencies::from_metal`].
+    pub fn margin(&self, key_price: Currency) -> f32 {
+        self.profit(key_price).margin
+    }
+    
+    /// Calculates the profit from buying and then selling using the given key price (represented 
+    /// as weapons).
+    /// 
+    /// # Panics
+    /// Panics if `key_price` is `0`, as with [`Currencies::from_metal`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, Pricing, refined};
+    /// 
+    /// let key_price = refined!(50);
+    /// let pricing = Pricing {
+    ///     buy: Currencies { keys: 0, metal: refined!(45) },
+    ///     sell: Currencies { keys: 1, metal: 0 },
+    /// };
+    /// let profit = pricing.profit(key_price);
+    /// 
+    /// assert_eq!(profit.profit, Currencies { keys: 0, metal: refined!(5) });
+    /// assert_eq!(profit.margin, 10.0);
+    /// ```
+    pub fn profit(&self, key_price: Currency) -> Profit {
+        let sell = helpers::to_metal_wide(self.sell.metal, self.sell.keys, key_price);
+        
+        self.profit_from_sale(sell, key_price)
+    }
+    
+    /// Calculates the profit from buying and then selling using the given key price (represented 
+    /// as weapons), after a fee of `fee_percent` is taken from the sale. The amount received from 
+    /// the sale is rounded using the given rounding method.
+    /// 
+    /// # Panics
+    /// Panics if `key_price` is `0`, as with [`Currencies::from_metal`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Currencies, Pricing, Rounding, refined};
+    /// 
+    /// let key_price = refined!(50);
+    /// let pricing = Pricing {
+    ///     buy: Currencies { keys: 0, metal: refined!(40) },
+    ///     sell: Currencies { keys: 1, metal: 0 },
+    /// };
+    /// // A 10% fee leaves 45 ref from the sale.
+    /// let profit = pricing.profit_after_fee(key_price, 10.0, &Rounding::DownScrap);
+    /// 
+    /// assert_eq!(profit.profit, Currencies { keys: 0, metal: refined!(5) });
+    /// ```
+    pub fn profit_after_fee<R: RoundMetal + ?Sized>(
+        &self,
+        key_price: Currency,
+        fee_percent: f32,
+        rounding: &R,
+    ) -> Profit {
+        let sell = helpers::to_metal_wide(self.sell.metal, self.sell.keys, key_price);
+        let received = rounding.round_metal_float(
+            sell as f64 * (100.0 - fee_percent as f64) / 100.0,
+        );
+        
+        self.profit_from_sale(received as i128, key_price)
+    }
+    
+    fn profit_from_sale(&self, received: i128, key_price: Currency) -> Profit {
+        let buy = helpers::to_metal_wide(self.buy.metal, self.buy.keys, key_price);
+        let profit = received - buy;
+        let metal = profit.clamp(Currency::MIN as i128, Currency::MAX as i128) as Currency;
+        
+        Profit {
+            profit: Currencies::from_metal(metal, key_price),
+            margin: (profit as f64 / received as f64 * 100.0) as f32,
+        }
+    }
+}
+
+/// The profit from buying and then selling an item.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Profit {
+    /// The profit. Negative if the item is sold at a loss.
+    pub profit: Currencies,
+    /// The profit as a percentage of the amount received from the sale. If nothing is received 
+    /// the margin is infinite, or `NaN` if the buy price has no value either.
+    pub margin: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{refined, scrap, Rounding};
+    use serde_json::{self, json, Value};
+    use assert_json_diff::assert_json_eq;
+    
+    #[test]
+    fn rejects_sell_worth_less_than_buy() {
+        assert_eq!(Pricing::new(
+            Currencies { keys: 1, metal: 0 },
+            Currencies { keys: 0, metal: refined!(49) },
+            refined!(50),
+        ), Err(InvalidPricingError {
+            buy: Currencies { keys: 1, metal: 0 },
+            sell: Currencies { keys: 0, metal: refined!(49) },
+        }));
+    }
+    
+    #[test]
+    fn accepts_equal_value() {
+        assert!(Pricing::new(
+            Currencies { keys: 1, metal: 0 },
+            Currencies { keys: 0, metal: refined!(50) },
+            refined!(50),
+        ).is_ok());
+    }
+    
+    #[test]
+    fn calculates_spread() {
+        let pricing = Pricing {
+            buy: Currencies { keys: 1, metal: refined!(2) },
+            sell: Currencies { keys: 1, metal: refined!(3) + scrap!(1) },
+        };
+        
+        assert_eq!(pricing.spread(), Currencies { keys: 0, metal: refined!(1) + scrap!(1) });
+    }
+    
+    #[test]
+    fn calculates_spread_percent_with_keys() {
+        let pricing = Pricing {
+            buy: Currencies { keys: 2, metal: 0 },
+            sell: Currencies { keys: 2, metal: refined!(10) },
+        };
+        
+        assert_eq!(pricing.spread_percent(refined!(50)), 10.0);
+    }
+    
+    #[test]
+    fn calculates_loss() {
+        let pricing = Pricing {
+            buy: Currencies { keys: 1, metal: 0 },
+            sell: Currencies { keys: 0, metal: refined!(40) },
+        };
+        let profit = pricing.profit(refined!(50));
+        
+        assert_eq!(profit.profit, Currencies { keys: 0, metal: -refined!(10) });
+        assert_eq!(profit.margin, -25.0);
+    }
+    
+    #[test]
+    fn calculates_profit_with_keys() {
+        let pricing = Pricing {
+            buy: Currencies { keys: 2, metal: 0 },
+            sell: Currencies { keys: 3, metal: refined!(10) },
+        };
+        
+        assert_eq!(pricing.profit(refined!(50)).profit, Currencies { keys: 1, metal: refined!(10) });
+    }
+    
+    #[test]
+    fn calculates_profit_after_fee_rounding() {
+        let pricing = Pricing {
+            buy: Currencies { keys: 0, metal: refined!(1) },
+            sell: Currencies { keys: 0, metal: refined!(2) },
+        };
+        // 2 ref less 5% is 34.2 weapons
+        let profit = pricing.profit_after_fee(refined!(50), 5.0, &Rounding::DownScrap);
+        
+        assert_eq!(profit.profit, Currencies { keys: 0, metal: refined!(1) - scrap!(1) });
+    }
+    
+    #[test]
+    fn margin_of_nothing_received() {
+        let pricing = Pricing {
+            buy: Currencies { keys: 0, metal: refined!(1) },
+            sell: Currencies { keys: 0, metal: 0 },
+        };
+        
+        assert_eq!(pricing.margin(refined!(50)), f32::NEG_INFINITY);
+        assert!(Pricing { buy: Currencies::ZERO, sell: Currencies::ZERO }.margin(refined!(50)).is_nan());
+    }
+    
+    #[test]
+    fn correct_json_format() {
+        let pricing = Pricing {
+            buy: Currencies { keys: 0, metal: refined!(10) },
+            sell: Currencies { keys: 1, metal: refined!(2) + scrap!(1) },
+        };
+        let pricing_json = serde_json::to_string(&pricing).unwrap();
+        let actual: Value = serde_json::from_str(&pricing_json).unwrap();
+        let expected: Value = json!({
+            "buy": { "metal": 10 },
+            "sell": { "keys": 1, "metal": 2.11 }
+        });
+        
+        assert_json_eq!(
+            actual,
+            expected,
+        );
+        assert_eq!(serde_json::from_str::<Pricing>(&pricing_json).unwrap(), pricing);
+    }
+    
+    #[test]
+    fn validates_with_key_price_sides() {
+        let key_price = KeyPrice::new(refined!(50), refined!(52)).unwrap();
+        let pricing = Pricing {
+            buy: Currencies { keys: 1, metal: 0 },
+            sell: Currencies { keys: 0, metal: refined!(51) },
+        };
+        
+        // keys in the buy price are valued at the key buy price
+        assert!(!pricing.is_valid(refined!(52)));
+        assert!(pricing.is_valid_with_key_price(&key_price));
+        assert!(Pricing::with_key_price(pricing.buy, pricing.sell, &key_price).is_ok());
+        assert!(Pricing::with_key_price(pricing.buy, pricing.sell, &KeyPrice::from(refined!(52))).is_err());
+    }
+}
@@ -0,0 +1,228 @@
+//! [`pyo3`] bindings for [`Currencies`]. Available with the `pyo3` feature.
+//! 
+//! The [`tf2_price`] module exports [`PyCurrencies`] to Python as the `Currencies` class. Values
+//! are immutable, hashable and ordered the same as [`Currencies`]. Like [`Currencies`], `metal` is
+//! represented as weapons so totals never accumulate float errors.
+//! 
+//! To build the Python module, use e.g. `maturin build --features pyo3`.
+//! 
+//! ```python
+//! from tf2_price import Currencies
+//! 
+//! price = Currencies.parse("2 keys, 3.33 ref")
+//! total = price + Currencies(keys=1, metal=18)
+//! 
+//! print(total)  # 3 keys, 4.33 ref
+//! ```
+
+use crate::types::Currency;
+use crate::{helpers, Currencies};
+use pyo3::prelude::*;
+use pyo3::exceptions::{PyValueError, PyZeroDivisionError};
+
+/// A Python class wrapping [`Currencies`].
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, refined};
+/// use tf2_price::python::PyCurrencies;
+/// 
+/// let currencies = PyCurrencies::new(2, refined!(3));
+/// 
+/// assert_eq!(currencies.__str__(), "2 keys, 3 ref");
+/// assert_eq!(Currencies::from(currencies), Currencies { keys: 2, metal: refined!(3) });
+/// ```
+#[pyclass(name = "Currencies", module = "tf2_price", frozen, eq, ord, hash, from_py_object)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct PyCurrencies(Currencies);
+
+#[pymethods]
+impl PyCurrencies {
+    /// Creates currencies from keys and metal (represented as weapons).
+    #[new]
+    #[pyo3(signature = (keys = 0, metal = 0))]
+    pub fn new(keys: Currency, metal: Currency) -> Self {
+        Self(Currencies { keys, metal })
+    }
+    
+    /// Creates currencies from keys and a refined metal value, e.g. `1.33`, rounded to the
+    /// nearest weapon.
+    #[staticmethod]
+    #[pyo3(signature = (keys, refined))]
+    pub fn from_refined(keys: Currency, refined: f64) -> PyResult<Self> {
+        if !refined.is_finite() {
+            return Err(PyValueError::new_err("Refined value must be finite"));
+        }
+        
+        Ok(Self(Currencies {
+            keys,
+            metal: helpers::get_metal_from_float_f64(refined),
+        }))
+    }
+    
+    /// Converts a metal value into keys and metal using the given key price (represented as
+    /// weapons).
+    #[staticmethod]
+    pub fn from_metal(metal: Currency, key_price: Currency) -> PyResult<Self> {
+        Currencies::checked_from_metal(metal, key_price)
+            .map(Self)
+            .ok_or_else(|| PyZeroDivisionError::new_err("Key price must not be 0"))
+    }
+    
+    /// Parses currencies from a string, e.g. "2 keys, 3.33 ref".
+    #[staticmethod]
+    pub fn parse(string: &str) -> PyResult<Self> {
+        string.parse::<Currencies>()
+            .map(Self)
+            .map_err(|error| PyValueError::new_err(error.to_string()))
+    }
+    
+    /// The number of keys.
+    #[getter]
+    pub fn keys(&self) -> Currency {
+        self.0.keys
+    }
+    
+    /// The metal value (represented as weapons).
+    #[getter]
+    pub fn metal(&self) -> Currency {
+        self.0.metal
+    }
+    
+    /// The metal value in refined, truncated to 2 decimal places, e.g. `1.33`.
+    #[getter]
+    pub fn metal_refined(&self) -> f64 {
+        helpers::get_metal_float_f64(self.0.metal)
+    }
+    
+    /// Converts currencies to a metal value using the given key price (represented as weapons).
+    /// This method is saturating.
+    pub fn to_metal(&self, key_price: Currency) -> Currency {
+        self.0.to_metal(key_price)
+    }
+    
+    /// Formats the total value as keys using the given key price (represented as weapons), e.g.
+    /// "2.5 keys".
+    #[pyo3(signature = (key_price, precision = 2))]
+    pub fn format_keys(&self, key_price: Currency, precision: u8) -> PyResult<String> {
+        if key_price <= 0 {
+            return Err(PyValueError::new_err("Key price must be positive"));
+        }
+        
+        Ok(self.0.display_as_keys(key_price, precision).to_string())
+    }
+    
+    /// Checks if the currencies contain no keys or metal.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+    
+    /// Adds currencies. This method is saturating.
+    pub fn __add__(&self, other: &Self) -> Self {
+        Self(self.0 + other.0)
+    }
+    
+    /// Subtracts currencies. This method is saturating.
+    pub fn __sub__(&self, other: &Self) -> Self {
+        Self(self.0 - other.0)
+    }
+    
+    /// Multiplies both fields by an integer. This method is saturating.
+    pub fn __mul__(&self, n: Currency) -> Self {
+        Self(self.0 * n)
+    }
+    
+    /// Multiplies both fields by an integer. This method is saturating.
+    pub fn __rmul__(&self, n: Currency) -> Self {
+        Self(self.0 * n)
+    }
+    
+    /// Divides both fields by an integer, rounding towards zero.
+    pub fn __floordiv__(&self, n: Currency) -> PyResult<Self> {
+        self.0.checked_div(n)
+            .map(Self)
+            .ok_or_else(|| PyZeroDivisionError::new_err("Cannot divide by 0"))
+    }
+    
+    /// Negates both fields. This method is saturating.
+    pub fn __neg__(&self) -> Self {
+        Self(-self.0)
+    }
+    
+    /// Whether the currencies contain any keys or metal.
+    pub fn __bool__(&self) -> bool {
+        !self.0.is_empty()
+    }
+    
+    /// Formats the currencies, e.g. "2 keys, 3.33 ref".
+    pub fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+    
+    /// Formats the currencies as a constructor call, e.g. "Currencies(keys=2, metal=60)".
+    pub fn __repr__(&self) -> String {
+        format!("Currencies(keys={}, metal={})", self.0.keys, self.0.metal)
+    }
+}
+
+impl From<Currencies> for PyCurrencies {
+    fn from(currencies: Currencies) -> Self {
+        Self(currencies)
+    }
+}
+
+impl From<PyCurrencies> for Currencies {
+    fn from(currencies: PyCurrencies) -> Self {
+        currencies.0
+    }
+}
+
+/// The `tf2_price` Python module.
+#[pymodule]
+pub fn tf2_price(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyCurrencies>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{refined, scrap};
+    
+    #[test]
+    fn creates_currencies() {
+        let currencies = PyCurrencies::from_refined(2, 3.33).unwrap();
+        
+        assert_eq!(currencies, PyCurrencies::new(2, refined!(3) + scrap!(3)));
+        assert_eq!(currencies.metal_refined(), 3.33);
+        assert!(PyCurrencies::from_refined(2, f64::NAN).is_err());
+        assert!(PyCurrencies::from_metal(refined!(10), 0).is_err());
+    }
+    
+    #[test]
+    fn parses_and_formats() {
+        let currencies = PyCurrencies::parse("2 keys, 3.33 ref").unwrap();
+        
+        assert_eq!(currencies.__str__(), "2 keys, 3.33 ref");
+        assert_eq!(currencies.__repr__(), "Currencies(keys=2, metal=60)");
+        assert_eq!(
+            PyCurrencies::new(2, refined!(25)).format_keys(refined!(50), 1).unwrap(),
+            "2.5 keys",
+        );
+        assert!(PyCurrencies::parse("2 kittens").is_err());
+    }
+    
+    #[test]
+    fn performs_arithmetic() {
+        let a = PyCurrencies::new(2, refined!(3));
+        let b = PyCurrencies::new(1, refined!(1));
+        
+        assert_eq!(a.__add__(&b), PyCurrencies::new(3, refined!(4)));
+        assert_eq!(a.__sub__(&b), PyCurrencies::new(1, refined!(2)));
+        assert_eq!(a.__mul__(2), PyCurrencies::new(4, refined!(6)));
+        assert_eq!(a.__floordiv__(2).unwrap(), PyCurrencies::new(1, 27));
+        assert_eq!(a.__neg__(), PyCurrencies::new(-2, -refined!(3)));
+        assert_eq!(a.to_metal(refined!(50)), refined!(103));
+        assert!(!PyCurrencies::default().__bool__());
+    }
+}
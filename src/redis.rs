@@ -0,0 +1,138 @@
+//! [`redis`] support for [`Currencies`]. Available with the `redis` feature.
+//! 
+//! Currencies are written to Redis as their display string, e.g. "2 keys, 3.33 ref", which is 
+//! parsed back into the same currencies, including "nothing" for zero currencies. They can also 
+//! be read from a hash containing `keys` and `metal` (represented as weapons) fields, such as one 
+//! written using [`hash_fields`] with `HSET`.
+//! 
+//! # Examples
+//! ```
+//! use tf2_price::{Currencies, refined};
+//! use redis::{FromRedisValue, ToRedisArgs, Value};
+//! 
+//! let currencies = Currencies { keys: 2, metal: refined!(3) };
+//! 
+//! assert_eq!(currencies.to_redis_args(), vec![b"2 keys, 3 ref".to_vec()]);
+//! 
+//! let value = Value::BulkString(b"2 keys, 3 ref".to_vec());
+//! 
+//! assert_eq!(Currencies::from_redis_value(value).unwrap(), currencies);
+//! ```
+
+use crate::types::Currency;
+use crate::{Currencies, ParseOptions};
+use std::collections::HashMap;
+use ::redis::{FromRedisValue, ParsingError, RedisWrite, ToRedisArgs, Value};
+
+const KEYS_FIELD: &str = "keys";
+const METAL_FIELD: &str = "metal";
+
+/// The fields and values for storing currencies in a hash, e.g. using `HSET` with 
+/// `hset_multiple`. Metal is represented as weapons so the value is exact.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, refined};
+/// 
+/// let currencies = Currencies { keys: 2, metal: refined!(3) };
+/// 
+/// assert_eq!(tf2_price::redis::hash_fields(&currencies), [("keys", 2), ("metal", 54)]);
+/// ```
+pub fn hash_fields(currencies: &Currencies) -> [(&'static str, Currency); 2] {
+    [
+        (KEYS_FIELD, currencies.keys),
+        (METAL_FIELD, currencies.metal),
+    ]
+}
+
+impl ToRedisArgs for Currencies {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg_fmt(self);
+    }
+}
+
+impl FromRedisValue for Currencies {
+    fn from_redis_value(value: Value) -> Result<Self, ParsingError> {
+        if let Value::Array(_) | Value::Map(_) = value {
+            let fields = HashMap::<String, Currency>::from_redis_value(value)?;
+            let keys = fields.get(KEYS_FIELD).copied();
+            let metal = fields.get(METAL_FIELD).copied();
+            
+            if keys.is_none() && metal.is_none() {
+                return Err("Does not contain values for keys or metal".to_string().into());
+            }
+            
+            return Ok(Self {
+                keys: keys.unwrap_or_default(),
+                metal: metal.unwrap_or_default(),
+            });
+        }
+        
+        let string = String::from_redis_value(value)?;
+        // Zero currencies are displayed as "nothing".
+        let options = ParseOptions::new().allow_empty(true);
+        
+        Currencies::from_str_with(&string, &options).map_err(|error| error.to_string().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{refined, scrap};
+    
+    fn bulk(string: &str) -> Value {
+        Value::BulkString(string.as_bytes().to_vec())
+    }
+    
+    #[test]
+    fn writes_display_string() {
+        let currencies = Currencies { keys: -2, metal: -(refined!(3) + scrap!(3)) };
+        
+        assert_eq!(currencies.to_redis_args(), vec![b"-2 keys, -3.33 ref".to_vec()]);
+    }
+    
+    #[test]
+    fn reads_string() {
+        let currencies = Currencies { keys: 1, metal: refined!(3) + scrap!(3) + 1 };
+        let args = currencies.to_redis_args();
+        let value = Value::BulkString(args[0].clone());
+        
+        assert_eq!(Currencies::from_redis_value(value).unwrap(), currencies);
+        assert!(Currencies::from_redis_value(bulk("2 kegs")).is_err());
+        
+        let zero = Value::BulkString(Currencies::ZERO.to_redis_args().remove(0));
+        
+        assert_eq!(Currencies::from_redis_value(zero).unwrap(), Currencies::ZERO);
+        assert!(Currencies::from_redis_value(Value::Nil).is_err());
+    }
+    
+    #[test]
+    fn reads_hash() {
+        let currencies = Currencies { keys: 2, metal: refined!(3) + 1 };
+        let pairs = hash_fields(&currencies)
+            .map(|(field, value)| (bulk(field), bulk(&value.to_string())));
+        let array = Value::Array(pairs
+            .iter()
+            .flat_map(|(field, value)| [field.clone(), value.clone()])
+            .collect());
+        let map = Value::Map(pairs.to_vec());
+        
+        assert_eq!(Currencies::from_redis_value(array).unwrap(), currencies);
+        assert_eq!(Currencies::from_redis_value(map).unwrap(), currencies);
+        assert_eq!(
+            Currencies::from_redis_value(Value::Map(vec![(bulk("metal"), Value::Int(6))])).unwrap(),
+            Currencies { keys: 0, metal: 6 },
+        );
+        
+        let zero = hash_fields(&Currencies::ZERO)
+            .map(|(field, value)| (bulk(field), Value::Int(value)))
+            .to_vec();
+        
+        assert_eq!(Currencies::from_redis_value(Value::Map(zero)).unwrap(), Currencies::ZERO);
+        assert!(Currencies::from_redis_value(Value::Array(Vec::new())).is_err());
+    }
+}
@@ -1,5 +1,36 @@
+use crate::error::ParseRoundingError;
+use crate::helpers;
+use crate::types::Currency;
+use crate::constants::{ONE_REF, ONE_SCRAP};
+use core::fmt;
+use core::str::FromStr;
+use serde::{Serialize, Deserialize};
+use alloc::borrow::ToOwned;
+
 /// Rounding methods for metal values.
-#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+/// 
+/// The "up" and "down" methods round towards positive and negative infinity respectively, so 
+/// rounding a negative value up reduces its magnitude. Use the "toward-zero" and "away-from-zero" 
+/// methods to round by magnitude regardless of sign.
+/// 
+/// Rounding methods are written in kebab-case for [`Display`](std::fmt::Display), 
+/// [`FromStr`] and serde, e.g. "up-scrap", so they can be stored in config files or passed as 
+/// command-line flags.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::Rounding;
+/// 
+/// let rounding: Rounding = "up-scrap".parse().unwrap();
+/// 
+/// assert_eq!(rounding, Rounding::UpScrap);
+/// assert_eq!(rounding.to_string(), "up-scrap");
+/// ```
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "ts-rs", ts(rename_all = "kebab-case"))]
 pub enum Rounding {
     /// Rounds up to the nearest scrap.
     UpScrap,
@@ -11,6 +42,389 @@ pub enum Rounding {
     UpRefined,
     /// Rounds down to the nearest refined.
     DownRefined,
+    /// Rounds to the nearest scrap, rounding half-way values to the nearest even number of scrap 
+    /// (banker's rounding). Unlike [`Rounding::UpScrap`] this does not bias totals upward when 
+    /// many values are rounded.
+    ToEvenScrap,
+    /// Rounds towards zero to the nearest scrap, e.g. `-5` weapons becomes `-4` weapons.
+    TowardZeroScrap,
+    /// Rounds away from zero to the nearest scrap, e.g. `-5` weapons becomes `-6` weapons.
+    AwayFromZeroScrap,
+    /// Rounds towards zero to the nearest refined, e.g. `-1.44` ref becomes `-1` ref.
+    TowardZeroRefined,
+    /// Rounds away from zero to the nearest refined, e.g. `-1.44` ref becomes `-2` ref.
+    AwayFromZeroRefined,
     /// No rounding.
     None,
-}
\ No newline at end of file
+}
+
+impl Rounding {
+    /// All rounding methods.
+    pub const ALL: [Rounding; 11] = [
+        Self::UpScrap,
+        Self::DownScrap,
+        Self::Refined,
+        Self::UpRefined,
+        Self::DownRefined,
+        Self::ToEvenScrap,
+        Self::TowardZeroScrap,
+        Self::AwayFromZeroScrap,
+        Self::TowardZeroRefined,
+        Self::AwayFromZeroRefined,
+        Self::None,
+    ];
+    
+    /// The name of the rounding method in kebab-case e.g. "up-scrap".
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::UpScrap => "up-scrap",
+            Self::DownScrap => "down-scrap",
+            Self::Refined => "refined",
+            Self::UpRefined => "up-refined",
+            Self::DownRefined => "down-refined",
+            Self::ToEvenScrap => "to-even-scrap",
+            Self::TowardZeroScrap => "toward-zero-scrap",
+            Self::AwayFromZeroScrap => "away-from-zero-scrap",
+            Self::TowardZeroRefined => "toward-zero-refined",
+            Self::AwayFromZeroRefined => "away-from-zero-refined",
+            Self::None => "none",
+        }
+    }
+    
+    /// Rounds a metal value (represented as weapons) using this rounding method. Can be used in 
+    /// `const` contexts, e.g. to build price tables. Results saturate at integer bounds.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Rounding, refined, scrap};
+    /// 
+    /// const PRICE: i64 = Rounding::UpRefined.round(refined!(1) + scrap!(3));
+    /// 
+    /// assert_eq!(PRICE, refined!(2));
+    /// assert_eq!(Rounding::DownScrap.round(5), 4);
+    /// ```
+    pub const fn round(self, metal: Currency) -> Currency {
+        helpers::saturate(self.round_wide(metal as i128))
+    }
+    
+    /// Rounds a metal value (represented as weapons) using this rounding method. `None` if the 
+    /// result overflows integer bounds.
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{Rounding, refined};
+    /// 
+    /// assert_eq!(Rounding::UpRefined.checked_round(refined!(1) + 1), Some(refined!(2)));
+    /// assert_eq!(Rounding::UpRefined.checked_round(i64::MAX), None);
+    /// assert_eq!(Rounding::DownRefined.checked_round(i64::MIN), None);
+    /// ```
+    pub const fn checked_round(self, metal: Currency) -> Option<Currency> {
+        let rounded = self.round_wide(metal as i128);
+        
+        if rounded > Currency::MAX as i128 || rounded < Currency::MIN as i128 {
+            None
+        } else {
+            Some(rounded as Currency)
+        }
+    }
+    
+    /// Rounds a metal value widened to [`i128`] so that rounding values near the integer bounds 
+    /// of [`Currency`] cannot overflow.
+    const fn round_wide(self, metal: i128) -> i128 {
+        const REFINED: i128 = ONE_REF as i128;
+        const SCRAP: i128 = ONE_SCRAP as i128;
+        
+        if metal == 0 {
+            return metal;
+        }
+        
+        match self {
+            // No rounding needed if the metal value is an even number.
+            Self::UpScrap if metal % 2 != 0 => {
+                metal + 1
+            },
+            // No rounding needed if the metal value is an even number.
+            Self::DownScrap if metal % 2 != 0 => {
+                metal - 1
+            },
+            // Rounded using floor division so that negative values round to the nearest refined 
+            // rather than towards zero. Halves are rounded up.
+            Self::Refined => {
+                (metal + REFINED / 2).div_euclid(REFINED) * REFINED
+            },
+            Self::UpRefined => {
+                let remainder = metal % REFINED;
+                
+                if remainder != 0 {
+                    if metal > 0 {
+                        metal - (remainder + -REFINED)
+                    } else {
+                        metal - remainder
+                    }
+                } else {
+                    metal
+                }
+            },
+            Self::DownRefined => {
+                let remainder = metal % REFINED;
+                
+                if remainder != 0 {
+                    if metal > 0 {
+                        metal - remainder
+                    } else {
+                        metal - (remainder + REFINED)
+                    }
+                } else {
+                    metal
+                }
+            },
+            // Odd metal values are half-way between two scrap values, one of which is an even 
+            // number of scrap.
+            Self::ToEvenScrap if metal % 2 != 0 => {
+                if (metal - 1).rem_euclid(SCRAP * 2) == 0 {
+                    metal - 1
+                } else {
+                    metal + 1
+                }
+            },
+            // The remainder of an odd metal value is always 1 or -1.
+            Self::TowardZeroScrap if metal % 2 != 0 => {
+                metal - metal.signum()
+            },
+            Self::AwayFromZeroScrap if metal % 2 != 0 => {
+                metal + metal.signum()
+            },
+            Self::TowardZeroRefined => {
+                metal - metal % REFINED
+            },
+            Self::AwayFromZeroRefined => {
+                let remainder = metal % REFINED;
+                
+                if remainder != 0 {
+                    metal - remainder + REFINED * metal.signum()
+                } else {
+                    metal
+                }
+            },
+            _ => {
+                metal
+            },
+        }
+    }
+}
+
+/// A method for rounding metal values. Methods which accept a rounding method take any 
+/// implementation of this trait, so applications can plug in their own rounding rules as a type 
+/// or a closure. [`Rounding`] implements it for the built-in methods.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, RoundMetal, Rounding, refined, scrap};
+/// 
+/// // Rounds up to the nearest scrap, but never beyond 50 ref.
+/// let capped = |metal: i64| Rounding::UpScrap.round(metal).min(refined!(50));
+/// let currencies = Currencies { keys: 0, metal: refined!(50) - 1 };
+/// 
+/// assert_eq!(capped.round_metal(5), 6);
+/// assert_eq!(currencies.round(&capped).metal, refined!(50));
+/// assert_eq!(currencies.round(&Rounding::UpScrap).metal, refined!(50));
+/// ```
+pub trait RoundMetal {
+    /// Rounds a metal value (represented as weapons).
+    fn round_metal(&self, metal: Currency) -> Currency;
+    
+    /// Rounds a fractional metal value (represented as weapons) into a whole metal value. By 
+    /// default the value is rounded to the nearest weapon, saturating at integer bounds, before 
+    /// being passed to [`RoundMetal::round_metal`]. Implement this directly to round without 
+    /// losing precision first.
+    fn round_metal_float(&self, metal: f64) -> Currency {
+        self.round_metal(helpers::round_metal_float(metal, &Rounding::None))
+    }
+}
+
+impl RoundMetal for Rounding {
+    fn round_metal(&self, metal: Currency) -> Currency {
+        helpers::round_metal(metal, self)
+    }
+    
+    fn round_metal_float(&self, metal: f64) -> Currency {
+        helpers::round_metal_float(metal, self)
+    }
+}
+
+impl<F> RoundMetal for F
+where
+    F: Fn(Currency) -> Currency,
+{
+    fn round_metal(&self, metal: Currency) -> Currency {
+        self(metal)
+    }
+}
+
+/// Directions for rounding metal values to a multiple, used by 
+/// [`round_metal_to_multiple`](crate::round_metal_to_multiple).
+/// 
+/// Directions are written in kebab-case for serde, e.g. "toward-zero".
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "ts-rs", ts(rename_all = "kebab-case"))]
+pub enum RoundingDirection {
+    /// Rounds to the nearest multiple. Half-way values are rounded up, matching 
+    /// [`Rounding::Refined`].
+    Nearest,
+    /// Rounds up towards positive infinity.
+    Up,
+    /// Rounds down towards negative infinity.
+    Down,
+    /// Rounds towards zero.
+    TowardZero,
+    /// Rounds away from zero.
+    AwayFromZero,
+}
+
+impl fmt::Display for Rounding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+impl FromStr for Rounding {
+    type Err = ParseRoundingError;
+    
+    /// Parses a rounding method from its name in kebab-case, ignoring case.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|rounding| rounding.name().eq_ignore_ascii_case(string))
+            .ok_or_else(|| ParseRoundingError {
+                name: string.to_owned(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    #[test]
+    fn rounds_in_const_context() {
+        const PRICES: [Currency; 3] = [
+            Rounding::UpScrap.round(5),
+            Rounding::Refined.round(ONE_REF + 9),
+            Rounding::AwayFromZeroRefined.round(-ONE_REF - 1),
+        ];
+        
+        assert_eq!(PRICES, [6, ONE_REF * 2, -ONE_REF * 2]);
+    }
+    
+    #[test]
+    fn rounds_near_integer_bounds() {
+        for rounding in Rounding::ALL {
+            for metal in [Currency::MAX, Currency::MAX - 1, Currency::MIN, Currency::MIN + 1] {
+                let rounded = rounding.round(metal);
+                
+                assert_eq!(rounding.checked_round(metal).unwrap_or(rounded), rounded);
+            }
+        }
+        
+        assert_eq!(Rounding::UpScrap.round(Currency::MAX), Currency::MAX);
+        assert_eq!(Rounding::UpScrap.checked_round(Currency::MAX), None);
+        assert_eq!(Rounding::AwayFromZeroRefined.checked_round(Currency::MAX), None);
+        assert_eq!(Rounding::AwayFromZeroRefined.round(Currency::MIN + 1), Currency::MIN);
+        assert_eq!(Rounding::DownScrap.checked_round(Currency::MAX), Some(Currency::MAX - 1));
+        assert_eq!(Rounding::TowardZeroRefined.checked_round(Currency::MIN), Some(Currency::MIN + 8));
+        assert_eq!(Rounding::None.checked_round(Currency::MIN), Some(Currency::MIN));
+        assert_eq!(Rounding::Refined.round(Currency::MIN + 1) % ONE_REF, 0);
+        assert_eq!(Rounding::Refined.checked_round(Currency::MIN + 1), Some(Currency::MIN + 8));
+        assert_eq!(Rounding::Refined.round(Currency::MAX - 1) % ONE_REF, 0);
+    }
+    
+    #[test]
+    fn rounds_with_custom_method() {
+        struct Sell;
+        
+        impl RoundMetal for Sell {
+            fn round_metal(&self, metal: Currency) -> Currency {
+                Rounding::UpRefined.round(metal)
+            }
+        }
+        
+        let currencies = crate::Currencies { keys: 0, metal: 4 };
+        
+        assert_eq!(currencies.round(&Sell).metal, ONE_REF);
+        assert_eq!(currencies.round(&|metal: Currency| metal * 2).metal, 8);
+        assert_eq!(Sell.round_metal_float(0.4), 0);
+        assert_eq!((&Sell as &dyn RoundMetal).round_metal_float(0.6), ONE_REF);
+        assert_eq!(Rounding::UpScrap.round_metal_float(4.5), 6);
+    }
+    
+    #[test]
+    fn parses_display_round_trip() {
+        for rounding in Rounding::ALL {
+            assert_eq!(rounding.to_string().parse::<Rounding>().unwrap(), rounding);
+        }
+    }
+    
+    #[test]
+    fn parses_ignoring_case() {
+        assert_eq!("Up-Scrap".parse::<Rounding>().unwrap(), Rounding::UpScrap);
+        assert_eq!("NONE".parse::<Rounding>().unwrap(), Rounding::None);
+    }
+    
+    #[test]
+    fn parses_invalid_name() {
+        assert_eq!("upscrap".parse::<Rounding>(), Err(ParseRoundingError {
+            name: "upscrap".into(),
+        }));
+        assert!("".parse::<Rounding>().is_err());
+    }
+    
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn generates_json_schema() {
+        let schema = serde_json::to_value(schemars::schema_for!(Rounding)).unwrap();
+        // a schema is generated for each variant to include its description
+        let names = schema["oneOf"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|variant| variant["enum"][0].as_str().unwrap())
+            .collect::<Vec<_>>();
+        
+        assert_eq!(names, Rounding::ALL.map(|rounding| rounding.name()));
+    }
+    
+    #[cfg(feature = "ts-rs")]
+    #[test]
+    fn generates_typescript_declaration() {
+        use ts_rs::TS;
+        
+        assert_eq!(
+            Rounding::decl(),
+            r#"type Rounding = "up-scrap" | "down-scrap" | "refined" | "up-refined" | "down-refined" | "to-even-scrap" | "toward-zero-scrap" | "away-from-zero-scrap" | "toward-zero-refined" | "away-from-zero-refined" | "none";"#,
+        );
+    }
+    
+    #[test]
+    fn serializes_direction_kebab_case() {
+        assert_eq!(serde_json::to_value(RoundingDirection::TowardZero).unwrap(), json!("toward-zero"));
+        assert_eq!(
+            serde_json::from_value::<RoundingDirection>(json!("away-from-zero")).unwrap(),
+            RoundingDirection::AwayFromZero,
+        );
+    }
+    
+    #[test]
+    fn serializes_kebab_case() {
+        assert_eq!(serde_json::to_value(Rounding::DownRefined).unwrap(), json!("down-refined"));
+        assert_eq!(serde_json::from_value::<Rounding>(json!("up-scrap")).unwrap(), Rounding::UpScrap);
+        assert!(serde_json::from_value::<Rounding>(json!("UpScrap")).is_err());
+        assert_eq!(
+            serde_json::from_value::<Rounding>(json!("away-from-zero-refined")).unwrap(),
+            Rounding::AwayFromZeroRefined,
+        );
+    }
+}
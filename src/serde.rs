@@ -0,0 +1,500 @@
+//! Helpers for serializing and deserializing values in other representations.
+//! 
+//! Use these with `#[serde(with = "...")]` on fields of your own structs. The `metal_*` modules 
+//! are for metal fields (represented as weapons), named for the unit they count metal in. When 
+//! deserializing from human-readable formats, integers, floats and numeric strings are all 
+//! accepted in that unit since APIs are often inconsistent in how they write numbers.
+//! 
+//! Formats which are not human-readable, such as bincode, store metal values as integer weapons 
+//! in every module so that values are exact.
+//! 
+//! # Examples
+//! ```
+//! use serde::{Serialize, Deserialize};
+//! use tf2_price::{refined, scrap};
+//! 
+//! #[derive(Serialize, Deserialize, PartialEq, Debug)]
+//! struct Item {
+//!     #[serde(with = "tf2_price::serde::metal_float")]
+//!     price: i64,
+//! }
+//! 
+//! let item = Item { price: refined!(3) + scrap!(3) };
+//! let json = serde_json::to_string(&item).unwrap();
+//! 
+//! assert_eq!(json, r#"{"price":3.33}"#);
+//! assert_eq!(serde_json::from_str::<Item>(&json).unwrap(), item);
+//! ```
+
+/// Serializes metal values (represented as weapons) as refined floats truncated to 2 decimal 
+/// places, e.g. `3.33`. Deserializes refined values, rounding to the nearest weapon.
+pub mod metal_float {
+    use crate::helpers;
+    use crate::types::Currency;
+    use serde::{Serializer, Deserializer};
+    
+    /// Serializes a metal value as a refined float.
+    pub fn serialize<S>(value: &Currency, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        if !serializer.is_human_readable() {
+            return serializer.serialize_i64(*value);
+        }
+        
+        serializer.serialize_f64(helpers::get_metal_float_f64(*value))
+    }
+    
+    /// Deserializes a refined value as a metal value.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Currency, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        helpers::metal_deserializer(deserializer)
+    }
+}
+
+/// Serializes metal values as integer weapons, e.g. `60` for 3.33 refined. Deserializes weapon 
+/// values, rounding to the nearest weapon.
+pub mod metal_weapons {
+    use crate::helpers;
+    use crate::types::Currency;
+    use crate::constants::ONE_WEAPON;
+    use serde::{Serializer, Deserializer};
+    
+    /// Serializes a metal value as weapons.
+    pub fn serialize<S>(value: &Currency, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        serializer.serialize_i64(*value)
+    }
+    
+    /// Deserializes a weapon value as a metal value.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Currency, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        helpers::deserialize_metal(deserializer, ONE_WEAPON)
+    }
+}
+
+/// Serializes metal values as scrap, e.g. `30` for 3.33 refined. Values containing an odd weapon 
+/// are serialized as floats, e.g. `0.5`. Deserializes scrap values, rounding to the nearest 
+/// weapon.
+pub mod metal_scrap {
+    use crate::helpers;
+    use crate::types::Currency;
+    use crate::constants::ONE_SCRAP;
+    use serde::{Serializer, Deserializer};
+    
+    /// Serializes a metal value as scrap.
+    pub fn serialize<S>(value: &Currency, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        if !serializer.is_human_readable() {
+            return serializer.serialize_i64(*value);
+        }
+        
+        if value % ONE_SCRAP == 0 {
+            serializer.serialize_i64(value / ONE_SCRAP)
+        } else {
+            serializer.serialize_f64(*value as f64 / ONE_SCRAP as f64)
+        }
+    }
+    
+    /// Deserializes a scrap value as a metal value.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Currency, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        helpers::deserialize_metal(deserializer, ONE_SCRAP)
+    }
+}
+
+/// Serializes values as their [`Display`](std::fmt::Display) string and parses them back using 
+/// [`FromStr`](std::str::FromStr), e.g. `"2 keys, 3.33 ref"` for [`Currencies`](crate::Currencies). 
+/// Useful for human-editable config files where nested objects are clunky.
+/// 
+/// Zero [`Currencies`](crate::Currencies) and [`ListingCurrencies`](crate::ListingCurrencies) 
+/// are written as "nothing", which [`FromStr`](std::str::FromStr) rejects as empty, so zero 
+/// values cannot be round-tripped through this format. Use [`string_option`] to store a missing 
+/// price as `null` instead.
+/// 
+/// # Examples
+/// ```
+/// use serde::{Serialize, Deserialize};
+/// use tf2_price::{Currencies, refined, scrap};
+/// 
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Config {
+///     #[serde(with = "tf2_price::serde::string")]
+///     price: Currencies,
+/// }
+/// 
+/// let config = Config { price: Currencies { keys: 2, metal: refined!(3) + scrap!(3) } };
+/// let json = serde_json::to_string(&config).unwrap();
+/// 
+/// assert_eq!(json, r#"{"price":"2 keys, 3.33 ref"}"#);
+/// assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+/// 
+/// let zero = serde_json::to_string(&Config { price: Currencies::ZERO }).unwrap();
+/// 
+/// assert_eq!(zero, r#"{"price":"nothing"}"#);
+/// assert!(serde_json::from_str::<Config>(&zero).is_err());
+/// ```
+pub mod string {
+    use core::fmt::Display;
+    use core::str::FromStr;
+    use serde::{Serializer, Deserialize, Deserializer};
+    use serde::de::Error;
+    use alloc::string::String;
+    
+    /// Serializes a value as its display string.
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Display,
+        S: Serializer,
+    {
+        serializer.collect_str(value)
+    }
+    
+    /// Deserializes a value by parsing a string.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        let string = String::deserialize(deserializer)?;
+        
+        string.parse().map_err(D::Error::custom)
+    }
+}
+
+/// Generates a module for `Option` fields which wraps a helper module for values of the given 
+/// type.
+macro_rules! option_module {
+    ( $(#[$meta:meta])* $name:ident, $module:ident, $type:ty ) => {
+        $(#[$meta])*
+        pub mod $name {
+            use crate::types::Currency;
+            use serde::{Serialize, Serializer, Deserialize, Deserializer};
+            
+            struct Wrapper($type);
+            
+            impl Serialize for Wrapper {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer
+                {
+                    super::$module::serialize(&self.0, serializer)
+                }
+            }
+            
+            impl<'de> Deserialize<'de> for Wrapper {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>
+                {
+                    super::$module::deserialize(deserializer).map(Wrapper)
+                }
+            }
+            
+            #[doc = concat!("Serializes an optional value using [`", stringify!($module), "`](super::", stringify!($module), ").")]
+            pub fn serialize<S>(value: &Option<Currency>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer
+            {
+                value.map(Wrapper).serialize(serializer)
+            }
+            
+            #[doc = concat!("Deserializes an optional value using [`", stringify!($module), "`](super::", stringify!($module), ").")]
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Currency>, D::Error>
+            where
+                D: Deserializer<'de>
+            {
+                Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|wrapper| wrapper.0))
+            }
+        }
+    };
+}
+
+option_module!(
+    /// [`metal_float`] for `Option` fields. Add `#[serde(default)]` to allow missing fields.
+    metal_float_option, metal_float, Currency
+);
+
+option_module!(
+    /// [`metal_weapons`] for `Option` fields. Add `#[serde(default)]` to allow missing fields.
+    metal_weapons_option, metal_weapons, Currency
+);
+
+option_module!(
+    /// [`metal_scrap`] for `Option` fields. Add `#[serde(default)]` to allow missing fields.
+    metal_scrap_option, metal_scrap, Currency
+);
+
+/// [`string`] for `Option` fields. Add `#[serde(default)]` to allow missing fields.
+/// 
+/// # Examples
+/// ```
+/// use serde::{Serialize, Deserialize};
+/// use tf2_price::Currencies;
+/// 
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Config {
+///     #[serde(with = "tf2_price::serde::string_option", default)]
+///     max_price: Option<Currencies>,
+/// }
+/// 
+/// let config = serde_json::from_str::<Config>(r#"{"max_price":"5 keys"}"#).unwrap();
+/// 
+/// assert_eq!(config.max_price, Some(Currencies { keys: 5, metal: 0 }));
+/// assert_eq!(serde_json::from_str::<Config>("{}").unwrap().max_price, None);
+/// ```
+pub mod string_option {
+    use core::fmt::Display;
+    use core::str::FromStr;
+    use serde::{Serialize, Serializer, Deserialize, Deserializer};
+    
+    struct Wrapper<T>(T);
+    
+    impl<T> Serialize for Wrapper<&T>
+    where
+        T: Display,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer
+        {
+            super::string::serialize(self.0, serializer)
+        }
+    }
+    
+    impl<'de, T> Deserialize<'de> for Wrapper<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>
+        {
+            super::string::deserialize(deserializer).map(Wrapper)
+        }
+    }
+    
+    /// Serializes an optional value as its display string.
+    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Display,
+        S: Serializer,
+    {
+        value.as_ref().map(Wrapper).serialize(serializer)
+    }
+    
+    /// Deserializes an optional value by parsing a string.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        Ok(Option::<Wrapper<T>>::deserialize(deserializer)?.map(|wrapper| wrapper.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Currencies, ListingCurrencies, refined, scrap};
+    use crate::types::Currency;
+    use serde::{Serialize, Deserialize};
+    use serde_json::json;
+    
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct MetalFloat {
+        #[serde(with = "super::metal_float")]
+        metal: Currency,
+    }
+    
+    #[test]
+    fn serializes_metal_float() {
+        let value = MetalFloat { metal: refined!(3) + scrap!(3) };
+        
+        assert_eq!(serde_json::to_value(&value).unwrap(), json!({ "metal": 3.33 }));
+        assert_eq!(serde_json::to_value(MetalFloat { metal: refined!(2) }).unwrap(), json!({ "metal": 2.0 }));
+        assert_eq!(serde_json::to_value(MetalFloat { metal: -scrap!(1) }).unwrap(), json!({ "metal": -0.11 }));
+    }
+    
+    #[test]
+    fn deserializes_metal_float() {
+        let from_value = |value| serde_json::from_value::<MetalFloat>(value).unwrap().metal;
+        
+        assert_eq!(from_value(json!({ "metal": 3.33 })), refined!(3) + scrap!(3));
+        assert_eq!(from_value(json!({ "metal": 2 })), refined!(2));
+        assert_eq!(from_value(json!({ "metal": 0.05 })), 1);
+    }
+    
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct MetalWeapons {
+        #[serde(with = "super::metal_weapons")]
+        metal: Currency,
+    }
+    
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct MetalScrap {
+        #[serde(with = "super::metal_scrap")]
+        metal: Currency,
+    }
+    
+    #[test]
+    fn deserializes_metal_float_from_any_number() {
+        let from_value = |value| serde_json::from_value::<MetalFloat>(value).map(|value| value.metal);
+        
+        assert_eq!(from_value(json!({ "metal": "3.33" })).unwrap(), refined!(3) + scrap!(3));
+        assert_eq!(from_value(json!({ "metal": " 2 " })).unwrap(), refined!(2));
+        assert_eq!(from_value(json!({ "metal": u64::MAX })).unwrap(), i64::MAX);
+        assert!(from_value(json!({ "metal": "2 ref" })).is_err());
+        assert!(from_value(json!({ "metal": "NaN" })).is_err());
+        assert!(from_value(json!({ "metal": [] })).is_err());
+    }
+    
+    #[test]
+    fn serializes_metal_weapons() {
+        let value = MetalWeapons { metal: refined!(3) + scrap!(3) };
+        
+        assert_eq!(serde_json::to_value(&value).unwrap(), json!({ "metal": 60 }));
+        assert_eq!(serde_json::from_value::<MetalWeapons>(json!({ "metal": 60 })).unwrap(), value);
+        assert_eq!(serde_json::from_value::<MetalWeapons>(json!({ "metal": "60" })).unwrap(), value);
+        assert_eq!(serde_json::from_value::<MetalWeapons>(json!({ "metal": 59.6 })).unwrap(), value);
+    }
+    
+    #[test]
+    fn serializes_metal_scrap() {
+        let value = MetalScrap { metal: refined!(3) + scrap!(3) };
+        
+        assert_eq!(serde_json::to_value(&value).unwrap(), json!({ "metal": 30 }));
+        assert_eq!(serde_json::to_value(MetalScrap { metal: 1 }).unwrap(), json!({ "metal": 0.5 }));
+        assert_eq!(serde_json::from_value::<MetalScrap>(json!({ "metal": 30 })).unwrap(), value);
+        assert_eq!(serde_json::from_value::<MetalScrap>(json!({ "metal": "30" })).unwrap(), value);
+        assert_eq!(serde_json::from_value::<MetalScrap>(json!({ "metal": 0.5 })).unwrap().metal, 1);
+    }
+    
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Strings {
+        #[serde(with = "super::string")]
+        currencies: Currencies,
+        #[serde(with = "super::string")]
+        listing_currencies: ListingCurrencies,
+    }
+    
+    #[test]
+    fn serializes_strings() {
+        let value = Strings {
+            currencies: Currencies { keys: -2, metal: -(refined!(3) + scrap!(3)) },
+            listing_currencies: ListingCurrencies { keys: 1.5, metal: refined!(1) },
+        };
+        let json = json!({ "currencies": "-2 keys, -3.33 ref", "listing_currencies": "1.50 keys, 1 ref" });
+        
+        assert_eq!(serde_json::to_value(&value).unwrap(), json);
+        assert_eq!(serde_json::from_value::<Strings>(json).unwrap(), value);
+    }
+    
+    #[test]
+    fn deserializes_invalid_strings() {
+        let error = serde_json::from_value::<Strings>(json!({
+            "currencies": "2 keys, 3.x3 ref",
+            "listing_currencies": "1 key",
+        })).unwrap_err();
+        
+        assert!(error.to_string().starts_with(r#"Invalid metal value "3.x3" at 8..12"#));
+        assert!(serde_json::from_value::<Strings>(json!({
+            "currencies": { "keys": 2 },
+            "listing_currencies": "1 key",
+        })).is_err());
+    }
+    
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Options {
+        #[serde(with = "super::metal_float_option", default)]
+        float: Option<Currency>,
+        #[serde(with = "super::metal_weapons_option", default)]
+        weapons: Option<Currency>,
+        #[serde(with = "super::metal_scrap_option", default)]
+        scrap: Option<Currency>,
+        #[serde(with = "super::string_option", default)]
+        string: Option<Currencies>,
+    }
+    
+    #[test]
+    fn serializes_options() {
+        let metal = refined!(3) + scrap!(3);
+        let value = Options {
+            float: Some(metal),
+            weapons: Some(metal),
+            scrap: Some(metal),
+            string: Some(Currencies { keys: 2, metal }),
+        };
+        let json = json!({ "float": 3.33, "weapons": 60, "scrap": 30, "string": "2 keys, 3.33 ref" });
+        
+        assert_eq!(serde_json::to_value(&value).unwrap(), json);
+        assert_eq!(serde_json::from_value::<Options>(json).unwrap(), value);
+    }
+    
+    #[test]
+    fn serializes_none_options() {
+        let value = Options {
+            float: None,
+            weapons: None,
+            scrap: None,
+            string: None,
+        };
+        let json = json!({ "float": null, "weapons": null, "scrap": null, "string": null });
+        
+        assert_eq!(serde_json::to_value(&value).unwrap(), json);
+        assert_eq!(serde_json::from_value::<Options>(json).unwrap(), value);
+        assert_eq!(serde_json::from_value::<Options>(json!({})).unwrap(), value);
+        assert!(serde_json::from_value::<Options>(json!({ "string": "2 kegs" })).is_err());
+    }
+    
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Metals {
+        #[serde(with = "super::metal_float")]
+        float: Currency,
+        #[serde(with = "super::metal_weapons")]
+        weapons: Currency,
+        #[serde(with = "super::metal_scrap")]
+        scrap: Currency,
+        #[serde(with = "super::metal_float_option")]
+        option: Option<Currency>,
+        #[serde(with = "super::string")]
+        string: Currencies,
+    }
+    
+    #[test]
+    fn serializes_bincode() {
+        for metal in [1, refined!(3) + scrap!(3) + 1, i64::MAX] {
+            let value = Metals {
+                float: metal,
+                weapons: metal,
+                scrap: metal,
+                option: Some(metal),
+                string: Currencies { keys: 1, metal },
+            };
+            let bytes = bincode::serialize(&value).unwrap();
+            
+            assert_eq!(bincode::deserialize::<Metals>(&bytes).unwrap(), value);
+        }
+    }
+    
+    #[test]
+    fn metal_float_round_trip() {
+        for metal in [0, 1, scrap!(1), refined!(1) + scrap!(5), -refined!(30), refined!(1_000_000) + scrap!(7)] {
+            let json = serde_json::to_string(&MetalFloat { metal }).unwrap();
+            
+            assert_eq!(serde_json::from_str::<MetalFloat>(&json).unwrap().metal, metal);
+        }
+    }
+}
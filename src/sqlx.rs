@@ -0,0 +1,126 @@
+//! [`sqlx`] support for [`Currencies`]. Available with the `sqlx` feature.
+//! 
+//! In Postgres, currencies are stored as a composite type which must be created in the database:
+//! 
+//! ```sql
+//! CREATE TYPE currencies AS (keys BIGINT, metal BIGINT);
+//! ```
+//! 
+//! Metal is stored as weapons so values are exact.
+//! 
+//! SQLite has no composite types so currencies are stored as `TEXT` using their display string,
+//! e.g. "2 keys, 3.33 ref". The string is parsed back into the same currencies, including 
+//! "nothing" for zero currencies.
+
+use crate::types::Currency;
+use crate::{Currencies, ParseOptions};
+use sqlx::{Decode, Encode, Postgres, Sqlite, Type};
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueRef};
+use sqlx::postgres::types::{PgRecordDecoder, PgRecordEncoder};
+use sqlx::sqlite::{SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef};
+
+/// The name of the Postgres composite type.
+const PG_TYPE_NAME: &str = "currencies";
+
+impl Type<Postgres> for Currencies {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name(PG_TYPE_NAME)
+    }
+}
+
+impl Encode<'_, Postgres> for Currencies {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        let mut encoder = PgRecordEncoder::new(buf);
+        
+        encoder.encode(self.keys)?;
+        encoder.encode(self.metal)?;
+        encoder.finish();
+        
+        Ok(IsNull::No)
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for Currencies {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        let mut decoder = PgRecordDecoder::new(value)?;
+        let keys = decoder.try_decode::<Currency>()?;
+        let metal = decoder.try_decode::<Currency>()?;
+        
+        Ok(Self {
+            keys,
+            metal,
+        })
+    }
+}
+
+impl Type<Sqlite> for Currencies {
+    fn type_info() -> SqliteTypeInfo {
+        <String as Type<Sqlite>>::type_info()
+    }
+}
+
+impl<'q> Encode<'q, Sqlite> for Currencies {
+    fn encode_by_ref(&self, args: &mut Vec<SqliteArgumentValue<'q>>) -> Result<IsNull, BoxDynError> {
+        <String as Encode<'q, Sqlite>>::encode(self.to_string(), args)
+    }
+}
+
+impl<'r> Decode<'r, Sqlite> for Currencies {
+    fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+        let string = <&str as Decode<'r, Sqlite>>::decode(value)?;
+        // Zero currencies are displayed as "nothing".
+        let options = ParseOptions::new().allow_empty(true);
+        
+        Ok(Currencies::from_str_with(string, &options)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{refined, scrap};
+    use sqlx::{Connection, SqliteConnection};
+    
+    async fn round_trip(currencies: Currencies) -> Currencies {
+        let mut connection = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+        
+        sqlx::query("CREATE TABLE prices (price TEXT NOT NULL)")
+            .execute(&mut connection)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO prices (price) VALUES (?)")
+            .bind(currencies)
+            .execute(&mut connection)
+            .await
+            .unwrap();
+        
+        sqlx::query_scalar("SELECT price FROM prices")
+            .fetch_one(&mut connection)
+            .await
+            .unwrap()
+    }
+    
+    #[tokio::test]
+    async fn round_trips_sqlite() {
+        for currencies in [
+            Currencies { keys: 2, metal: refined!(3) + scrap!(3) },
+            Currencies { keys: -1, metal: 1 },
+            Currencies { keys: 0, metal: refined!(1_000_000) + 17 },
+            Currencies::ZERO,
+        ] {
+            assert_eq!(round_trip(currencies).await, currencies);
+        }
+    }
+    
+    #[tokio::test]
+    async fn decodes_invalid_sqlite_text() {
+        let mut connection = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+        let result = sqlx::query_scalar::<_, Currencies>("SELECT '2 kegs'")
+            .fetch_one(&mut connection)
+            .await;
+        
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,265 @@
+//! Steam Community Market fee calculations. All values are in cents (or the minor unit of the 
+//! wallet currency).
+//! 
+//! Fees are calculated the same way Steam calculates them: a 5% Steam fee and a 10% Team 
+//! Fortress 2 publisher fee, both taken from the amount the seller receives and each at least 
+//! one cent.
+//! 
+//! # Examples
+//! ```
+//! use tf2_price::steam_market::SteamMarketFees;
+//! 
+//! // An item listed so that the buyer pays $1.15.
+//! let fees = SteamMarketFees::from_paid(115).unwrap();
+//! 
+//! assert_eq!(fees.received, 100);
+//! assert_eq!(fees.steam_fee, 5);
+//! assert_eq!(fees.publisher_fee, 10);
+//! 
+//! assert_eq!(SteamMarketFees::from_received(100), Some(fees));
+//! ```
+
+use crate::types::Currency;
+use crate::{Converter, Currencies, USDCurrencies};
+
+/// The smallest amount a seller can receive for an item.
+pub const MIN_RECEIVED: Currency = 1;
+/// The Steam fee as a percentage of the amount the seller receives.
+pub const STEAM_FEE_PERCENT: Currency = 5;
+/// The Team Fortress 2 publisher fee as a percentage of the amount the seller receives.
+pub const PUBLISHER_FEE_PERCENT: Currency = 10;
+
+/// The breakdown of a Steam Community Market sale.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct SteamMarketFees {
+    /// The amount the seller receives.
+    pub received: Currency,
+    /// The fee taken by Steam.
+    pub steam_fee: Currency,
+    /// The fee taken by the publisher.
+    pub publisher_fee: Currency,
+    /// The amount the buyer pays.
+    pub paid: Currency,
+}
+
+impl SteamMarketFees {
+    /// Calculates the fees from the amount the seller receives. `None` if `received` is less 
+    /// than [`MIN_RECEIVED`] or the result overflows integer bounds.
+    pub fn from_received(received: Currency) -> Option<Self> {
+        if received < MIN_RECEIVED {
+            return None;
+        }
+        
+        let steam_fee = (received.checked_mul(STEAM_FEE_PERCENT)? / 100).max(1);
+        let publisher_fee = (received.checked_mul(PUBLISHER_FEE_PERCENT)? / 100).max(1);
+        let paid = received.checked_add(steam_fee)?.checked_add(publisher_fee)?;
+        
+        Some(Self {
+            received,
+            steam_fee,
+            publisher_fee,
+            paid,
+        })
+    }
+    
+    /// Calculates the fees from the amount the buyer pays. `None` if the seller would receive 
+    /// less than [`MIN_RECEIVED`].
+    /// 
+    /// Not every amount can be produced by [`SteamMarketFees::from_received`]. In these cases 
+    /// the difference is added to the Steam fee, which matches what Steam does.
+    pub fn from_paid(paid: Currency) -> Option<Self> {
+        let total_percent = 100 + STEAM_FEE_PERCENT + PUBLISHER_FEE_PERCENT;
+        // Widened so that the multiplication cannot overflow.
+        let mut estimated = ((paid as i128 * 100 / total_percent as i128) as Currency)
+            .max(MIN_RECEIVED);
+        let mut ever_undershot = false;
+        
+        // Steam makes at most 10 adjustments to the estimate.
+        for _ in 0..10 {
+            let fees = Self::from_received(estimated)?;
+            
+            if fees.paid == paid {
+                return Some(fees);
+            }
+            
+            if fees.paid > paid {
+                if ever_undershot {
+                    let mut fees = Self::from_received(estimated - 1)?;
+                    
+                    fees.steam_fee += paid - fees.paid;
+                    fees.paid = paid;
+                    
+                    return Some(fees);
+                }
+                
+                estimated -= 1;
+                
+                if estimated < MIN_RECEIVED {
+                    return None;
+                }
+            } else {
+                ever_undershot = true;
+                estimated += 1;
+            }
+        }
+        
+        let fees = Self::from_received(estimated)?;
+        
+        (fees.paid == paid).then_some(fees)
+    }
+    
+    /// The total fees taken from the sale.
+    pub fn fees(&self) -> Currency {
+        self.steam_fee + self.publisher_fee
+    }
+}
+
+/// Converts a Steam Community Market price in cents, the amount the buyer pays, to currencies 
+/// using the given converter. `None` if the converter does not have a cash value for keys.
+/// 
+/// # Panics
+/// Panics if the converter's key price is `0`.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Converter, Currencies, refined};
+/// use tf2_price::steam_market;
+/// 
+/// // Keys are 50 ref or $2.30 each.
+/// let converter = Converter::new(refined!(50), Some(230));
+/// 
+/// assert_eq!(
+///     steam_market::from_scm_cents(115, &converter),
+///     Some(Currencies { keys: 0, metal: refined!(25) }),
+/// );
+/// // The seller receives $1.00 from a sale at $1.15.
+/// assert_eq!(
+///     steam_market::from_scm_cents_received(115, &converter),
+///     Some(Currencies { keys: 0, metal: 391 }),
+/// );
+/// ```
+pub fn from_scm_cents(cents: Currency, converter: &Converter) -> Option<Currencies> {
+    converter.from_usd(&USDCurrencies { usd: cents })
+}
+
+/// Converts the amount the seller receives from a sale on the Steam Community Market at `cents` 
+/// to currencies using the given converter. `None` if the converter does not have a cash value 
+/// for keys or the price is too low to sell at.
+/// 
+/// # Panics
+/// Panics if the converter's key price is `0`.
+pub fn from_scm_cents_received(cents: Currency, converter: &Converter) -> Option<Currencies> {
+    let fees = SteamMarketFees::from_paid(cents)?;
+    
+    from_scm_cents(fees.received, converter)
+}
+
+/// Converts currencies to a Steam Community Market price in cents, the amount the buyer pays, 
+/// using the given converter. `None` if the converter does not have a cash value for keys.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Converter, Currencies, refined};
+/// use tf2_price::steam_market;
+/// 
+/// let converter = Converter::new(refined!(50), Some(230));
+/// let currencies = Currencies { keys: 0, metal: refined!(25) };
+/// 
+/// assert_eq!(steam_market::to_scm_cents(&currencies, &converter), Some(115));
+/// // Listing at $1.31 leaves $1.15 for the seller.
+/// assert_eq!(steam_market::to_scm_cents_for_received(&currencies, &converter), Some(131));
+/// ```
+pub fn to_scm_cents(currencies: &Currencies, converter: &Converter) -> Option<Currency> {
+    Some(converter.to_usd(currencies)?.usd)
+}
+
+/// Converts currencies to the Steam Community Market price in cents the item must be listed at 
+/// for the seller to receive their value using the given converter. `None` if the converter does 
+/// not have a cash value for keys or the value is too low to sell at.
+pub fn to_scm_cents_for_received(
+    currencies: &Currencies,
+    converter: &Converter,
+) -> Option<Currency> {
+    let received = to_scm_cents(currencies, converter)?;
+    
+    Some(SteamMarketFees::from_received(received)?.paid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn calculates_minimum_fees() {
+        assert_eq!(SteamMarketFees::from_received(1), Some(SteamMarketFees {
+            received: 1,
+            steam_fee: 1,
+            publisher_fee: 1,
+            paid: 3,
+        }));
+    }
+    
+    #[test]
+    fn calculates_fees_from_received() {
+        assert_eq!(SteamMarketFees::from_received(199), Some(SteamMarketFees {
+            received: 199,
+            steam_fee: 9,
+            publisher_fee: 19,
+            paid: 227,
+        }));
+    }
+    
+    #[test]
+    fn calculates_fees_from_paid() {
+        assert_eq!(SteamMarketFees::from_paid(227), SteamMarketFees::from_received(199));
+        assert_eq!(SteamMarketFees::from_paid(3), SteamMarketFees::from_received(1));
+    }
+    
+    #[test]
+    fn calculates_fees_from_paid_unreachable_amount() {
+        // 19 cents received costs 21 cents, 20 cents received costs 23 cents.
+        assert_eq!(SteamMarketFees::from_paid(22), Some(SteamMarketFees {
+            received: 19,
+            steam_fee: 2,
+            publisher_fee: 1,
+            paid: 22,
+        }));
+    }
+    
+    #[test]
+    fn converts_scm_cents_without_usd_key_price() {
+        let converter = Converter::new(900, None);
+        
+        assert_eq!(from_scm_cents(100, &converter), None);
+        assert_eq!(to_scm_cents(&Currencies { keys: 1, metal: 0 }, &converter), None);
+    }
+    
+    #[test]
+    fn converts_scm_cents_received_too_low() {
+        assert_eq!(from_scm_cents_received(2, &Converter::new(900, Some(200))), None);
+    }
+    
+    #[test]
+    fn converts_scm_cents_round_trip() {
+        let converter = Converter::new(900, Some(200));
+        let currencies = Currencies { keys: 3, metal: 0 };
+        let cents = to_scm_cents_for_received(&currencies, &converter).unwrap();
+        
+        assert_eq!(from_scm_cents_received(cents, &converter), Some(currencies));
+    }
+    
+    #[test]
+    fn from_paid_too_low() {
+        assert_eq!(SteamMarketFees::from_paid(2), None);
+        assert_eq!(SteamMarketFees::from_paid(0), None);
+    }
+    
+    #[test]
+    fn from_paid_matches_from_received() {
+        for received in 1..10_000 {
+            let fees = SteamMarketFees::from_received(received).unwrap();
+            
+            assert_eq!(SteamMarketFees::from_paid(fees.paid), Some(fees));
+        }
+    }
+}
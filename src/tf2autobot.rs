@@ -0,0 +1,235 @@
+//! Types for tf2autobot pricelists.
+//! 
+//! # Examples
+//! ```
+//! use tf2_price::{Currencies, refined, scrap};
+//! use tf2_price::tf2autobot::{Intent, PricelistEntry};
+//! 
+//! let json = r#"{
+//!     "sku": "5021;6",
+//!     "enabled": true,
+//!     "autoprice": true,
+//!     "min": 0,
+//!     "max": 1,
+//!     "intent": 2,
+//!     "buy": { "keys": 0, "metal": 67.33 },
+//!     "sell": { "keys": 1, "metal": 0 },
+//!     "time": 1672531200
+//! }"#;
+//! let entry: PricelistEntry = serde_json::from_str(json).unwrap();
+//! 
+//! assert_eq!(entry.intent, Intent::Bank);
+//! assert_eq!(entry.buy, Currencies { keys: 0, metal: refined!(67) + scrap!(3) });
+//! assert_eq!(entry.sell, Currencies { keys: 1, metal: 0 });
+//! ```
+
+use crate::types::Currency;
+use crate::Currencies;
+use serde::{Serialize, Deserialize, Serializer, Deserializer};
+use serde::de::Error;
+use alloc::string::String;
+use alloc::format;
+
+/// Serializes and deserializes [`Currencies`] in the format used by tf2autobot, where both `keys` 
+/// and `metal` are always present and may both be `0`.
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::Currencies;
+/// use serde::{Serialize, Deserialize};
+/// 
+/// #[derive(Serialize, Deserialize)]
+/// struct Price {
+///     #[serde(with = "tf2_price::tf2autobot::currencies")]
+///     buy: Currencies,
+/// }
+/// 
+/// let price = Price { buy: Currencies { keys: 0, metal: 0 } };
+/// 
+/// assert_eq!(serde_json::to_string(&price).unwrap(), r#"{"buy":{"keys":0,"metal":0}}"#);
+/// ```
+pub mod currencies {
+    use crate::helpers;
+    use crate::types::Currency;
+    use crate::Currencies;
+    use serde::{Serialize, Deserialize, Serializer, Deserializer};
+    
+    #[derive(Serialize, Deserialize)]
+    struct Tf2AutobotCurrencies {
+        #[serde(default)]
+        keys: Currency,
+        #[serde(with = "helpers::metal", default)]
+        metal: Currency,
+    }
+    
+    pub fn serialize<S>(currencies: &Currencies, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        Tf2AutobotCurrencies {
+            keys: currencies.keys,
+            metal: currencies.metal,
+        }.serialize(serializer)
+    }
+    
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Currencies, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        let currencies = Tf2AutobotCurrencies::deserialize(deserializer)?;
+        
+        Ok(Currencies {
+            keys: currencies.keys,
+            metal: currencies.metal,
+        })
+    }
+}
+
+/// What a pricelist entry is traded for.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Intent {
+    /// Only bought.
+    Buy,
+    /// Only sold.
+    Sell,
+    /// Bought and sold.
+    Bank,
+}
+
+impl Intent {
+    fn as_u8(self) -> u8 {
+        match self {
+            Self::Buy => 0,
+            Self::Sell => 1,
+            Self::Bank => 2,
+        }
+    }
+}
+
+impl Serialize for Intent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u8(self.as_u8())
+    }
+}
+
+impl<'de> Deserialize<'de> for Intent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match u8::deserialize(deserializer)? {
+            0 => Ok(Self::Buy),
+            1 => Ok(Self::Sell),
+            2 => Ok(Self::Bank),
+            intent => Err(D::Error::custom(format!("Invalid intent: {intent}"))),
+        }
+    }
+}
+
+/// Notes displayed with a pricelist entry.
+#[derive(Debug, Serialize, Deserialize, Default, PartialEq, Eq, Hash, Clone)]
+pub struct Note {
+    /// Note for buying.
+    pub buy: Option<String>,
+    /// Note for selling.
+    pub sell: Option<String>,
+}
+
+/// An entry in a tf2autobot pricelist. Fields not included here are not preserved.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PricelistEntry {
+    /// The SKU of the item.
+    pub sku: String,
+    /// The name of the item.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Whether the item is traded.
+    pub enabled: bool,
+    /// Whether the item is priced automatically.
+    pub autoprice: bool,
+    /// The minimum stock to keep.
+    pub min: Currency,
+    /// The maximum stock to keep.
+    pub max: Currency,
+    /// What the item is traded for.
+    pub intent: Intent,
+    /// The buy price.
+    #[serde(with = "currencies")]
+    pub buy: Currencies,
+    /// The sell price.
+    #[serde(with = "currencies")]
+    pub sell: Currencies,
+    /// Whether the listing is promoted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub promoted: Option<u8>,
+    /// The group of the entry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    /// Notes displayed with the entry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<Note>,
+    /// Whether the item is partially priced.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_partial_priced: Option<bool>,
+    /// When the price was last updated as a Unix timestamp in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<i64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{refined, scrap};
+    use serde_json::{self, json, Value};
+    use assert_json_diff::assert_json_eq;
+    
+    #[test]
+    fn serializes_entry() {
+        let entry = PricelistEntry {
+            sku: "5021;6".into(),
+            name: None,
+            enabled: true,
+            autoprice: false,
+            min: 0,
+            max: 5,
+            intent: Intent::Sell,
+            buy: Currencies { keys: 0, metal: 0 },
+            sell: Currencies { keys: 1, metal: refined!(2) + scrap!(1) },
+            promoted: None,
+            group: Some("all".into()),
+            note: Some(Note::default()),
+            is_partial_priced: Some(false),
+            time: None,
+        };
+        let entry_json = serde_json::to_string(&entry).unwrap();
+        let actual: Value = serde_json::from_str(&entry_json).unwrap();
+        let expected: Value = json!({
+            "sku": "5021;6",
+            "enabled": true,
+            "autoprice": false,
+            "min": 0,
+            "max": 5,
+            "intent": 1,
+            "buy": { "keys": 0, "metal": 0 },
+            "sell": { "keys": 1, "metal": 2.11 },
+            "group": "all",
+            "note": { "buy": null, "sell": null },
+            "isPartialPriced": false
+        });
+        
+        assert_json_eq!(
+            actual,
+            expected,
+        );
+        assert_eq!(serde_json::from_str::<PricelistEntry>(&entry_json).unwrap(), entry);
+    }
+    
+    #[test]
+    fn rejects_invalid_intent() {
+        assert!(serde_json::from_str::<Intent>("3").is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use std::fmt::Debug;
+use core::fmt::Debug;
 use serde::{Serialize, de::DeserializeOwned};
 
 pub trait SerializeCurrencies: Sized + Debug + Serialize + DeserializeOwned {}
\ No newline at end of file
@@ -1,15 +1,24 @@
 use crate::helpers;
 use crate::types::Currency;
-use std::fmt;
-use std::cmp::{Ord, Ordering};
-use std::ops::{self, AddAssign, SubAssign, MulAssign, DivAssign};
+use crate::error::ParseUSDError;
+#[cfg(not(feature = "std"))]
+use crate::float::Float;
+use core::fmt;
+use core::str::FromStr;
+use core::cmp::{Ord, Ordering};
+use core::ops::{self, AddAssign, SubAssign, MulAssign, DivAssign};
 use serde::{Serialize, Deserialize};
+use alloc::string::ToString;
 
 /// For storing cash values.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 pub struct USDCurrencies {
     /// Cash value in cents.
     #[serde(with = "helpers::cents", default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "f64"))]
+    #[cfg_attr(feature = "ts-rs", ts(type = "number"))]
     pub usd: Currency,
 }
 
@@ -35,7 +44,7 @@ impl Default for USDCurrencies {
 
 impl USDCurrencies {
     /// Creates a new [`USDCurrencies`] with `0` usd.
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Self {
             usd: 0,
         }
@@ -57,7 +66,27 @@ impl USDCurrencies {
         usd_key_price: Currency,
         metal_key_price: Currency,
     ) -> Currency {
-        ((self.usd as f32 / usd_key_price as f32) * metal_key_price as f32).round() as Currency
+        ((self.usd as f64 / usd_key_price as f64) * metal_key_price as f64).round() as Currency
+    }
+    
+    /// Converts a metal value (represented as weapons) to cash using the key prices, rounded to 
+    /// the nearest cent. This is the reverse of [`USDCurrencies::to_metal`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use tf2_price::{USDCurrencies, refined};
+    /// 
+    /// // Keys are $1.80 or 50 ref.
+    /// assert_eq!(USDCurrencies::from_metal(refined!(25), 180, refined!(50)), USDCurrencies { usd: 90 });
+    /// ```
+    pub fn from_metal(
+        metal: Currency,
+        usd_key_price: Currency,
+        metal_key_price: Currency,
+    ) -> Self {
+        Self {
+            usd: (metal as f64 / metal_key_price as f64 * usd_key_price as f64).round() as Currency,
+        }
     }
     
     /// Checks if the currencies contain any value.
@@ -98,9 +127,33 @@ impl USDCurrencies {
     }
 }
 
+impl<'a> TryFrom<&'a str> for USDCurrencies {
+    type Error = ParseUSDError;
+    
+    /// Parses a cash value from a string e.g. "$1,234.56", "4.99 USD" or "499¢". The dollar sign 
+    /// and thousands separators are optional.
+    fn try_from(string: &'a str) -> Result<Self, Self::Error>  {
+        Ok(Self {
+            usd: helpers::parse_cents(string)?,
+        })
+    }
+}
+
+impl FromStr for USDCurrencies {
+    type Err = ParseUSDError;
+    
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Self::try_from(string)
+    }
+}
+
 impl fmt::Display for USDCurrencies {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "${}", helpers::thousands(format!("{:.2}", self.to_dollars())))
+        // Formatted from integers so that large values do not lose precision.
+        let sign = if self.usd < 0 { "-" } else { "" };
+        let cents = self.usd.unsigned_abs();
+        
+        write!(f, "${sign}{}.{:02}", helpers::thousands((cents / 100).to_string()), cents % 100)
     }
 }
 
@@ -130,13 +183,13 @@ impl_op_ex!(/ |currencies: &USDCurrencies, num: Currency| -> USDCurrencies {
 
 impl_op_ex!(* |currencies: &USDCurrencies, num: f32| -> USDCurrencies {
     USDCurrencies { 
-        usd: (currencies.usd as f32 * num).round() as Currency,
+        usd: (currencies.usd as f64 * num as f64).round() as Currency,
     }
 });
 
 impl_op_ex!(/ |currencies: &USDCurrencies, num: f32| -> USDCurrencies {
     USDCurrencies {
-        usd: (currencies.usd as f32 / num).round() as Currency,
+        usd: (currencies.usd as f64 / num as f64).round() as Currency,
     }
 });
 
@@ -172,7 +225,7 @@ impl MulAssign<Currency> for USDCurrencies {
 
 impl MulAssign<f32> for USDCurrencies {
     fn mul_assign(&mut self, other: f32) {
-        self.usd = (self.usd as f32 * other).round() as Currency;
+        self.usd = (self.usd as f64 * other as f64).round() as Currency;
     }
 }
 
@@ -184,13 +237,14 @@ impl DivAssign<Currency> for USDCurrencies {
 
 impl DivAssign<f32> for USDCurrencies {
     fn div_assign(&mut self, other: f32) {
-        self.usd = (self.usd as f32 / other).round() as Currency;
+        self.usd = (self.usd as f64 / other as f64).round() as Currency;
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::refined;
     use serde_json::{self, json, Value};
     use assert_json_diff::assert_json_eq;
 
@@ -322,6 +376,14 @@ mod tests {
         });
     }
     
+    #[test]
+    fn converts_from_metal_and_back() {
+        let currencies = USDCurrencies::from_metal(refined!(100), 180, refined!(50));
+        
+        assert_eq!(currencies, USDCurrencies { usd: 360 });
+        assert_eq!(currencies.to_metal(180, refined!(50)), refined!(100));
+    }
+    
     #[test]
     fn to_string() {
         assert_eq!(USDCurrencies {
@@ -336,6 +398,65 @@ mod tests {
         }.to_string(), "$1,234.56");
     }
     
+    #[test]
+    fn to_string_with_large_value() {
+        assert_eq!(USDCurrencies {
+            usd: 12345678901,
+        }.to_string(), "$123,456,789.01");
+    }
+    
+    #[test]
+    fn to_string_negative() {
+        assert_eq!(USDCurrencies {
+            usd: -5,
+        }.to_string(), "$-0.05");
+    }
+    
+    #[test]
+    fn serializes_large_value() {
+        let currencies = USDCurrencies {
+            usd: 12345678901,
+        };
+        
+        assert_eq!(serde_json::to_string(&currencies).unwrap(), r#"{"usd":123456789.01}"#);
+    }
+    
+    #[test]
+    fn parses_from_string() {
+        assert_eq!(USDCurrencies::try_from("$4.99").unwrap(), USDCurrencies {
+            usd: 499,
+        });
+    }
+    
+    #[test]
+    fn parses_from_string_with_thousands() {
+        assert_eq!(USDCurrencies::try_from("$1,234.56").unwrap(), USDCurrencies {
+            usd: 123456,
+        });
+    }
+    
+    #[test]
+    fn parses_to_string_round_trip() {
+        let currencies = USDCurrencies {
+            usd: -987654,
+        };
+        
+        assert_eq!(USDCurrencies::try_from(currencies.to_string().as_str()).unwrap(), currencies);
+    }
+    
+    #[test]
+    fn parses_from_string_invalid() {
+        assert!(USDCurrencies::try_from("4.99 CAD").is_err());
+    }
+    
+    #[test]
+    fn parses_with_from_str() {
+        assert_eq!("$1,234.56".parse::<USDCurrencies>(), Ok(USDCurrencies { usd: 123456 }));
+        assert_eq!("4.99 USD".parse::<USDCurrencies>(), Ok(USDCurrencies { usd: 499 }));
+        assert_eq!("499¢".parse::<USDCurrencies>(), Ok(USDCurrencies { usd: 499 }));
+        assert_eq!("4.99 EUR".parse::<USDCurrencies>(), Err(ParseUSDError::InvalidFormat));
+    }
+    
     #[test]
     fn correct_json_format() {
         let currencies = USDCurrencies {
@@ -361,4 +482,22 @@ mod tests {
             usd: 123456,
         }, currencies);
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn deserializes_currencies_with_large_value() {
+        let currencies: USDCurrencies = serde_json::from_str(r#"{"usd":123456789.01}"#).unwrap();
+        
+        assert_eq!(USDCurrencies {
+            usd: 12345678901,
+        }, currencies);
+    }
+    
+    #[test]
+    fn serializes_bincode() {
+        let currencies = USDCurrencies { usd: 1_000_000_000_000_001 };
+        let bytes = bincode::serialize(&currencies).unwrap();
+        
+        assert_eq!(bytes.len(), 8);
+        assert_eq!(bincode::deserialize::<USDCurrencies>(&bytes).unwrap(), currencies);
+    }
+}
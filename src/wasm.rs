@@ -0,0 +1,248 @@
+//! [`wasm_bindgen`] bindings for [`Currencies`]. Available with the `wasm` feature.
+//! 
+//! [`WasmCurrencies`] is exported to JavaScript as the `Currencies` class. Values are given as
+//! JavaScript numbers and converted to integers, saturating at the bounds of [`i64`]. Like
+//! [`Currencies`], `metal` is represented as weapons. Values beyond `Number.MAX_SAFE_INTEGER` lose
+//! precision when passed to or from JavaScript.
+//! 
+//! ```js
+//! import { Currencies } from "tf2-price";
+//! 
+//! const price = Currencies.parse("2 keys, 3.33 ref");
+//! const total = price.add(new Currencies(1, 18));
+//! 
+//! console.log(total.toString()); // "3 keys, 4.33 ref"
+//! ```
+
+use crate::types::Currency;
+use crate::{get_metal_float, get_metal_from_float, Currencies, FormatOptions};
+use std::cmp::Ordering;
+use wasm_bindgen::prelude::*;
+
+/// A JavaScript class wrapping [`Currencies`].
+/// 
+/// # Examples
+/// ```
+/// use tf2_price::{Currencies, refined};
+/// use tf2_price::wasm::WasmCurrencies;
+/// 
+/// let currencies = WasmCurrencies::new(2.0, refined!(3) as f64);
+/// 
+/// assert_eq!(currencies.to_js_string(), "2 keys, 3 ref");
+/// assert_eq!(Currencies::from(currencies), Currencies { keys: 2, metal: refined!(3) });
+/// ```
+#[wasm_bindgen(js_name = Currencies)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WasmCurrencies(Currencies);
+
+#[wasm_bindgen(js_class = Currencies)]
+impl WasmCurrencies {
+    /// Creates currencies from keys and metal (represented as weapons).
+    #[wasm_bindgen(constructor)]
+    pub fn new(keys: f64, metal: f64) -> Self {
+        Self(Currencies {
+            keys: keys as Currency,
+            metal: metal as Currency,
+        })
+    }
+    
+    /// Creates currencies from keys and a refined metal value, e.g. `1.33`.
+    #[wasm_bindgen(js_name = fromRefined)]
+    pub fn from_refined(keys: f64, refined: f32) -> Self {
+        Self(Currencies {
+            keys: keys as Currency,
+            metal: get_metal_from_float(refined),
+        })
+    }
+    
+    /// Converts a metal value into keys and metal using the given key price (represented as
+    /// weapons). Fails if the key price is `0`.
+    #[wasm_bindgen(js_name = fromMetal)]
+    pub fn from_metal(metal: f64, key_price: f64) -> Result<WasmCurrencies, JsError> {
+        Currencies::checked_from_metal(metal as Currency, key_price as Currency)
+            .map(Self)
+            .ok_or_else(|| JsError::new("Key price must not be 0"))
+    }
+    
+    /// Parses currencies from a string, e.g. "2 keys, 3.33 ref".
+    pub fn parse(string: &str) -> Result<WasmCurrencies, JsError> {
+        string.parse::<Currencies>()
+            .map(Self)
+            .map_err(JsError::from)
+    }
+    
+    /// The number of keys.
+    #[wasm_bindgen(getter)]
+    pub fn keys(&self) -> f64 {
+        self.0.keys as f64
+    }
+    
+    /// Sets the number of keys.
+    #[wasm_bindgen(setter)]
+    pub fn set_keys(&mut self, keys: f64) {
+        self.0.keys = keys as Currency;
+    }
+    
+    /// The metal value (represented as weapons).
+    #[wasm_bindgen(getter)]
+    pub fn metal(&self) -> f64 {
+        self.0.metal as f64
+    }
+    
+    /// Sets the metal value (represented as weapons).
+    #[wasm_bindgen(setter)]
+    pub fn set_metal(&mut self, metal: f64) {
+        self.0.metal = metal as Currency;
+    }
+    
+    /// The metal value in refined, truncated to 2 decimal places, e.g. `1.33`.
+    #[wasm_bindgen(getter, js_name = metalRefined)]
+    pub fn metal_refined(&self) -> f32 {
+        get_metal_float(self.0.metal)
+    }
+    
+    /// Adds currencies. This method is saturating.
+    pub fn add(&self, other: &WasmCurrencies) -> WasmCurrencies {
+        Self(self.0 + other.0)
+    }
+    
+    /// Subtracts currencies. This method is saturating.
+    pub fn sub(&self, other: &WasmCurrencies) -> WasmCurrencies {
+        Self(self.0 - other.0)
+    }
+    
+    /// Multiplies both fields by an integer. This method is saturating.
+    pub fn mul(&self, n: f64) -> WasmCurrencies {
+        Self(self.0 * n as Currency)
+    }
+    
+    /// Divides both fields by an integer. Fails if `n` is `0`.
+    pub fn div(&self, n: f64) -> Result<WasmCurrencies, JsError> {
+        self.0.checked_div(n as Currency)
+            .map(Self)
+            .ok_or_else(|| JsError::new("Cannot divide by 0"))
+    }
+    
+    /// Converts currencies to a metal value using the given key price (represented as weapons).
+    /// This method is saturating.
+    #[wasm_bindgen(js_name = toMetal)]
+    pub fn to_metal(&self, key_price: f64) -> f64 {
+        self.0.to_metal(key_price as Currency) as f64
+    }
+    
+    /// Compares currencies by their total value using the given key price (represented as
+    /// weapons). Returns `-1`, `0` or `1`, which can be used with `Array.prototype.sort`.
+    #[wasm_bindgen(js_name = compareWithKeyPrice)]
+    pub fn compare_with_key_price(&self, other: &WasmCurrencies, key_price: f64) -> i32 {
+        match self.0.cmp_with_key_price(&other.0, key_price as Currency) {
+            Ordering::Less => -1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1,
+        }
+    }
+    
+    /// Checks if both currencies hold the same keys and metal.
+    pub fn equals(&self, other: &WasmCurrencies) -> bool {
+        self.0 == other.0
+    }
+    
+    /// Checks if the currencies contain no keys or metal.
+    #[wasm_bindgen(js_name = isEmpty)]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+    
+    /// Formats the currencies, e.g. "2 keys, 3.33 ref".
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+    
+    /// Formats the currencies in compact form, e.g. "2k, 3.33r".
+    #[wasm_bindgen(js_name = toCompactString)]
+    pub fn to_compact_string(&self) -> String {
+        self.0.display_with(&FormatOptions::new().compact(true)).to_string()
+    }
+    
+    /// Formats the total value as keys using the given key price (represented as weapons), e.g.
+    /// "2.5 keys". Fails if the key price is not positive.
+    #[wasm_bindgen(js_name = toKeysString)]
+    pub fn to_keys_string(&self, key_price: f64, precision: u8) -> Result<String, JsError> {
+        let key_price = key_price as Currency;
+        
+        if key_price <= 0 {
+            return Err(JsError::new("Key price must be positive"));
+        }
+        
+        Ok(self.0.display_as_keys(key_price, precision).to_string())
+    }
+}
+
+impl From<Currencies> for WasmCurrencies {
+    fn from(currencies: Currencies) -> Self {
+        Self(currencies)
+    }
+}
+
+impl From<WasmCurrencies> for Currencies {
+    fn from(currencies: WasmCurrencies) -> Self {
+        currencies.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{refined, scrap};
+    
+    #[test]
+    fn creates_currencies() {
+        let currencies = WasmCurrencies::new(2.0, refined!(3) as f64);
+        
+        assert_eq!(currencies.keys(), 2.0);
+        assert_eq!(currencies.metal(), refined!(3) as f64);
+        assert_eq!(
+            WasmCurrencies::from_refined(2.0, 3.33),
+            Currencies { keys: 2, metal: refined!(3) + scrap!(3) }.into(),
+        );
+        assert_eq!(WasmCurrencies::new(f64::INFINITY, f64::NAN).keys(), i64::MAX as f64);
+    }
+    
+    #[test]
+    fn sets_fields() {
+        let mut currencies = WasmCurrencies::default();
+        
+        currencies.set_keys(5.0);
+        currencies.set_metal(scrap!(3) as f64);
+        
+        assert_eq!(Currencies::from(currencies), Currencies { keys: 5, metal: scrap!(3) });
+        assert_eq!(currencies.metal_refined(), 0.33);
+    }
+    
+    #[test]
+    fn performs_arithmetic() {
+        let a = WasmCurrencies::new(2.0, refined!(3) as f64);
+        let b = WasmCurrencies::new(1.0, refined!(1) as f64);
+        
+        assert!(a.add(&b).equals(&WasmCurrencies::new(3.0, refined!(4) as f64)));
+        assert!(a.sub(&b).equals(&WasmCurrencies::new(1.0, refined!(2) as f64)));
+        assert!(a.mul(2.0).equals(&WasmCurrencies::new(4.0, refined!(6) as f64)));
+        assert!(a.div(2.0).unwrap().equals(&WasmCurrencies::new(1.0, 27.0)));
+        assert_eq!(a.to_metal(refined!(50) as f64), refined!(103) as f64);
+        assert_eq!(a.compare_with_key_price(&b, refined!(50) as f64), 1);
+    }
+    
+    #[test]
+    fn parses_and_formats() {
+        let currencies = WasmCurrencies::parse("2 keys, 3.33 ref").unwrap();
+        
+        assert_eq!(currencies.to_js_string(), "2 keys, 3.33 ref");
+        assert_eq!(currencies.to_compact_string(), "2k, 3.33r");
+        assert_eq!(
+            WasmCurrencies::new(2.0, refined!(25) as f64)
+                .to_keys_string(refined!(50) as f64, 1)
+                .unwrap(),
+            "2.5 keys",
+        );
+    }
+}